@@ -3,6 +3,19 @@ export interface SourceBuf {
   filePath: string;
   importableFilePath?: string;
   startLine: number;
+  startColumn: number;
+  offset: number;
+  mappings?: SourceMapping[];
+}
+
+/** Maps a byte range of generated code to the position in the host file it was generated from. */
+export interface SourceMapping {
+  generatedStart: number;
+  generatedEnd: number;
+  line: number;
+  column: number;
+  endLine: number;
+  endColumn: number;
 }
 
 export interface ExtractGraphQLSourcesResult {
@@ -10,8 +23,32 @@ export interface ExtractGraphQLSourcesResult {
   errors: unknown[];
 }
 
+export interface ExtractGraphQLSourcesConfig {
+  /** Modules whose `graphql` export marks calls for extraction. Defaults to `['$mearie']`. */
+  graphqlModules?: string[];
+  /** Whether an unimported, undeclared `graphql` also marks calls for extraction. Defaults to `false`. */
+  globalGraphql?: boolean;
+  /** Directory non-relative imports are resolved against, like tsconfig `baseUrl`. */
+  baseUrl?: string;
+  /** Import aliases, like tsconfig `paths`. Relative targets are resolved against `baseUrl`. */
+  paths?: Record<string, string[]>;
+  /** Local workspace packages fragments can be imported from by package name. */
+  packages?: WorkspacePackageConfig[];
+}
+
+export interface WorkspacePackageConfig {
+  name: string;
+  /** Absolute path of the package directory. */
+  root: string;
+  /** `package.json` style exports mapping subpaths to files relative to `root`. */
+  exports?: Record<string, string>;
+}
+
 export interface GenerateCodeConfig {
   scalars?: Record<string, string>;
+  keyFields?: Record<string, string[]>;
+  /** Paths of the files the documents were read from. `.graphql` and `.gql` files among them get a `.d.ts` declaration. */
+  documentFiles?: string[];
 }
 
 export interface GenerateCodeResult {
@@ -19,8 +56,21 @@ export interface GenerateCodeResult {
   errors: unknown[];
 }
 
-export declare function extractGraphQLSources(source: SourceBuf): ExtractGraphQLSourcesResult;
-export declare function extractGraphQLSourcesFromDocuments(sources: SourceBuf[]): ExtractGraphQLSourcesResult;
+export declare function extractGraphQLSources(
+  source: SourceBuf,
+  config?: ExtractGraphQLSourcesConfig | null,
+): ExtractGraphQLSourcesResult;
+export declare function extractGraphQLSourcesFromDocuments(
+  sources: SourceBuf[],
+  config?: ExtractGraphQLSourcesConfig | null,
+): ExtractGraphQLSourcesResult;
+/** Extracts GraphQL sources from the documents of a project, reusing results for unchanged documents. */
+export declare class ExtractionSession {
+  constructor(config?: ExtractGraphQLSourcesConfig | null);
+  /** Extracts GraphQL sources from all documents of the project. Documents missing since the previous call are forgotten. */
+  extract(sources: SourceBuf[]): ExtractGraphQLSourcesResult;
+}
+/** Schemas with a `.json` file path are loaded as introspection results, others as SDL. */
 export declare function generateCode(
   schemas: SourceBuf[],
   documents: SourceBuf[],
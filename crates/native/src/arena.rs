@@ -1,8 +1,18 @@
 use bumpalo::Bump;
 use rustc_hash::FxHashSet;
 use std::cell::RefCell;
+use thread_local::ThreadLocal;
 
+/// A bump allocator with a string interner.
+///
+/// Each thread allocates from its own bump allocator, so an arena can be shared by
+/// threads parsing in parallel. All allocations live as long as the arena.
 pub struct Arena {
+    locals: ThreadLocal<ThreadArena>,
+}
+
+#[derive(Default)]
+struct ThreadArena {
     bump: Bump,
     interned: RefCell<FxHashSet<&'static str>>,
 }
@@ -11,37 +21,49 @@ impl Arena {
     #[inline]
     pub fn new() -> Self {
         Self {
-            bump: Bump::new(),
-            interned: RefCell::new(FxHashSet::default()),
+            locals: ThreadLocal::new(),
         }
     }
 
+    #[inline]
+    fn local(&self) -> &ThreadArena {
+        self.locals.get_or_default()
+    }
+
     #[inline]
     pub fn allocator(&self) -> &Bump {
-        &self.bump
+        &self.local().bump
     }
 
     #[inline]
     pub fn alloc<T>(&self, value: T) -> &T {
-        self.bump.alloc(value)
+        self.allocator().alloc(value)
     }
 
     #[inline]
     pub fn alloc_vec<T>(&self) -> bumpalo::collections::Vec<'_, T> {
-        bumpalo::collections::Vec::new_in(&self.bump)
+        bumpalo::collections::Vec::new_in(self.allocator())
     }
 
+    /// Allocates the items of `iter` as a slice.
+    #[inline]
+    pub fn alloc_slice<T>(&self, iter: impl IntoIterator<Item = T>) -> &[T] {
+        bumpalo::collections::Vec::from_iter_in(iter, self.allocator()).into_bump_slice()
+    }
+
+    /// Interns `s`. Strings are deduplicated per thread.
     pub fn intern(&self, s: &str) -> &str {
-        let mut interned = self.interned.borrow_mut();
+        let local = self.local();
+        let mut interned = local.interned.borrow_mut();
 
         if let Some(&existing) = interned.get(s) {
             return existing;
         }
 
-        let allocated = self.bump.alloc_str(s);
+        let allocated = local.bump.alloc_str(s);
 
         // SAFETY: This lifetime extension from the arena's lifetime to 'static is sound because:
-        // 1. The memory is allocated in the bump allocator owned by this Arena
+        // 1. The memory is allocated in a bump allocator owned by this Arena
         // 2. The bump allocator never moves or frees allocated memory until the Arena is dropped
         // 3. The string is only stored internally in the FxHashSet with a 'static lifetime
         // 4. The public API returns &str with the Arena's lifetime (not 'static), maintaining proper borrow checking
@@ -135,4 +157,25 @@ mod tests {
         assert_eq!(outer.len(), 3);
         assert!(std::ptr::eq(outer[0], outer[2]));
     }
+
+    #[test]
+    fn test_allocation_from_multiple_threads() {
+        let arena = Arena::new();
+
+        let names = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|index| {
+                    let arena = &arena;
+                    scope.spawn(move || arena.intern(&format!("Type{}", index)))
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(names, vec!["Type0", "Type1", "Type2", "Type3"]);
+        assert_eq!(*arena.alloc(42), 42);
+    }
 }
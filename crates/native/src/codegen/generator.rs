@@ -1,10 +1,11 @@
 use super::{
     CodegenContext,
-    generators::{ModuleGenerator, RuntimeGenerator, TypesGenerator},
+    generators::{DeclarationGenerator, ModuleGenerator, RuntimeGenerator, TypesGenerator},
 };
 use crate::error::Result;
 use crate::schema::{DocumentIndex, SchemaIndex};
 use crate::source::SourceBuf;
+use itertools::chain;
 
 #[cfg(test)]
 use crate::pipeline::PipelineConfig;
@@ -17,12 +18,15 @@ use crate::pipeline::PipelineConfig;
 /// - `graphql.d.ts` - Module augmentation with enum types and public fragment types
 /// - `graphql.js` - Runtime document nodes and the `graphql()` function
 ///
+/// Each `.graphql` and `.gql` document additionally gets a declaration file next to it.
+///
 /// # Architecture
 ///
-/// Uses three specialized generators:
+/// Uses four specialized generators:
 /// - [`TypesGenerator`] - Generates scalar types, input objects, enums, operations, and fragments
 /// - [`ModuleGenerator`] - Generates module augmentation with type aliases and function overloads
 /// - [`RuntimeGenerator`] - Generates runtime document nodes
+/// - [`DeclarationGenerator`] - Generates declarations for importing document files
 ///
 /// # Example
 ///
@@ -67,6 +71,9 @@ impl<'a, 'b> Generator<'a, 'b> {
     /// 2. `graphql.d.ts` - Module augmentation
     /// 3. `graphql.js` - Runtime code
     ///
+    /// These are followed by a declaration for each GraphQL document file, at the path
+    /// of the document with `.d.ts` appended.
+    ///
     /// # Errors
     ///
     /// Returns an error if code generation fails due to:
@@ -97,8 +104,9 @@ impl<'a, 'b> Generator<'a, 'b> {
         let types = TypesGenerator::new(self.ctx, self.schema, self.document).generate()?;
         let module = ModuleGenerator::new(self.ctx, self.schema, self.document).generate()?;
         let runtime = RuntimeGenerator::new(self.ctx, self.schema, self.document).generate()?;
+        let declarations = DeclarationGenerator::new(self.ctx, self.document).generate();
 
-        Ok(vec![types, module, runtime])
+        Ok(chain![[types, module, runtime], declarations].collect())
     }
 }
 
@@ -223,6 +231,44 @@ mod tests {
         assert_contains!(code, "UserFields$key");
     }
 
+    #[test]
+    fn test_generate_opaque_tagged_template_overload() {
+        let schema = r#"
+            type Query {
+                user(id: ID!): User
+            }
+            type User {
+                id: ID!
+                name: String!
+            }
+        "#;
+
+        let operations = r#"
+            fragment UserFields on User {
+                name
+            }
+
+            query GetUser($id: ID!) {
+                user(id: $id) {
+                    ...UserFields
+                }
+            }
+        "#;
+
+        let (ctx, schema_index, document_index) = setup_codegen!(schema, operations);
+        let builder = Generator::new(&ctx, &schema_index, &document_index);
+
+        let result = builder.generate();
+        assert_ok!(&result);
+        let files = result.unwrap();
+        let code = &files[1].code;
+        assert_contains!(
+            code,
+            "export function graphql(document: TemplateStringsArray, ...fragments: unknown[]): import(\"mearie/types\").Artifact;"
+        );
+        assert_not_contains!(code, "GetUser | UserFields");
+    }
+
     #[test]
     fn test_generate_with_input_object() {
         let schema = r#"
@@ -0,0 +1,115 @@
+use super::super::CodegenContext;
+use crate::graphql::ast::*;
+use crate::schema::DocumentIndex;
+use crate::source::{SourceBuf, is_graphql_document};
+
+/// Generates a declaration file next to every `.graphql` and `.gql` document file, e.g.
+/// `GetUser.graphql.d.ts`, so that the default import of a document is typed as the
+/// artifact `graphql()` returns for its source.
+///
+/// Declarations are only generated for documents read from the files configured in
+/// [`PipelineConfig::document_files`](crate::pipeline::PipelineConfig::document_files),
+/// since their paths are where the declarations are written. Sources that were not read
+/// from a file of their own, such as in-memory sources, get no declaration.
+pub struct DeclarationGenerator<'a, 'b> {
+    ctx: &'a CodegenContext,
+    document: &'a DocumentIndex<'b>,
+}
+
+impl<'a, 'b> DeclarationGenerator<'a, 'b> {
+    pub fn new(ctx: &'a CodegenContext, document: &'a DocumentIndex<'b>) -> Self {
+        Self { ctx, document }
+    }
+
+    pub fn generate(&self) -> Vec<SourceBuf> {
+        let document_files = &self.ctx.config().document_files;
+
+        self.document
+            .documents()
+            .filter(|document| {
+                let file_path = document.source.file_path;
+                is_graphql_document(file_path) && document_files.contains(file_path)
+            })
+            .filter_map(|document| {
+                let artifact_name = artifact_name(document)?;
+                let code = format!(
+                    "import type {{ {artifact_name} }} from '$mearie';\n\ndeclare const document: {artifact_name};\nexport default document;\n"
+                );
+
+                Some(SourceBuf {
+                    code,
+                    file_path: format!("{}.d.ts", document.source.file_path),
+                    importable_file_path: None,
+                    start_line: 1,
+                    start_column: 1,
+                    offset: 0,
+                    mappings: None,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns the name of the artifact a document evaluates to: its first named
+/// operation, or its first fragment for documents without operations. This matches
+/// the `graphql()` overload TypeScript picks for the document source.
+fn artifact_name<'b>(document: &Document<'b>) -> Option<&'b str> {
+    document
+        .operations()
+        .find_map(|operation| operation.name.map(|name| name.as_str()))
+        .or_else(|| document.fragments().next().map(|fragment| fragment.name.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arena::Arena;
+    use crate::graphql::parser::Parser;
+    use crate::pipeline::PipelineConfig;
+    use crate::source::Source;
+    use assertables::*;
+
+    #[test]
+    fn test_generate_declarations_for_document_files() {
+        let arena = Arena::new();
+        let mut document_index = DocumentIndex::new();
+
+        for (file_path, code) in [
+            (
+                "/project/GetUser.graphql",
+                "query GetUser { user { ...UserFields } }\nfragment Extra on User { id }",
+            ),
+            ("/project/UserFields.gql", "fragment UserFields on User { name }"),
+            ("/project/query.ts", "query Inline { user { id } }"),
+            ("test.graphql", "query InMemory { user { id } }"),
+        ] {
+            let source = arena.alloc(Source {
+                code,
+                file_path,
+                start_line: 1,
+                start_column: 1,
+                offset: 0,
+                mappings: &[],
+            });
+            let document = Parser::new(&arena).with_source(source).parse().unwrap();
+            document_index.add_document(document).unwrap();
+        }
+
+        let document_files = [
+            "/project/GetUser.graphql",
+            "/project/UserFields.gql",
+            "/project/query.ts",
+        ];
+        let ctx = CodegenContext::new(
+            PipelineConfig::new().with_document_files(document_files.into_iter().map(String::from).collect()),
+        );
+        let declarations = DeclarationGenerator::new(&ctx, &document_index).generate();
+
+        assert_len_eq_x!(&declarations, 2);
+        assert_eq!(declarations[0].file_path, "/project/GetUser.graphql.d.ts");
+        assert_contains!(declarations[0].code, "import type { GetUser } from '$mearie';");
+        assert_contains!(declarations[0].code, "declare const document: GetUser;");
+        assert_eq!(declarations[1].file_path, "/project/UserFields.gql.d.ts");
+        assert_contains!(declarations[1].code, "declare const document: UserFields;");
+    }
+}
@@ -1,7 +1,9 @@
+mod declarations;
 mod module;
 mod runtime;
 mod types;
 
+pub use declarations::*;
 pub use module::*;
 pub use runtime::*;
 pub use types::*;
@@ -55,6 +55,9 @@ impl<'a, 'b> ModuleGenerator<'a, 'b> {
             file_path: "graphql.d.ts".to_string(),
             importable_file_path: None,
             start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
         })
     }
 
@@ -134,7 +137,8 @@ impl<'a, 'b> ModuleGenerator<'a, 'b> {
             .fragments()
             .filter_map(|fragment| self.stmt_fragment_overload(fragment));
 
-        self.ast.vec_from_iter(chain![operations, fragments])
+        self.ast
+            .vec_from_iter(chain![operations, fragments, self.stmt_tagged_template_overload()])
     }
 
     fn gen_type_exports(&self, items: impl Iterator<Item = String>) -> StmtVec<'b> {
@@ -613,13 +617,13 @@ type $$DataForConcreteSelection<TypeName extends string, Selection, Fields = $$O
                 let has_args = !field_def.arguments.is_empty();
                 let field_type = if self.schema.is_composite(field_type_name) {
                     if has_args {
-                        let args_type = self.ts_args_object_type(&field_def.arguments);
+                        let args_type = self.ts_args_object_type(field_def.arguments);
                         format!("$$CompositeField<{:?}, {}, Defs, Depth>", field_type_name, args_type)
                     } else {
                         format!("$$CompositeFieldNoArgs<{:?}, Defs, Depth>", field_type_name)
                     }
                 } else if has_args {
-                    let args_type = self.ts_args_object_type(&field_def.arguments);
+                    let args_type = self.ts_args_object_type(field_def.arguments);
                     format!("$$ScalarField<{}, Defs>", args_type)
                 } else {
                     "$$ScalarFieldNoArgs<Defs>".to_string()
@@ -1038,7 +1042,7 @@ type $$FragmentSpec<TypeName extends $$FragmentableTypeName, Defs extends $$Vari
         if directive.arguments.is_empty() {
             "true".to_owned()
         } else {
-            format!("$$Args<{}, Defs>", self.ts_args_object_type(&directive.arguments))
+            format!("$$Args<{}, Defs>", self.ts_args_object_type(directive.arguments))
         }
     }
 
@@ -1095,7 +1099,7 @@ type $$FragmentSpec<TypeName extends $$FragmentableTypeName, Defs extends $$Vari
     }
 
     fn ts_input_object_shape(&self, input_object: &InputObjectTypeDefinition<'b>) -> String {
-        self.ts_args_object_type(&input_object.fields)
+        self.ts_args_object_type(input_object.fields)
     }
 
     fn ts_graphql_input_shape(&self, graphql_type: &Type<'b>) -> String {
@@ -1162,7 +1166,7 @@ type $$FragmentSpec<TypeName extends $$FragmentableTypeName, Defs extends $$Vari
     }
 
     fn ts_fragment_vars_type(&self, fragment: &FragmentDefinition<'b>) -> String {
-        self.ts_variable_definitions_shape(&fragment.variable_definitions)
+        self.ts_variable_definitions_shape(fragment.variable_definitions)
     }
 
     fn ts_input_object_shape_name(&self, type_name: &str) -> String {
@@ -1213,6 +1217,56 @@ type $$FragmentSpec<TypeName extends $$FragmentableTypeName, Defs extends $$Vari
         self.stmt_export_value(function)
     }
 
+    /// Declares the tagged template form, e.g. `` graphql`query ...` ``.
+    ///
+    /// TypeScript types the strings of a tagged template as `TemplateStringsArray`
+    /// without their literal contents, so the document cannot select an overload.
+    /// The result is an opaque `Artifact` with unknown data and variables instead,
+    /// which does not claim to be any particular document. Fragment documents may be
+    /// interpolated and are accepted as rest arguments.
+    fn stmt_tagged_template_overload(&self) -> Option<Statement<'b>> {
+        let has_documents = self
+            .document
+            .operations()
+            .any(|operation| operation.name.is_some() && self.document.get_operation_source(operation).is_some())
+            || self
+                .document
+                .fragments()
+                .any(|fragment| self.document.get_fragment_source(fragment).is_some());
+
+        if !has_documents {
+            return None;
+        }
+
+        let return_type = self.type_import_from("mearie/types", "Artifact", None);
+        let return_annotation = self.ast.ts_type_annotation(SPAN, return_type);
+
+        let type_annotation = self.ast.ts_type_annotation(SPAN, self.type_ref("TemplateStringsArray"));
+        let param = self.create_formal_parameter("document", Some(type_annotation));
+
+        let fragments_type = self
+            .ast
+            .ts_type_array_type(SPAN, self.ast.ts_type_unknown_keyword(SPAN));
+        let fragments = self.ast.alloc_formal_parameter_rest(
+            SPAN,
+            self.ast.vec(),
+            self.ast
+                .binding_rest_element(SPAN, self.create_binding_pattern("fragments")),
+            Some(self.ast.alloc(self.ast.ts_type_annotation(SPAN, fragments_type))),
+        );
+
+        let params = self.ast.alloc(self.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::Signature,
+            self.ast.vec1(param),
+            Some(fragments),
+        ));
+
+        let function = self.decl_function("graphql", params, return_annotation, None);
+
+        Some(self.stmt_export_value(function))
+    }
+
     fn stmt_schema_declaration(&self) -> Statement<'b> {
         let schema_type = self.type_import("$Schema");
         let type_annotation = self.ast.ts_type_annotation(SPAN, schema_type);
@@ -52,6 +52,9 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
             file_path: "graphql.js".to_string(),
             importable_file_path: None,
             start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
         })
     }
 
@@ -127,7 +130,7 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
         };
 
         let selections = self.flatten_selections(&operation.selection_set, root_type)?;
-        let obj_expr = self.expr_artifact(name, &body, kind, &selections, Some(&operation.variable_definitions));
+        let obj_expr = self.expr_artifact(name, &body, kind, &selections, Some(operation.variable_definitions));
 
         let var_name = format!("${}", name);
 
@@ -152,7 +155,7 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
         body: &str,
         kind: &str,
         selections: &[SelectionNodeData<'b>],
-        variables: Option<&bumpalo::collections::Vec<'b, VariableDefinition<'b>>>,
+        variables: Option<&'b [VariableDefinition<'b>]>,
     ) -> Expression<'b> {
         let mut properties = self.ast.vec_from_array([
             self.prop_object("name", self.expr_string(name)),
@@ -210,8 +213,13 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
         ObjectPropertyKind::ObjectProperty(self.ast.alloc(property))
     }
 
+    /// Maps document sources to their artifacts. A source with several definitions maps
+    /// to the first one, the same artifact its `graphql()` overload is typed as.
     fn stmt_artifact_map(&self, artifacts: &[ArtifactRegistration<'b>]) -> Statement<'b> {
-        let properties = self.ast.vec_from_iter(artifacts.iter().map(|artifact| {
+        let mut seen_sources = FxHashSet::default();
+        let unique_artifacts = artifacts.iter().filter(|artifact| seen_sources.insert(artifact.source));
+
+        let properties = self.ast.vec_from_iter(unique_artifacts.map(|artifact| {
             let var_name = format!("${}", artifact.name);
             let var_ref = Expression::Identifier(
                 self.ast
@@ -269,6 +277,10 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
         ])
     }
 
+    /// Documents are looked up by the text they were extracted with. Template literals
+    /// passed to `graphql()` are extracted as the string they evaluate to. Tagged
+    /// templates pass their strings array instead, whose raw strings are joined into
+    /// the document with the fragment interpolations dropped.
     fn stmt_graphql_function(&self) -> Statement<'b> {
         let param_pattern = self.ast.binding_pattern_binding_identifier(SPAN, "artifact");
         let param = self.ast.formal_parameter(
@@ -291,10 +303,40 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
 
         let artifact_map_expr =
             Expression::Identifier(self.ast.alloc(self.ast.identifier_reference(SPAN, "artifactMap")));
-        let artifact_expr = Expression::Identifier(self.ast.alloc(self.ast.identifier_reference(SPAN, "artifact")));
+        let key_expr = self.ast.expression_conditional(
+            SPAN,
+            self.ast.expression_binary(
+                SPAN,
+                self.ast.expression_unary(
+                    SPAN,
+                    UnaryOperator::Typeof,
+                    self.ast.expression_identifier(SPAN, "artifact"),
+                ),
+                BinaryOperator::StrictEquality,
+                self.expr_string("string"),
+            ),
+            self.ast.expression_identifier(SPAN, "artifact"),
+            self.ast.expression_call(
+                SPAN,
+                Expression::from(self.ast.member_expression_static(
+                    SPAN,
+                    Expression::from(self.ast.member_expression_static(
+                        SPAN,
+                        self.ast.expression_identifier(SPAN, "artifact"),
+                        self.ast.identifier_name(SPAN, "raw"),
+                        false,
+                    )),
+                    self.ast.identifier_name(SPAN, "join"),
+                    false,
+                )),
+                None::<OxcBox<TSTypeParameterInstantiation>>,
+                self.ast.vec1(oxc_ast::ast::Argument::from(self.expr_string(""))),
+                false,
+            ),
+        );
         let member_expr = self
             .ast
-            .member_expression_computed(SPAN, artifact_map_expr, artifact_expr, false);
+            .member_expression_computed(SPAN, artifact_map_expr, key_expr, false);
 
         let expression_body = member_expr.into();
         let function_body = self.ast.function_body(
@@ -482,21 +524,13 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
     fn gen_schema(&self) -> Result<Statement<'b>> {
         let mut entity_properties = self.ast.vec();
 
-        for (type_name, type_info) in self.schema.types() {
-            if !matches!(type_info, TypeInfo::Object(_)) {
-                continue;
-            }
-
-            if Some(type_name) == self.schema.query_type()
-                || Some(type_name) == self.schema.mutation_type()
-                || Some(type_name) == self.schema.subscription_type()
-            {
-                continue;
-            }
-
-            if let Some(key_field) = self.determine_key_field(type_name) {
-                let key_fields_array_elements =
-                    self.ast.vec1(ArrayExpressionElement::from(self.expr_string(key_field)));
+        for (type_name, _) in self.schema.types() {
+            if let Some(key_fields) = self.schema.entity_key_fields(type_name) {
+                let key_fields_array_elements = self.ast.vec_from_iter(
+                    key_fields
+                        .iter()
+                        .map(|&key_field| ArrayExpressionElement::from(self.expr_string(key_field))),
+                );
                 let key_fields_array = Expression::ArrayExpression(
                     self.ast
                         .alloc(self.ast.array_expression(SPAN, key_fields_array_elements)),
@@ -606,10 +640,7 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
         Expression::ArrayExpression(self.ast.alloc(self.ast.array_expression(SPAN, elements)))
     }
 
-    fn expr_variable_defs_array(
-        &self,
-        variables: &bumpalo::collections::Vec<'b, VariableDefinition<'b>>,
-    ) -> Expression<'b> {
+    fn expr_variable_defs_array(&self, variables: &'b [VariableDefinition<'b>]) -> Expression<'b> {
         let elements = self.ast.vec_from_iter(
             variables
                 .iter()
@@ -813,8 +844,8 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
     }
 
     fn expr_arg_value(&self, value: &Value<'b>) -> Expression<'b> {
-        match value {
-            Value::Variable(name) => self.expr_variable_object(name.as_str()),
+        match &value.kind {
+            ValueKind::Variable(name) => self.expr_variable_object(name.as_str()),
             _ => {
                 let properties = self.ast.vec_from_array([
                     self.prop_object("kind", self.expr_string("literal")),
@@ -826,21 +857,21 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
     }
 
     fn expr_from_graphql_value(&self, value: &Value<'b>) -> Expression<'b> {
-        match value {
-            Value::Variable(name) => self.expr_variable_object(name.as_str()),
-            Value::Int(s) => {
+        match &value.kind {
+            ValueKind::Variable(name) => self.expr_variable_object(name.as_str()),
+            ValueKind::Int(s) => {
                 let num = s.parse::<i64>().unwrap_or(0) as f64;
                 self.expr_number(num)
             }
-            Value::Float(s) => {
+            ValueKind::Float(s) => {
                 let num = s.parse::<f64>().unwrap_or(0.0);
                 self.expr_number(num)
             }
-            Value::String(s) => self.expr_string(s),
-            Value::Boolean(b) => self.expr_boolean(*b),
-            Value::Null => self.expr_null(),
-            Value::Enum(name) => self.expr_string(name.as_str()),
-            Value::List(items) => {
+            ValueKind::String(s) => self.expr_string(s),
+            ValueKind::Boolean(b) => self.expr_boolean(*b),
+            ValueKind::Null => self.expr_null(),
+            ValueKind::Enum(name) => self.expr_string(name.as_str()),
+            ValueKind::List(items) => {
                 let elements = self.ast.vec_from_iter(
                     items
                         .iter()
@@ -848,7 +879,7 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
                 );
                 Expression::ArrayExpression(self.ast.alloc(self.ast.array_expression(SPAN, elements)))
             }
-            Value::Object(fields) => {
+            ValueKind::Object(fields) => {
                 let properties =
                     self.ast.vec_from_iter(fields.iter().map(|field| {
                         self.prop_object(field.name.as_str(), self.expr_from_graphql_value(&field.value))
@@ -888,37 +919,38 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
         }
 
         if !self.is_root_type(parent_type)
-            && self.schema.is_object(parent_type)
-            && let Some(key_field) = self.determine_key_field(parent_type)
+            && let Some(key_fields) = self.schema.entity_key_fields(parent_type)
         {
-            let has_key_field = selection_set
-                .selections
-                .iter()
-                .any(|s| matches!(s, Selection::Field(f) if f.name.as_str() == key_field));
-
-            if !has_key_field {
-                let field_def = self.schema.get_field(parent_type, key_field).ok_or_else(|| {
-                    MearieError::codegen(format!("Key field '{}' not found on type '{}'", key_field, parent_type))
-                })?;
-
-                let type_name = field_def.typ.innermost_type().to_string();
-                let is_array = field_def.typ.is_list();
-                let is_nullable = field_def.typ.is_nullable();
-
-                result.push(SelectionNodeData::Field {
-                    name: key_field,
-                    type_name: Some(type_name),
-                    array: Some(is_array),
-                    nullable: Some(is_nullable),
-                    alias: None,
-                    args: None,
-                    selections: None,
-                    directives: None,
-                });
+            for &key_field in key_fields {
+                let has_key_field = selection_set
+                    .selections
+                    .iter()
+                    .any(|s| matches!(s, Selection::Field(f) if f.name.as_str() == key_field));
+
+                if !has_key_field {
+                    let field_def = self.schema.get_field(parent_type, key_field).ok_or_else(|| {
+                        MearieError::codegen(format!("Key field '{}' not found on type '{}'", key_field, parent_type))
+                    })?;
+
+                    let type_name = field_def.typ.innermost_type().to_string();
+                    let is_array = field_def.typ.is_list();
+                    let is_nullable = field_def.typ.is_nullable();
+
+                    result.push(SelectionNodeData::Field {
+                        name: key_field,
+                        type_name: Some(type_name),
+                        array: Some(is_array),
+                        nullable: Some(is_nullable),
+                        alias: None,
+                        args: None,
+                        selections: None,
+                        directives: None,
+                    });
+                }
             }
         }
 
-        for selection in &selection_set.selections {
+        for selection in selection_set.selections {
             match selection {
                 Selection::Field(field) => {
                     let node = self.process_field(field, parent_type)?;
@@ -976,7 +1008,7 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
         let args = if field.arguments.is_empty() {
             None
         } else {
-            Some(&field.arguments[..])
+            Some(field.arguments)
         };
 
         let selections = if field.selection_set.is_empty() {
@@ -989,7 +1021,7 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
         let directives = if field.directives.is_empty() {
             None
         } else {
-            Some(&field.directives[..])
+            Some(field.directives)
         };
 
         Ok(SelectionNodeData::Field {
@@ -1013,7 +1045,7 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
         let selections = self.flatten_selections(&fragment.selection_set, fragment.type_condition.as_str())?;
 
         let args = if !spread.arguments.is_empty() || !fragment.variable_definitions.is_empty() {
-            Some(spread.arguments.as_slice())
+            Some(spread.arguments)
         } else {
             None
         };
@@ -1024,7 +1056,7 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
             directives: if spread.directives.is_empty() {
                 None
             } else {
-                Some(&spread.directives[..])
+                Some(spread.directives)
             },
             selections,
         })
@@ -1045,7 +1077,7 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
             directives: if inline.directives.is_empty() {
                 None
             } else {
-                Some(&inline.directives[..])
+                Some(inline.directives)
             },
             selections,
         })
@@ -1094,7 +1126,7 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
     fn collect_fragment_names(&self, selection_set: &SelectionSet<'b>) -> FxHashSet<&'b str> {
         let mut names = FxHashSet::default();
 
-        for selection in &selection_set.selections {
+        for selection in selection_set.selections {
             match selection {
                 Selection::Field(field) => {
                     if !field.selection_set.is_empty() {
@@ -1117,27 +1149,6 @@ impl<'a, 'b> RuntimeGenerator<'a, 'b> {
 
         names
     }
-
-    fn determine_key_field(&self, type_name: &str) -> Option<&'static str> {
-        const KEY_FIELD_NAMES: [&str; 3] = ["id", "_id", "uuid"];
-
-        let fields = self.schema.get_object_fields(type_name)?;
-
-        for &key_name in &KEY_FIELD_NAMES {
-            if let Some(&field_def) = fields.get(key_name) {
-                let is_nullable = field_def.typ.is_nullable();
-                let is_list = field_def.typ.is_list();
-                let innermost_type = field_def.typ.innermost_type().as_str();
-                let is_scalar = self.schema.is_scalar(innermost_type);
-
-                if !is_nullable && !is_list && is_scalar {
-                    return Some(key_name);
-                }
-            }
-        }
-
-        None
-    }
 }
 
 enum SelectionNodeData<'b> {
@@ -2,7 +2,7 @@ use super::super::CodegenContext;
 use crate::error::{MearieError, Result};
 use crate::graphql::ast::values::Value;
 use crate::graphql::ast::*;
-use crate::schema::{DocumentIndex, SchemaIndex, TypeInfo};
+use crate::schema::{DocumentIndex, SchemaIndex};
 use crate::source::SourceBuf;
 use itertools::chain;
 use oxc_allocator::Box as OxcBox;
@@ -59,6 +59,9 @@ impl<'a, 'b> TypesGenerator<'a, 'b> {
             file_path: "types.d.ts".to_string(),
             importable_file_path: None,
             start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
         })
     }
 
@@ -206,52 +209,27 @@ impl<'a, 'b> TypesGenerator<'a, 'b> {
         self.stmt_export_type("$Schema", schema_type)
     }
 
-    fn determine_key_field(&self, type_name: &str) -> Option<(&'static str, &'b str)> {
-        const KEY_FIELD_NAMES: [&str; 3] = ["id", "_id", "uuid"];
-
-        let fields = self.schema.get_object_fields(type_name)?;
-
-        for &key_name in &KEY_FIELD_NAMES {
-            if let Some(&field_def) = fields.get(key_name) {
-                let is_nullable = field_def.typ.is_nullable();
-                let is_list = field_def.typ.is_list();
-                let innermost_type = field_def.typ.innermost_type().as_str();
-                let is_scalar = self.schema.is_scalar(innermost_type);
-
-                if !is_nullable && !is_list && is_scalar {
-                    return Some((key_name, innermost_type));
-                }
-            }
-        }
-
-        None
-    }
-
     fn build_entities_type(&self) -> TSType<'b> {
         let mut entity_properties = self.ast.vec();
 
-        for (type_name, type_info) in self.schema.types() {
-            if !matches!(type_info, TypeInfo::Object(_)) {
-                continue;
-            }
-
-            if Some(type_name) == self.schema.query_type()
-                || Some(type_name) == self.schema.mutation_type()
-                || Some(type_name) == self.schema.subscription_type()
-            {
-                continue;
-            }
+        for (type_name, _) in self.schema.types() {
+            if let Some(key_fields) = self.schema.entity_key_fields(type_name) {
+                let key_fields_props = self.ast.vec_from_iter(key_fields.iter().map(|&key_field_name| {
+                    let key_field_type = self
+                        .schema
+                        .get_field(type_name, key_field_name)
+                        .map(|field_def| self.type_from_graphql(&field_def.typ, None, false))
+                        .unwrap_or_else(|| self.ast.ts_type_unknown_keyword(SPAN));
 
-            if let Some((key_field_name, scalar_type_name)) = self.determine_key_field(type_name) {
-                let key_field_type = self.type_scalar_ref(scalar_type_name);
-                let key_fields_props = self.ast.vec1(self.ast.ts_signature_property_signature(
-                    SPAN,
-                    false,
-                    false,
-                    false,
-                    self.ast.property_key_static_identifier(SPAN, key_field_name),
-                    Some(self.ast.ts_type_annotation(SPAN, key_field_type)),
-                ));
+                    self.ast.ts_signature_property_signature(
+                        SPAN,
+                        false,
+                        false,
+                        false,
+                        self.ast.property_key_static_identifier(SPAN, key_field_name),
+                        Some(self.ast.ts_type_annotation(SPAN, key_field_type)),
+                    )
+                }));
                 let key_fields_type = self.ast.ts_type_type_literal(SPAN, key_fields_props);
 
                 let fields_type = self.build_object_fields_union_type(type_name);
@@ -367,7 +345,7 @@ impl<'a, 'b> TypesGenerator<'a, 'b> {
         };
 
         let (data_type, _) = self.type_selection_set(&operation.selection_set, root_type)?;
-        let vars_type = self.type_variables(&operation.variable_definitions);
+        let vars_type = self.type_variables(operation.variable_definitions);
         let artifact_type = self.type_artifact(
             kind,
             name,
@@ -409,7 +387,7 @@ impl<'a, 'b> TypesGenerator<'a, 'b> {
         if has_vars {
             stmts.push(self.stmt_export_type(
                 &format!("{}$vars", fragment_name),
-                self.type_variables(&fragment.variable_definitions),
+                self.type_variables(fragment.variable_definitions),
             ));
         }
 
@@ -428,7 +406,7 @@ impl<'a, 'b> TypesGenerator<'a, 'b> {
         let mut inline_fragments: Vec<(&'b str, &InlineFragment<'b>)> = Vec::new();
         let mut fragment_refs: Vec<&'b str> = Vec::new();
 
-        for selection in &selection_set.selections {
+        for selection in selection_set.selections {
             match selection {
                 Selection::Field(field) => {
                     shared_fields.push(field);
@@ -465,11 +443,23 @@ impl<'a, 'b> TypesGenerator<'a, 'b> {
             match d.name.as_str() {
                 "skip" => {
                     // @skip(if: false) is a no-op → field always included
-                    !matches!(d.get_argument("if"), Some(Value::Boolean(false)))
+                    !matches!(
+                        d.get_argument("if"),
+                        Some(Value {
+                            kind: ValueKind::Boolean(false),
+                            ..
+                        })
+                    )
                 }
                 "include" => {
                     // @include(if: true) is a no-op → field always included
-                    !matches!(d.get_argument("if"), Some(Value::Boolean(true)))
+                    !matches!(
+                        d.get_argument("if"),
+                        Some(Value {
+                            kind: ValueKind::Boolean(true),
+                            ..
+                        })
+                    )
                 }
                 _ => false,
             }
@@ -482,7 +472,7 @@ impl<'a, 'b> TypesGenerator<'a, 'b> {
                 return false;
             }
             if let Some(action_value) = d.get_argument("action") {
-                matches!(action_value, Value::Enum(name) if name.as_str() == "CASCADE")
+                matches!(&action_value.kind, ValueKind::Enum(name) if name.as_str() == "CASCADE")
             } else {
                 false
             }
@@ -490,7 +480,7 @@ impl<'a, 'b> TypesGenerator<'a, 'b> {
     }
 
     fn cascade_escapes_selection_set(&self, selection_set: &SelectionSet<'b>, parent_type: &str) -> bool {
-        for selection in &selection_set.selections {
+        for selection in selection_set.selections {
             if let Selection::Field(field) = selection {
                 if self.has_cascade_action(field) {
                     return true;
@@ -612,7 +602,9 @@ impl<'a, 'b> TypesGenerator<'a, 'b> {
         fragment_refs: Vec<&'b str>,
     ) -> Result<TSType<'b>> {
         let possible_types: Vec<&'b str> = if self.schema.is_abstract(parent_type) {
-            self.schema.get_possible_types(parent_type).collect()
+            let mut possible: Vec<_> = self.schema.get_possible_types(parent_type).collect();
+            possible.sort_unstable();
+            possible
         } else {
             vec![parent_type]
         };
@@ -626,7 +618,10 @@ impl<'a, 'b> TypesGenerator<'a, 'b> {
                     branch_parts.push(self.build_fields_type(&shared_fields, type_condition)?);
                 }
 
-                if let Some((_, inline_fragment)) = inline_fragments.iter().find(|(t, _)| *t == type_condition) {
+                for (_, inline_fragment) in inline_fragments
+                    .iter()
+                    .filter(|(t, _)| self.schema.is_possible_type(t, type_condition))
+                {
                     let (inline_type, _) = self.type_selection_set(&inline_fragment.selection_set, type_condition)?;
                     branch_parts.push(inline_type);
                 }
@@ -1083,6 +1078,37 @@ mod tests {
         assert_contains!(source_buf.code, "__typename: \"Post\"");
     }
 
+    #[test]
+    fn test_inline_fragment_on_intermediate_interface_applies_to_implementors() {
+        let (ctx, schema_index, document_index) = setup_codegen!(
+            r#"
+                type Query { node: Node }
+                interface Node { id: ID! }
+                interface Resource implements Node { id: ID! url: String! }
+                type File implements Resource & Node { id: ID! url: String! size: Int! }
+                type User implements Node { id: ID! name: String! }
+            "#,
+            r#"
+                query GetNode {
+                    node {
+                        id
+                        ... on Resource { url }
+                        ... on File { size }
+                    }
+                }
+            "#
+        );
+
+        let generator = TypesGenerator::new(&ctx, &schema_index, &document_index);
+        let result = generator.generate();
+
+        assert_ok!(&result);
+        let source_buf = result.unwrap();
+        assert_contains!(source_buf.code, "url: $Scalars[\"String\"];");
+        assert_contains!(source_buf.code, "size: $Scalars[\"Int\"];");
+        assert_not_contains!(source_buf.code, "Resource");
+    }
+
     #[test]
     fn test_union_with_top_level_typename_generates_discriminated_union() {
         let (ctx, schema_index, document_index) = setup_codegen!(
@@ -18,6 +18,7 @@ macro_rules! setup_codegen {
         let schema_source = Box::leak(Box::new(Source::ephemeral($schema_code)));
         let schema_document = Parser::new(arena).with_source(schema_source).parse().unwrap();
         schema_builder.add_document(schema_document).unwrap();
+        assert!(schema_builder.apply_extensions(arena).is_empty());
         let schema_index = schema_builder.build();
 
         let operations_source = Box::leak(Box::new(Source::ephemeral($document_code)));
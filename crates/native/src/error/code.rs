@@ -0,0 +1,150 @@
+use serde::Serialize;
+use std::fmt;
+
+/// Stable, machine-readable identifier of a diagnostic.
+///
+/// Codes are serialized in kebab-case (e.g. `unknown-field`) and must not change once
+/// released, since editors and CI annotations filter and link documentation by them.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum ErrorCode {
+    // Parsing
+    SyntaxError,
+    InvalidIntrospection,
+
+    // Schema
+    DuplicateTypeDefinition,
+    DuplicateDirectiveDefinition,
+    InvalidExtension,
+    InvalidKeyFields,
+    InvalidInterfaceImplementation,
+    InvalidUnionMember,
+    InputObjectCycle,
+    InvalidFieldType,
+
+    // Documents and operations
+    DocumentDefinitionCount,
+    DuplicateOperationName,
+    OperationNameConflict,
+    MissingOperationName,
+    LoneAnonymousOperation,
+    SingleRootField,
+
+    // Fields and arguments
+    UnknownField,
+    UnknownArgument,
+    DuplicateArgument,
+    MissingRequiredArgument,
+    MissingSelectionSet,
+    UnexpectedSelectionSet,
+    OverlappingFields,
+
+    // Fragments
+    UnknownType,
+    UnknownFragment,
+    DuplicateFragmentName,
+    FragmentCycle,
+    FragmentOnNonCompositeType,
+    ImpossibleFragmentSpread,
+    ConflictingFragmentArguments,
+
+    // Variables
+    DuplicateVariableName,
+    NonInputVariableType,
+    UndefinedVariable,
+    UnusedVariable,
+    VariableTypeMismatch,
+
+    // Values and directives
+    InvalidValue,
+    UnknownInputField,
+    DuplicateInputField,
+    MissingRequiredInputField,
+    UnknownDirective,
+    DuplicateDirective,
+    MisplacedDirective,
+
+    // Deprecations
+    DeprecatedField,
+    DeprecatedArgument,
+    DeprecatedInputField,
+    DeprecatedEnumValue,
+}
+
+impl ErrorCode {
+    /// Returns the serialized form of the code.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::SyntaxError => "syntax-error",
+            ErrorCode::InvalidIntrospection => "invalid-introspection",
+            ErrorCode::DuplicateTypeDefinition => "duplicate-type-definition",
+            ErrorCode::DuplicateDirectiveDefinition => "duplicate-directive-definition",
+            ErrorCode::InvalidExtension => "invalid-extension",
+            ErrorCode::InvalidKeyFields => "invalid-key-fields",
+            ErrorCode::InvalidInterfaceImplementation => "invalid-interface-implementation",
+            ErrorCode::InvalidUnionMember => "invalid-union-member",
+            ErrorCode::InputObjectCycle => "input-object-cycle",
+            ErrorCode::InvalidFieldType => "invalid-field-type",
+            ErrorCode::DocumentDefinitionCount => "document-definition-count",
+            ErrorCode::DuplicateOperationName => "duplicate-operation-name",
+            ErrorCode::OperationNameConflict => "operation-name-conflict",
+            ErrorCode::MissingOperationName => "missing-operation-name",
+            ErrorCode::LoneAnonymousOperation => "lone-anonymous-operation",
+            ErrorCode::SingleRootField => "single-root-field",
+            ErrorCode::UnknownField => "unknown-field",
+            ErrorCode::UnknownArgument => "unknown-argument",
+            ErrorCode::DuplicateArgument => "duplicate-argument",
+            ErrorCode::MissingRequiredArgument => "missing-required-argument",
+            ErrorCode::MissingSelectionSet => "missing-selection-set",
+            ErrorCode::UnexpectedSelectionSet => "unexpected-selection-set",
+            ErrorCode::OverlappingFields => "overlapping-fields",
+            ErrorCode::UnknownType => "unknown-type",
+            ErrorCode::UnknownFragment => "unknown-fragment",
+            ErrorCode::DuplicateFragmentName => "duplicate-fragment-name",
+            ErrorCode::FragmentCycle => "fragment-cycle",
+            ErrorCode::FragmentOnNonCompositeType => "fragment-on-non-composite-type",
+            ErrorCode::ImpossibleFragmentSpread => "impossible-fragment-spread",
+            ErrorCode::ConflictingFragmentArguments => "conflicting-fragment-arguments",
+            ErrorCode::DuplicateVariableName => "duplicate-variable-name",
+            ErrorCode::NonInputVariableType => "non-input-variable-type",
+            ErrorCode::UndefinedVariable => "undefined-variable",
+            ErrorCode::UnusedVariable => "unused-variable",
+            ErrorCode::VariableTypeMismatch => "variable-type-mismatch",
+            ErrorCode::InvalidValue => "invalid-value",
+            ErrorCode::UnknownInputField => "unknown-input-field",
+            ErrorCode::DuplicateInputField => "duplicate-input-field",
+            ErrorCode::MissingRequiredInputField => "missing-required-input-field",
+            ErrorCode::UnknownDirective => "unknown-directive",
+            ErrorCode::DuplicateDirective => "duplicate-directive",
+            ErrorCode::MisplacedDirective => "misplaced-directive",
+            ErrorCode::DeprecatedField => "deprecated-field",
+            ErrorCode::DeprecatedArgument => "deprecated-argument",
+            ErrorCode::DeprecatedInputField => "deprecated-input-field",
+            ErrorCode::DeprecatedEnumValue => "deprecated-enum-value",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_str_matches_serialized_form() {
+        for code in [
+            ErrorCode::SyntaxError,
+            ErrorCode::UnknownField,
+            ErrorCode::FragmentCycle,
+            ErrorCode::FragmentOnNonCompositeType,
+            ErrorCode::DeprecatedEnumValue,
+        ] {
+            assert_eq!(serde_json::to_value(code).unwrap(), code.as_str());
+        }
+    }
+}
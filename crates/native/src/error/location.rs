@@ -8,6 +8,8 @@ pub struct Location {
     pub file_path: String,
     pub line: u32,
     pub column: Option<u32>,
+    pub end_line: Option<u32>,
+    pub end_column: Option<u32>,
 }
 
 impl Location {
@@ -17,19 +19,53 @@ impl Location {
             file_path: String::new(),
             line: 0,
             column: None,
+            end_line: None,
+            end_column: None,
         }
     }
 
+    /// Returns the location of `span` in `source`. The end position is exclusive, i.e.
+    /// it points just past the last character of the span.
+    ///
+    /// Positions are mapped to the host file of `source`, so a span inside a template
+    /// literal resolves to the line and column of the same character in the host file.
+    /// Spans in generated code resolve to the host code their mapping points at.
     pub fn from_span(source: &Source, span: Span) -> Self {
-        let (line, column) = Self::calculate_position(source.code, span.start);
+        if let Some(mapping) = source.mapping_at(span.start) {
+            return Self {
+                file_path: source.file_path.to_string(),
+                line: mapping.line,
+                column: Some(mapping.column),
+                end_line: Some(mapping.end_line),
+                end_column: Some(mapping.end_column),
+            };
+        }
+
+        let (line, column) = Self::position(source, span.start);
+        let (end_line, end_column) = Self::position(source, span.end.max(span.start));
 
         Self {
             file_path: source.file_path.to_string(),
-            line: source.start_line + line as u32 - 1,
-            column: Some(column as u32),
+            line,
+            column: Some(column),
+            end_line: Some(end_line),
+            end_column: Some(end_column),
         }
     }
 
+    /// Returns the 1-based line and column in the host file of the byte offset `pos`
+    /// in `source.code`. Only the first line of the code is shifted by `start_column`.
+    pub(crate) fn position(source: &Source, pos: usize) -> (u32, u32) {
+        let (line, column) = Self::calculate_position(source.code, pos);
+        let column = if line == 1 {
+            source.start_column + column as u32 - 1
+        } else {
+            column as u32
+        };
+
+        (source.start_line + line as u32 - 1, column)
+    }
+
     fn calculate_position(code: &str, pos: usize) -> (usize, usize) {
         let mut line = 1;
         let mut column = 1;
@@ -50,7 +86,7 @@ impl Location {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Span {
     pub start: usize,
     pub end: usize,
@@ -77,3 +113,82 @@ impl From<std::ops::Range<usize>> for Span {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::SourceMapping;
+
+    #[test]
+    fn test_from_span_includes_end_position() {
+        let source = Source {
+            code: "query Q {\n  user\n}",
+            file_path: "a.graphql",
+            start_line: 3,
+            start_column: 1,
+            offset: 0,
+            mappings: &[],
+        };
+
+        let location = Location::from_span(&source, Span::new(12, 16));
+        assert_eq!(location.line, 4);
+        assert_eq!(location.column, Some(3));
+        assert_eq!(location.end_line, Some(4));
+        assert_eq!(location.end_column, Some(7));
+
+        let location = Location::from_span(&source, Span::new(0, 18));
+        assert_eq!(location.end_line, Some(5));
+        assert_eq!(location.end_column, Some(2));
+    }
+
+    #[test]
+    fn test_from_span_shifts_first_line_by_start_column() {
+        let source = Source {
+            code: "query Q {\n  user\n}",
+            file_path: "a.tsx",
+            start_line: 3,
+            start_column: 25,
+            offset: 60,
+            mappings: &[],
+        };
+
+        let location = Location::from_span(&source, Span::new(6, 7));
+        assert_eq!(location.line, 3);
+        assert_eq!(location.column, Some(31));
+        assert_eq!(location.end_column, Some(32));
+
+        let location = Location::from_span(&source, Span::new(12, 16));
+        assert_eq!(location.line, 4);
+        assert_eq!(location.column, Some(3));
+    }
+
+    #[test]
+    fn test_from_span_uses_innermost_mapping() {
+        let mapping = |generated_start, generated_end, line, column| SourceMapping {
+            generated_start,
+            generated_end,
+            line,
+            column,
+            end_line: line,
+            end_column: column + 4,
+        };
+        let mappings = [mapping(0, 18, 7, 5), mapping(12, 17, 9, 11)];
+        let source = Source {
+            code: "query Q {\n  user\n}",
+            file_path: "a.ts",
+            start_line: 7,
+            start_column: 5,
+            offset: 0,
+            mappings: &mappings,
+        };
+
+        let location = Location::from_span(&source, Span::new(12, 16));
+        assert_eq!(location.line, 9);
+        assert_eq!(location.column, Some(11));
+        assert_eq!(location.end_column, Some(15));
+
+        let location = Location::from_span(&source, Span::new(6, 7));
+        assert_eq!(location.line, 7);
+        assert_eq!(location.column, Some(5));
+    }
+}
@@ -1,5 +1,8 @@
+pub mod code;
 pub mod location;
+pub mod suggestion;
 
+pub use code::ErrorCode;
 pub use location::{Location, Span};
 
 use serde::Serialize;
@@ -14,11 +17,36 @@ pub enum Stage {
     Codegen,
 }
 
+/// How seriously a diagnostic should be treated by consumers.
+///
+/// Only `Error` diagnostics should fail a build; warnings and infos are reported
+/// alongside generated output.
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    #[default]
+    Error,
+}
+
+/// A secondary location attached to a diagnostic, such as the other half of a
+/// conflict or the original definition of a duplicate.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RelatedLocation {
+    pub message: String,
+    pub location: Location,
+}
+
 #[derive(Debug, Clone)]
 struct ErrorInner {
     message: String,
     location: Option<Location>,
     stage: Stage,
+    severity: Severity,
+    code: Option<ErrorCode>,
+    related: Vec<RelatedLocation>,
+    suggestions: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -33,6 +61,10 @@ impl MearieError {
                 message: message.into(),
                 location: None,
                 stage: Stage::Extraction,
+                severity: Severity::Error,
+                code: None,
+                related: Vec::new(),
+                suggestions: Vec::new(),
             }),
         }
     }
@@ -43,6 +75,10 @@ impl MearieError {
                 message: message.into(),
                 location: None,
                 stage: Stage::Parse,
+                severity: Severity::Error,
+                code: None,
+                related: Vec::new(),
+                suggestions: Vec::new(),
             }),
         }
     }
@@ -53,6 +89,10 @@ impl MearieError {
                 message: message.into(),
                 location: None,
                 stage: Stage::Validation,
+                severity: Severity::Error,
+                code: None,
+                related: Vec::new(),
+                suggestions: Vec::new(),
             }),
         }
     }
@@ -63,6 +103,10 @@ impl MearieError {
                 message: message.into(),
                 location: None,
                 stage: Stage::Codegen,
+                severity: Severity::Error,
+                code: None,
+                related: Vec::new(),
+                suggestions: Vec::new(),
             }),
         }
     }
@@ -77,6 +121,38 @@ impl MearieError {
         self
     }
 
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.inner.severity = severity;
+        self
+    }
+
+    pub fn with_code(mut self, code: ErrorCode) -> Self {
+        self.inner.code = Some(code);
+        self
+    }
+
+    pub fn with_related(mut self, message: impl Into<String>, location: Location) -> Self {
+        self.inner.related.push(RelatedLocation {
+            message: message.into(),
+            location,
+        });
+        self
+    }
+
+    /// Attaches "did you mean" suggestions and appends them as a hint to the message.
+    pub fn with_suggestions<S: Into<String>>(mut self, suggestions: impl IntoIterator<Item = S>) -> Self {
+        self.inner.suggestions.extend(suggestions.into_iter().map(Into::into));
+        if let Some(hint) = suggestion::did_you_mean(&self.inner.suggestions) {
+            let separator = if self.inner.message.ends_with(['.', '?', '!']) {
+                " "
+            } else {
+                ". "
+            };
+            self.inner.message = format!("{}{}{}", self.inner.message, separator, hint);
+        }
+        self
+    }
+
     pub fn location(&self) -> Option<&Location> {
         self.inner.location.as_ref()
     }
@@ -88,6 +164,28 @@ impl MearieError {
     pub fn stage(&self) -> Stage {
         self.inner.stage
     }
+
+    pub fn severity(&self) -> Severity {
+        self.inner.severity
+    }
+
+    pub fn code(&self) -> Option<ErrorCode> {
+        self.inner.code
+    }
+
+    pub fn related(&self) -> &[RelatedLocation] {
+        &self.inner.related
+    }
+
+    pub fn suggestions(&self) -> &[String] {
+        &self.inner.suggestions
+    }
+
+    /// Returns true if this diagnostic should be treated as fatal.
+    #[inline]
+    pub fn is_error(&self) -> bool {
+        self.inner.severity == Severity::Error
+    }
 }
 
 impl fmt::Display for MearieError {
@@ -109,12 +207,23 @@ impl Serialize for MearieError {
             #[serde(skip_serializing_if = "Option::is_none")]
             location: &'a Option<Location>,
             stage: Stage,
+            severity: Severity,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            code: Option<ErrorCode>,
+            #[serde(skip_serializing_if = "<[_]>::is_empty")]
+            related: &'a [RelatedLocation],
+            #[serde(skip_serializing_if = "<[_]>::is_empty")]
+            suggestions: &'a [String],
         }
 
         SerializableError {
             message: &self.inner.message,
             location: &self.inner.location,
             stage: self.inner.stage,
+            severity: self.inner.severity,
+            code: self.inner.code,
+            related: &self.inner.related,
+            suggestions: &self.inner.suggestions,
         }
         .serialize(serializer)
     }
@@ -0,0 +1,103 @@
+/// Maximum number of suggestions attached to a single diagnostic.
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Returns the options that are close enough to `input` to be worth suggesting,
+/// ordered from the closest match.
+///
+/// Closeness is the optimal string alignment distance (Levenshtein plus adjacent
+/// transpositions). A case-only difference always counts as a distance of one, and
+/// options further than 40% of the input length away are dropped.
+pub fn suggestion_list<'o>(input: &str, options: impl IntoIterator<Item = &'o str>) -> Vec<&'o str> {
+    let input_lower: Vec<char> = input.to_lowercase().chars().collect();
+    let threshold = input.chars().count() * 2 / 5 + 1;
+
+    let mut matches: Vec<(usize, &'o str)> = options
+        .into_iter()
+        .filter(|option| *option != input)
+        .filter_map(|option| {
+            let distance = if option.to_lowercase().chars().eq(input_lower.iter().copied()) {
+                1
+            } else {
+                let option_lower: Vec<char> = option.to_lowercase().chars().collect();
+                lexical_distance(&input_lower, &option_lower)
+            };
+            (distance <= threshold).then_some((distance, option))
+        })
+        .collect();
+
+    matches.sort_unstable();
+    matches.dedup_by_key(|(_, option)| *option);
+    matches.truncate(MAX_SUGGESTIONS);
+    matches.into_iter().map(|(_, option)| option).collect()
+}
+
+/// Formats suggestions as a trailing hint, e.g. `Did you mean 'a', 'b' or 'c'?`.
+pub fn did_you_mean<S: AsRef<str>>(suggestions: &[S]) -> Option<String> {
+    let (last, rest) = suggestions.split_last()?;
+    let quote = |s: &S| format!("'{}'", s.as_ref());
+
+    if rest.is_empty() {
+        return Some(format!("Did you mean {}?", quote(last)));
+    }
+
+    let rest = rest.iter().map(quote).collect::<Vec<_>>().join(", ");
+    Some(format!("Did you mean {} or {}?", rest, quote(last)))
+}
+
+fn lexical_distance(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+
+            rows[i][j] = distance;
+        }
+    }
+
+    rows[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggestion_list_orders_by_distance() {
+        let options = ["name", "names", "nickname", "id"];
+        assert_eq!(suggestion_list("nam", options), vec!["name", "names"]);
+    }
+
+    #[test]
+    fn test_suggestion_list_case_and_transposition() {
+        assert_eq!(suggestion_list("USER", ["User", "Users"]), vec!["User", "Users"]);
+        assert_eq!(suggestion_list("uesr", ["user"]), vec!["user"]);
+    }
+
+    #[test]
+    fn test_suggestion_list_no_match() {
+        assert!(suggestion_list("zzz", ["name", "id"]).is_empty());
+        assert!(suggestion_list("name", ["name"]).is_empty());
+    }
+
+    #[test]
+    fn test_did_you_mean() {
+        assert_eq!(did_you_mean::<&str>(&[]), None);
+        assert_eq!(did_you_mean(&["a"]).unwrap(), "Did you mean 'a'?");
+        assert_eq!(did_you_mean(&["a", "b", "c"]).unwrap(), "Did you mean 'a', 'b' or 'c'?");
+    }
+}
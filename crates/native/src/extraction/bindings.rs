@@ -0,0 +1,103 @@
+use super::config::ExtractionConfig;
+use oxc_ast::ast::*;
+use oxc_semantic::{ReferenceId, SemanticBuilder, SymbolFlags};
+use std::collections::HashSet;
+
+const GRAPHQL_EXPORT: &str = "graphql";
+
+/// References to the `graphql` function in a module, and template literals marked as
+/// GraphQL with a `/* GraphQL */` comment.
+///
+/// A reference is resolved through the scope tree, so only references bound to a named
+/// `graphql` import from one of the configured modules are recognized, under any alias.
+/// A `graphql` parameter or declaration in any scope shadows the import. With
+/// [`ExtractionConfig::global_graphql`], references to a `graphql` that is not declared
+/// in the module, or only declared ambiently, are accepted as well.
+#[derive(Debug, Clone, Default)]
+pub(super) struct GraphqlBindings {
+    references: HashSet<ReferenceId>,
+    /// Start offsets of the code that `/* GraphQL */` comments are attached to.
+    comment_targets: HashSet<u32>,
+}
+
+impl GraphqlBindings {
+    pub(super) fn collect(program: &Program, config: &ExtractionConfig) -> Self {
+        let semantic = SemanticBuilder::new().build(program).semantic;
+        let scoping = semantic.scoping();
+        let mut references = HashSet::new();
+
+        for statement in &program.body {
+            let Statement::ImportDeclaration(import) = statement else {
+                continue;
+            };
+            if import.import_kind.is_type()
+                || !config
+                    .graphql_modules
+                    .iter()
+                    .any(|module| module == import.source.value.as_str())
+            {
+                continue;
+            }
+
+            for specifier in import.specifiers.iter().flatten() {
+                if let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier
+                    && !specifier.import_kind.is_type()
+                    && specifier.imported.name() == GRAPHQL_EXPORT
+                    && let Some(symbol_id) = specifier.local.symbol_id.get()
+                {
+                    references.extend(scoping.get_resolved_reference_ids(symbol_id).iter().copied());
+                }
+            }
+        }
+
+        if config.global_graphql {
+            for (name, reference_ids) in scoping.root_unresolved_references() {
+                if name.as_str() == GRAPHQL_EXPORT {
+                    references.extend(reference_ids.iter().copied());
+                }
+            }
+
+            // Ambient declarations such as `declare const graphql` only describe a global.
+            for symbol_id in scoping.symbol_ids() {
+                if scoping.symbol_name(symbol_id) == GRAPHQL_EXPORT
+                    && scoping.symbol_flags(symbol_id).contains(SymbolFlags::Ambient)
+                    && scoping.symbol_scope_id(symbol_id) == scoping.root_scope_id()
+                {
+                    references.extend(scoping.get_resolved_reference_ids(symbol_id).iter().copied());
+                }
+            }
+        }
+
+        let comment_targets = program
+            .comments
+            .iter()
+            .filter(|comment| {
+                comment.is_block()
+                    && comment.is_leading()
+                    && program.source_text[comment.content_span()]
+                        .trim()
+                        .eq_ignore_ascii_case(GRAPHQL_EXPORT)
+            })
+            .map(|comment| comment.attached_to)
+            .collect();
+
+        Self {
+            references,
+            comment_targets,
+        }
+    }
+
+    /// Returns whether `expr` refers to the `graphql` function.
+    pub(super) fn is_graphql(&self, expr: &Expression) -> bool {
+        matches!(
+            expr,
+            Expression::Identifier(identifier)
+                if identifier.reference_id.get().is_some_and(|id| self.references.contains(&id))
+        )
+    }
+
+    /// Returns whether `template` is marked as GraphQL, e.g. `` /* GraphQL */ `query ...` ``.
+    pub(super) fn is_graphql_template(&self, template: &TemplateLiteral) -> bool {
+        self.comment_targets.contains(&template.span.start)
+    }
+}
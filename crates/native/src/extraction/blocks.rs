@@ -0,0 +1,556 @@
+//! Splitting of component and Markdown files into the script blocks GraphQL is
+//! extracted from.
+//!
+//! Vue, Svelte and Astro components are scanned for `<script>` elements and Astro
+//! frontmatter, and Markdown files for fenced code blocks marked with `mearie`. Vue
+//! `<graphql>` custom blocks become blocks of GraphQL code. This only locates blocks,
+//! so it does not need the compilers of those frameworks.
+
+use crate::error::location::Location;
+use crate::source::{Source, SourceBuf};
+use std::ops::Range;
+
+/// Elements without a closing tag in HTML.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr",
+];
+
+/// Splits `source` into the blocks of script code it contains, positioned in the host
+/// file. Sources that are not components or Markdown files are returned as is.
+pub fn split_source_blocks(source: SourceBuf) -> Vec<SourceBuf> {
+    let extension = source
+        .file_path
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_ascii_lowercase());
+
+    match extension.as_deref() {
+        Some("vue") => vue_blocks(&source),
+        Some("svelte") => svelte_blocks(&source),
+        Some("astro") => astro_blocks(&source),
+        Some("md") => markdown_blocks(&source),
+        _ => vec![source],
+    }
+}
+
+/// Returns the `<script>` and `<script setup>` blocks of a Vue component, followed by
+/// its `<graphql>` custom blocks. The scripts keep the path of the component, so that
+/// their bindings are registered together. Custom blocks get a `.graphql` path, so that
+/// they are extracted as GraphQL documents.
+fn vue_blocks(source: &SourceBuf) -> Vec<SourceBuf> {
+    let elements = raw_text_elements(&source.code, 0, &["graphql"]);
+    let top_level_scripts = || {
+        elements
+            .iter()
+            .filter(|element| element.depth == 0 && element.name == "script")
+    };
+    let script = top_level_scripts().find(|script| !has_attribute(script.attributes, "setup"));
+    let script_setup = top_level_scripts().find(|script| has_attribute(script.attributes, "setup"));
+
+    let scripts = [script, script_setup].into_iter().flatten().map(|script| {
+        block(
+            source,
+            script.content.clone(),
+            source.file_path.clone(),
+            source.importable_file_path.clone(),
+        )
+    });
+
+    let documents = elements
+        .iter()
+        .filter(|element| element.depth == 0 && element.name == "graphql")
+        .enumerate()
+        .map(|(index, document)| {
+            block(
+                source,
+                document.content.clone(),
+                format!("{}.{}.graphql", source.file_path, index),
+                Some(importable_file_path(source)),
+            )
+        });
+
+    scripts.chain(documents).collect()
+}
+
+/// Returns the instance and module scripts of a Svelte component, declared with
+/// `<script module>` or `<script context="module">`.
+fn svelte_blocks(source: &SourceBuf) -> Vec<SourceBuf> {
+    let scripts = script_elements(&source.code, 0);
+    let is_module = |script: &&RawTextElement| {
+        has_attribute(script.attributes, "module")
+            || attribute_value(script.attributes, "context").is_some_and(|context| context == "module")
+    };
+    let instance = scripts.iter().find(|script| script.depth == 0 && !is_module(script));
+    let module = scripts.iter().find(|script| script.depth == 0 && is_module(script));
+
+    [(instance, "instance"), (module, "module")]
+        .into_iter()
+        .filter_map(|(script, kind)| Some((script?, kind)))
+        .map(|(script, kind)| {
+            block(
+                source,
+                script.content.clone(),
+                format!("{}.{}.ts", source.file_path, kind),
+                Some(importable_file_path(source)),
+            )
+        })
+        .collect()
+}
+
+/// Returns the frontmatter and every `<script>` element of an Astro component.
+fn astro_blocks(source: &SourceBuf) -> Vec<SourceBuf> {
+    let mut blocks = Vec::new();
+    let mut markup_start = 0;
+
+    if let Some(frontmatter) = astro_frontmatter(&source.code) {
+        markup_start = frontmatter.end;
+        blocks.push(block(
+            source,
+            frontmatter,
+            format!("{}.frontmatter.ts", source.file_path),
+            Some(importable_file_path(source)),
+        ));
+    }
+
+    for (index, script) in script_elements(&source.code, markup_start).into_iter().enumerate() {
+        blocks.push(block(
+            source,
+            script.content,
+            format!("{}.{}.ts", source.file_path, index),
+            Some(importable_file_path(source)),
+        ));
+    }
+
+    blocks
+}
+
+/// Returns the code between the `---` fences at the start of an Astro component.
+fn astro_frontmatter(code: &str) -> Option<Range<usize>> {
+    let rest = code.strip_prefix("---")?;
+    let whitespace = &rest[..rest.len() - rest.trim_start().len()];
+    let start = 3 + whitespace.rfind('\n')? + 1;
+    let end = start - 1 + code[start - 1..].find("\n---")?;
+
+    Some(start..end.max(start))
+}
+
+/// Returns the code of fenced `ts` and `tsx` blocks whose info string mentions `mearie`,
+/// such as ```` ```tsx mearie ````.
+fn markdown_blocks(source: &SourceBuf) -> Vec<SourceBuf> {
+    let code = source.code.as_str();
+    let mut blocks = Vec::new();
+    let mut position = 0;
+
+    while let Some(fence) = code[position..].find("```").map(|index| position + index) {
+        let info_start = fence + 3;
+        let info_end = code[info_start..]
+            .find('\n')
+            .map_or(code.len(), |index| info_start + index);
+        let info = &code[info_start..info_end];
+        let language = ["tsx", "ts"].into_iter().find(|language| info.starts_with(language));
+
+        let Some(language) = language.filter(|language| info[language.len()..].contains("mearie")) else {
+            position = fence + 1;
+            continue;
+        };
+
+        let start = (info_end + 1).min(code.len());
+        let Some(end) = code[start..].find("```").map(|index| start + index) else {
+            break;
+        };
+
+        blocks.push(block(
+            source,
+            start..end,
+            format!("{}.{}.{}", source.file_path, blocks.len(), language),
+            Some(importable_file_path(source)),
+        ));
+        position = end + 3;
+    }
+
+    blocks
+}
+
+fn importable_file_path(source: &SourceBuf) -> String {
+    source
+        .importable_file_path
+        .clone()
+        .unwrap_or_else(|| source.file_path.clone())
+}
+
+/// Creates a source for the code at `range` in `source`.
+fn block(
+    source: &SourceBuf,
+    range: Range<usize>,
+    file_path: String,
+    importable_file_path: Option<String>,
+) -> SourceBuf {
+    let (start_line, start_column) = Location::position(&Source::from(source), range.start);
+
+    SourceBuf {
+        code: source.code[range.clone()].to_string(),
+        file_path,
+        importable_file_path,
+        start_line,
+        start_column,
+        offset: source.offset + range.start as u32,
+        mappings: None,
+    }
+}
+
+/// An element whose content is text rather than markup, such as `<script>`.
+struct RawTextElement<'a> {
+    /// The lowercase tag name.
+    name: String,
+    attributes: &'a str,
+    content: Range<usize>,
+    /// The number of elements the element is nested in.
+    depth: usize,
+}
+
+/// Finds the `<script>` elements in the markup starting at `start`.
+fn script_elements(code: &str, start: usize) -> Vec<RawTextElement<'_>> {
+    raw_text_elements(code, start, &[])
+        .into_iter()
+        .filter(|element| element.name == "script")
+        .collect()
+}
+
+/// Finds the `<script>`, `<style>` and `custom` elements in the markup starting at
+/// `start`, whose contents are not markup.
+///
+/// Comments, the contents of those elements and `{...}` expressions are skipped, so
+/// that tags inside them are not mistaken for elements.
+fn raw_text_elements<'a>(code: &'a str, start: usize, custom: &[&str]) -> Vec<RawTextElement<'a>> {
+    let bytes = code.as_bytes();
+    let mut elements = Vec::new();
+    let mut depth = 0usize;
+    let mut position = start;
+
+    while position < bytes.len() {
+        match bytes[position] {
+            b'{' => position = skip_braces(bytes, position),
+            b'<' if code[position..].starts_with("<!--") => {
+                position = code[position..]
+                    .find("-->")
+                    .map_or(bytes.len(), |index| position + index + 3);
+            }
+            b'<' if bytes.get(position + 1) == Some(&b'/') => {
+                depth = depth.saturating_sub(1);
+                position = code[position..]
+                    .find('>')
+                    .map_or(bytes.len(), |index| position + index + 1);
+            }
+            b'<' if bytes.get(position + 1).is_some_and(u8::is_ascii_alphabetic) => {
+                let name_end = position
+                    + 1
+                    + bytes[position + 1..]
+                        .iter()
+                        .position(|byte| !(byte.is_ascii_alphanumeric() || b"-:._".contains(byte)))
+                        .unwrap_or(bytes.len() - position - 1);
+                let name = code[position + 1..name_end].to_ascii_lowercase();
+                let tag_end = skip_tag(bytes, name_end);
+                let self_closing = bytes[..tag_end].ends_with(b"/>");
+                position = tag_end;
+
+                if self_closing && custom.contains(&name.as_str()) {
+                    continue;
+                }
+
+                if name == "script" || name == "style" || custom.contains(&name.as_str()) {
+                    let Some(close) = find_ignore_case(code, position, &format!("</{}", name)) else {
+                        break;
+                    };
+                    if !self_closing {
+                        elements.push(RawTextElement {
+                            attributes: code[name_end..tag_end].trim_end_matches('>').trim_end_matches('/'),
+                            content: position..close,
+                            depth,
+                            name,
+                        });
+                    }
+                    position = code[close..].find('>').map_or(bytes.len(), |index| close + index + 1);
+                } else if !self_closing && !VOID_ELEMENTS.contains(&name.as_str()) {
+                    depth += 1;
+                }
+            }
+            _ => position += 1,
+        }
+    }
+
+    elements
+}
+
+/// Returns the position after the `>` ending the tag whose attributes start at `position`.
+fn skip_tag(bytes: &[u8], mut position: usize) -> usize {
+    while position < bytes.len() {
+        match bytes[position] {
+            b'>' => return position + 1,
+            quote @ (b'"' | b'\'') => {
+                position = bytes[position + 1..]
+                    .iter()
+                    .position(|&byte| byte == quote)
+                    .map_or(bytes.len(), |index| position + index + 2);
+            }
+            b'{' => position = skip_braces(bytes, position),
+            _ => position += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+/// Returns the position after the `}` matching the `{` at `position`.
+fn skip_braces(bytes: &[u8], mut position: usize) -> usize {
+    let mut depth = 0usize;
+
+    while position < bytes.len() {
+        match bytes[position] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return position + 1;
+                }
+            }
+            quote @ (b'"' | b'\'' | b'`') => {
+                position += 1;
+                while position < bytes.len() && bytes[position] != quote {
+                    if bytes[position] == b'\\' {
+                        position += 1;
+                    }
+                    position += 1;
+                }
+            }
+            _ => {}
+        }
+        position += 1;
+    }
+
+    bytes.len()
+}
+
+fn find_ignore_case(code: &str, start: usize, needle: &str) -> Option<usize> {
+    code.as_bytes()[start..]
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+        .map(|index| start + index)
+}
+
+/// Parses the attributes of a tag into names and optional unquoted values.
+fn attributes(source: &str) -> impl Iterator<Item = (&str, Option<&str>)> {
+    let mut rest = source;
+
+    std::iter::from_fn(move || {
+        rest = rest.trim_start_matches(|ch: char| ch.is_ascii_whitespace() || ch == '/');
+        if rest.is_empty() {
+            return None;
+        }
+
+        let name_end = rest
+            .find(|ch: char| ch.is_ascii_whitespace() || ch == '=' || ch == '/')
+            .unwrap_or(rest.len());
+        let name = &rest[..name_end];
+        rest = rest[name_end..].trim_start();
+
+        let Some(value) = rest.strip_prefix('=') else {
+            return Some((name, None));
+        };
+        let value = value.trim_start();
+
+        let (attribute_value, remainder) = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let end = value[1..].find(quote).map_or(value.len(), |index| index + 1);
+                (&value[1..end], value.get(end + 1..).unwrap_or_default())
+            }
+            _ => {
+                let end = value.find(|ch: char| ch.is_ascii_whitespace()).unwrap_or(value.len());
+                (&value[..end], &value[end..])
+            }
+        };
+        rest = remainder;
+
+        Some((name, Some(attribute_value)))
+    })
+}
+
+fn has_attribute(source: &str, name: &str) -> bool {
+    attributes(source).any(|(attribute, _)| attribute.eq_ignore_ascii_case(name))
+}
+
+fn attribute_value<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    attributes(source)
+        .find(|(attribute, _)| attribute.eq_ignore_ascii_case(name))
+        .and_then(|(_, value)| value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_source(file_path: &str, code: &str) -> SourceBuf {
+        SourceBuf {
+            code: code.to_string(),
+            file_path: file_path.to_string(),
+            importable_file_path: None,
+            start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
+        }
+    }
+
+    #[test]
+    fn test_split_vue_scripts() {
+        let code = r#"<template>
+  <div :title="a > b" @click="() => { open = true }">{{ a < b ? '<script>' : '' }}</div>
+  <template v-if="ok"><br></template>
+</template>
+
+<script setup lang="ts">
+const setup = true;
+</script>
+
+<script lang="ts">
+export const script = true;
+</script>
+
+<style>
+a > b { color: red; }
+</style>
+"#;
+
+        let blocks = split_source_blocks(create_source("/project/UserCard.vue", code));
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].code, "\nexport const script = true;\n");
+        assert_eq!(blocks[0].file_path, "/project/UserCard.vue");
+        assert_eq!(blocks[0].importable_file_path, None);
+        assert_eq!((blocks[0].start_line, blocks[0].start_column), (10, 19));
+        assert_eq!(blocks[1].code, "\nconst setup = true;\n");
+        assert_eq!((blocks[1].start_line, blocks[1].start_column), (6, 25));
+        assert_eq!(blocks[1].offset as usize, code.find("\nconst setup").unwrap());
+    }
+
+    #[test]
+    fn test_split_vue_graphql_blocks() {
+        let code = r#"<template>
+  <graphql-view />
+</template>
+
+<graphql>
+query GetUser {
+  user { name } # the user's name
+}
+</graphql>
+
+<script setup lang="ts">
+const setup = true;
+</script>
+"#;
+
+        let blocks = split_source_blocks(create_source("/project/UserCard.vue", code));
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].code, "\nconst setup = true;\n");
+        assert_eq!(
+            blocks[1].code,
+            "\nquery GetUser {\n  user { name } # the user's name\n}\n"
+        );
+        assert_eq!(blocks[1].file_path, "/project/UserCard.vue.0.graphql");
+        assert_eq!(blocks[1].importable_file_path.as_deref(), Some("/project/UserCard.vue"));
+        assert_eq!((blocks[1].start_line, blocks[1].start_column), (5, 10));
+    }
+
+    #[test]
+    fn test_split_svelte_scripts() {
+        let code = r#"<script context="module">
+  export const prerender = true;
+</script>
+
+<script lang="ts">
+  let count = 0;
+</script>
+
+<svelte:head>
+  <script type="application/ld+json">{"@type": "Person"}</script>
+</svelte:head>
+
+<button on:click={() => count < 10 && count++}>{count}</button>
+"#;
+
+        let blocks = split_source_blocks(create_source("/project/Counter.svelte", code));
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].code, "\n  let count = 0;\n");
+        assert_eq!(blocks[0].file_path, "/project/Counter.svelte.instance.ts");
+        assert_eq!(
+            blocks[0].importable_file_path.as_deref(),
+            Some("/project/Counter.svelte")
+        );
+        assert_eq!(blocks[0].start_line, 5);
+        assert_eq!(blocks[1].code, "\n  export const prerender = true;\n");
+        assert_eq!(blocks[1].file_path, "/project/Counter.svelte.module.ts");
+
+        let blocks = split_source_blocks(create_source("/project/Counter.svelte", "<script module>\n</script>"));
+        assert_eq!(blocks[0].file_path, "/project/Counter.svelte.module.ts");
+    }
+
+    #[test]
+    fn test_split_astro_frontmatter_and_scripts() {
+        let code = r#"---
+const title = '<script>';
+---
+<h1>{title}</h1>
+<div><script>
+  document.title = 'ready';
+</script></div>
+"#;
+
+        let blocks = split_source_blocks(create_source("/project/Page.astro", code));
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].code, "const title = '<script>';");
+        assert_eq!(blocks[0].file_path, "/project/Page.astro.frontmatter.ts");
+        assert_eq!(blocks[0].importable_file_path.as_deref(), Some("/project/Page.astro"));
+        assert_eq!((blocks[0].start_line, blocks[0].start_column), (2, 1));
+        assert_eq!(blocks[1].code, "\n  document.title = 'ready';\n");
+        assert_eq!(blocks[1].file_path, "/project/Page.astro.0.ts");
+        assert_eq!((blocks[1].start_line, blocks[1].start_column), (5, 14));
+    }
+
+    #[test]
+    fn test_split_markdown_code_blocks() {
+        let code = r#"# Queries
+
+```ts
+const ignored = true;
+```
+
+```tsx mearie
+const first = true;
+```
+
+```ts title="query.ts" mearie
+const second = true;
+```
+"#;
+
+        let blocks = split_source_blocks(create_source("/docs/guide.md", code));
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].code, "const first = true;\n");
+        assert_eq!(blocks[0].file_path, "/docs/guide.md.0.tsx");
+        assert_eq!((blocks[0].start_line, blocks[0].start_column), (8, 1));
+        assert_eq!(blocks[1].code, "const second = true;\n");
+        assert_eq!(blocks[1].file_path, "/docs/guide.md.1.ts");
+        assert_eq!(blocks[1].importable_file_path.as_deref(), Some("/docs/guide.md"));
+    }
+
+    #[test]
+    fn test_split_keeps_script_files() {
+        let blocks = split_source_blocks(create_source("/project/query.ts", "const query = 1;"));
+
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].file_path, "/project/query.ts");
+    }
+}
@@ -0,0 +1,229 @@
+use super::normalize_file_path;
+use std::path::Path;
+
+/// Module specifier the `graphql` function is imported from by default.
+pub const DEFAULT_GRAPHQL_MODULE: &str = "$mearie";
+
+#[derive(Debug, Clone)]
+pub struct ExtractionConfig {
+    /// Module specifiers whose `graphql` export marks calls for extraction.
+    pub graphql_modules: Vec<String>,
+    /// Whether a `graphql` the module neither imports nor declares also marks calls for
+    /// extraction, for projects that provide it as a global.
+    pub global_graphql: bool,
+    /// Directory non-relative module specifiers are resolved against, like tsconfig `baseUrl`.
+    pub base_url: Option<String>,
+    /// Module specifier aliases, like tsconfig `paths`.
+    pub paths: Vec<PathAlias>,
+    /// Local workspace packages that can be imported by name.
+    pub packages: Vec<WorkspacePackage>,
+}
+
+/// A tsconfig `paths` entry. The pattern and its targets may contain a single `*`
+/// wildcard. Relative targets are resolved against the base URL.
+#[derive(Debug, Clone)]
+pub struct PathAlias {
+    pub pattern: String,
+    pub targets: Vec<String>,
+}
+
+/// A workspace package located at `root`.
+///
+/// `exports` maps subpaths such as `"."` or `"./utils/*"` to files relative to `root`,
+/// like the `exports` field of `package.json`. Without exports, subpaths resolve to
+/// files in `root` and the package itself resolves to its index file.
+#[derive(Debug, Clone)]
+pub struct WorkspacePackage {
+    pub name: String,
+    pub root: String,
+    pub exports: Vec<(String, String)>,
+}
+
+impl Default for ExtractionConfig {
+    fn default() -> Self {
+        Self {
+            graphql_modules: vec![DEFAULT_GRAPHQL_MODULE.to_string()],
+            global_graphql: false,
+            base_url: None,
+            paths: Vec::new(),
+            packages: Vec::new(),
+        }
+    }
+}
+
+impl ExtractionConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_graphql_modules(mut self, graphql_modules: Vec<String>) -> Self {
+        self.graphql_modules = graphql_modules;
+        self
+    }
+
+    pub fn with_global_graphql(mut self, global_graphql: bool) -> Self {
+        self.global_graphql = global_graphql;
+        self
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    pub fn with_paths(mut self, paths: Vec<PathAlias>) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    pub fn with_packages(mut self, packages: Vec<WorkspacePackage>) -> Self {
+        self.packages = packages;
+        self
+    }
+
+    /// Returns the paths a non-relative module specifier may refer to, without
+    /// extensions or index files, in the order they should be tried.
+    pub(super) fn alias_bases(&self, specifier: &str) -> Vec<String> {
+        let mut bases = Vec::new();
+
+        if let Some((alias, captured)) = best_match(self.paths.iter().map(|p| p.pattern.as_str()), specifier) {
+            for target in &self.paths[alias].targets {
+                bases.push(self.resolve_from_base_url(&target.replacen('*', captured, 1)));
+            }
+        }
+
+        if self.base_url.is_some() {
+            bases.push(self.resolve_from_base_url(specifier));
+        }
+
+        for package in &self.packages {
+            let Some(subpath) = package_subpath(&package.name, specifier) else {
+                continue;
+            };
+
+            if package.exports.is_empty() {
+                bases.push(join_path(&package.root, &subpath));
+            } else if let Some((export, captured)) =
+                best_match(package.exports.iter().map(|(subpath, _)| subpath.as_str()), &subpath)
+            {
+                let target = package.exports[export].1.replacen('*', captured, 1);
+                bases.push(join_path(&package.root, &target));
+            }
+        }
+
+        bases
+    }
+
+    fn resolve_from_base_url(&self, path: &str) -> String {
+        match &self.base_url {
+            Some(base_url) => join_path(base_url, path),
+            None => normalize_file_path(path),
+        }
+    }
+}
+
+/// Returns `"."` for the package itself and `"./sub/path"` for paths inside it.
+fn package_subpath(name: &str, specifier: &str) -> Option<String> {
+    if specifier == name {
+        return Some(".".to_string());
+    }
+
+    let rest = specifier.strip_prefix(name)?.strip_prefix('/')?;
+    Some(format!("./{}", rest))
+}
+
+/// Finds the pattern matching `specifier` the way TypeScript picks `paths` entries:
+/// an exact pattern wins, then the wildcard pattern with the longest prefix.
+/// Returns the index of the pattern and the text matched by its wildcard.
+fn best_match<'a, 's>(patterns: impl Iterator<Item = &'a str>, specifier: &'s str) -> Option<(usize, &'s str)> {
+    let mut best: Option<(usize, usize, &'s str)> = None;
+
+    for (index, pattern) in patterns.enumerate() {
+        match pattern.split_once('*') {
+            None if pattern == specifier => return Some((index, "")),
+            None => {}
+            Some((prefix, suffix)) => {
+                if specifier.len() < prefix.len() + suffix.len()
+                    || !specifier.starts_with(prefix)
+                    || !specifier.ends_with(suffix)
+                {
+                    continue;
+                }
+
+                if best.is_none_or(|(_, length, _)| prefix.len() > length) {
+                    let captured = &specifier[prefix.len()..specifier.len() - suffix.len()];
+                    best = Some((index, prefix.len(), captured));
+                }
+            }
+        }
+    }
+
+    best.map(|(index, _, captured)| (index, captured))
+}
+
+fn join_path(base: &str, path: &str) -> String {
+    normalize_file_path(Path::new(base).join(path).to_string_lossy().as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alias_bases_prefers_exact_then_longest_prefix() {
+        let config = ExtractionConfig::new().with_base_url("/project").with_paths(vec![
+            PathAlias {
+                pattern: "@/*".to_string(),
+                targets: vec!["src/*".to_string()],
+            },
+            PathAlias {
+                pattern: "@/components/*".to_string(),
+                targets: vec!["src/ui/*".to_string(), "legacy/*".to_string()],
+            },
+            PathAlias {
+                pattern: "@/env".to_string(),
+                targets: vec!["config/env".to_string()],
+            },
+        ]);
+
+        assert_eq!(
+            config.alias_bases("@/components/UserCard"),
+            vec![
+                "/project/src/ui/UserCard",
+                "/project/legacy/UserCard",
+                "/project/@/components/UserCard"
+            ]
+        );
+        assert_eq!(config.alias_bases("@/env")[0], "/project/config/env");
+        assert_eq!(config.alias_bases("lib/user")[0], "/project/lib/user");
+    }
+
+    #[test]
+    fn test_alias_bases_resolves_workspace_packages() {
+        let config = ExtractionConfig::new().with_packages(vec![
+            WorkspacePackage {
+                name: "@acme/ui".to_string(),
+                root: "/repo/packages/ui".to_string(),
+                exports: vec![
+                    (".".to_string(), "./src/index.ts".to_string()),
+                    ("./fragments/*".to_string(), "./src/fragments/*.ts".to_string()),
+                ],
+            },
+            WorkspacePackage {
+                name: "shared".to_string(),
+                root: "/repo/packages/shared".to_string(),
+                exports: Vec::new(),
+            },
+        ]);
+
+        assert_eq!(config.alias_bases("@acme/ui"), vec!["/repo/packages/ui/src/index.ts"]);
+        assert_eq!(
+            config.alias_bases("@acme/ui/fragments/user"),
+            vec!["/repo/packages/ui/src/fragments/user.ts"]
+        );
+        assert!(config.alias_bases("@acme/ui/internal").is_empty());
+        assert!(config.alias_bases("@acme/uikit").is_empty());
+        assert_eq!(config.alias_bases("shared"), vec!["/repo/packages/shared"]);
+        assert_eq!(config.alias_bases("shared/user"), vec!["/repo/packages/shared/user"]);
+    }
+}
@@ -0,0 +1,324 @@
+use super::bindings::GraphqlBindings;
+use oxc_ast::ast::*;
+
+/// A statically analyzable expression, detached from the AST of the file it was
+/// written in so that it can be evaluated while resolving other files.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum ConstExpr {
+    Value(ConstValue),
+    Enum(String),
+    Identifier(String),
+    List(Vec<ConstExpr>),
+    Object(Vec<ConstMember>),
+    Member(Box<ConstExpr>, ConstKey),
+    Unary(UnaryOperator, Box<ConstExpr>),
+    Binary(BinaryOperator, Box<ConstExpr>, Box<ConstExpr>),
+    Template(Vec<String>, Vec<ConstExpr>),
+}
+
+/// A property or a spread element of a constant object expression.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum ConstMember {
+    Property(String, ConstExpr),
+    Spread(ConstExpr),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum ConstKey {
+    Name(String),
+    Index(f64),
+}
+
+/// The value of a constant expression.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum ConstValue {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    Enum(String),
+    List(Vec<ConstValue>),
+    Object(Vec<(String, ConstValue)>),
+    /// A fragment document, referenced through the binding it was declared with.
+    Fragment(String),
+}
+
+impl ConstExpr {
+    /// Converts `expr` into a constant expression, or returns `None` if it can only be
+    /// known at runtime, e.g. because it calls a function.
+    pub(super) fn from_expression(expr: &Expression, graphql: &GraphqlBindings) -> Option<Self> {
+        let expr = match expr.without_parentheses() {
+            Expression::TSAsExpression(expr) => return Self::from_expression(&expr.expression, graphql),
+            Expression::TSSatisfiesExpression(expr) => return Self::from_expression(&expr.expression, graphql),
+            Expression::TSTypeAssertion(expr) => return Self::from_expression(&expr.expression, graphql),
+            Expression::TSNonNullExpression(expr) => return Self::from_expression(&expr.expression, graphql),
+            expr => expr,
+        };
+
+        Some(match expr {
+            Expression::NullLiteral(_) => Self::Value(ConstValue::Null),
+            Expression::BooleanLiteral(value) => Self::Value(ConstValue::Boolean(value.value)),
+            Expression::NumericLiteral(value) => Self::Value(ConstValue::Number(value.value)),
+            Expression::StringLiteral(value) => Self::Value(ConstValue::String(value.value.to_string())),
+            Expression::Identifier(identifier) => Self::Identifier(identifier.name.to_string()),
+            Expression::TemplateLiteral(template) => Self::Template(
+                template
+                    .quasis
+                    .iter()
+                    .map(|quasi| quasi.value.cooked.map(|cooked| cooked.to_string()))
+                    .collect::<Option<_>>()?,
+                template
+                    .expressions
+                    .iter()
+                    .map(|expr| Self::from_expression(expr, graphql))
+                    .collect::<Option<_>>()?,
+            ),
+            Expression::ArrayExpression(array) => Self::List(
+                array
+                    .elements
+                    .iter()
+                    .map(|element| Self::from_expression(element.as_expression()?, graphql))
+                    .collect::<Option<_>>()?,
+            ),
+            Expression::ObjectExpression(object) => Self::Object(
+                object
+                    .properties
+                    .iter()
+                    .map(|property| match property {
+                        ObjectPropertyKind::ObjectProperty(property) => {
+                            if property.computed || property.kind != PropertyKind::Init {
+                                return None;
+                            }
+                            let key = property.key.static_name()?.to_string();
+                            Some(ConstMember::Property(
+                                key,
+                                Self::from_expression(&property.value, graphql)?,
+                            ))
+                        }
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            Some(ConstMember::Spread(Self::from_expression(&spread.argument, graphql)?))
+                        }
+                    })
+                    .collect::<Option<_>>()?,
+            ),
+            Expression::StaticMemberExpression(member) if !member.optional => Self::Member(
+                Box::new(Self::from_expression(&member.object, graphql)?),
+                ConstKey::Name(member.property.name.to_string()),
+            ),
+            Expression::ComputedMemberExpression(member) if !member.optional => {
+                let key = match member.expression.without_parentheses() {
+                    Expression::StringLiteral(value) => ConstKey::Name(value.value.to_string()),
+                    Expression::NumericLiteral(value) => ConstKey::Index(value.value),
+                    _ => return None,
+                };
+                Self::Member(Box::new(Self::from_expression(&member.object, graphql)?), key)
+            }
+            Expression::UnaryExpression(unary)
+                if matches!(
+                    unary.operator,
+                    UnaryOperator::UnaryNegation | UnaryOperator::UnaryPlus | UnaryOperator::LogicalNot
+                ) =>
+            {
+                Self::Unary(
+                    unary.operator,
+                    Box::new(Self::from_expression(&unary.argument, graphql)?),
+                )
+            }
+            Expression::BinaryExpression(binary)
+                if matches!(
+                    binary.operator,
+                    BinaryOperator::Addition
+                        | BinaryOperator::Subtraction
+                        | BinaryOperator::Multiplication
+                        | BinaryOperator::Division
+                        | BinaryOperator::Remainder
+                        | BinaryOperator::Exponential
+                ) =>
+            {
+                Self::Binary(
+                    binary.operator,
+                    Box::new(Self::from_expression(&binary.left, graphql)?),
+                    Box::new(Self::from_expression(&binary.right, graphql)?),
+                )
+            }
+            Expression::CallExpression(call) => {
+                let Expression::StaticMemberExpression(member) = call.callee.without_parentheses() else {
+                    return None;
+                };
+                if member.property.name != "enum" || !graphql.is_graphql(&member.object) || call.arguments.len() != 1 {
+                    return None;
+                }
+                match call.arguments[0].as_expression()?.without_parentheses() {
+                    Expression::StringLiteral(value) => Self::Enum(value.value.to_string()),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        })
+    }
+
+    /// Evaluates the expression, looking up identifiers with `lookup`. Returns `None`
+    /// if an identifier cannot be resolved or an operation has no constant result.
+    pub(super) fn evaluate(&self, lookup: &mut dyn FnMut(&str) -> Option<ConstValue>) -> Option<ConstValue> {
+        Some(match self {
+            Self::Value(value) => value.clone(),
+            Self::Enum(value) => ConstValue::Enum(value.clone()),
+            Self::Identifier(name) => lookup(name)?,
+            Self::List(items) => {
+                ConstValue::List(items.iter().map(|item| item.evaluate(lookup)).collect::<Option<_>>()?)
+            }
+            Self::Object(members) => {
+                let mut fields = Vec::new();
+                for member in members {
+                    match member {
+                        ConstMember::Property(key, value) => {
+                            insert_member(&mut fields, key.clone(), value.evaluate(lookup)?);
+                        }
+                        ConstMember::Spread(value) => {
+                            let ConstValue::Object(spread) = value.evaluate(lookup)? else {
+                                return None;
+                            };
+                            for (key, value) in spread {
+                                insert_member(&mut fields, key, value);
+                            }
+                        }
+                    }
+                }
+                ConstValue::Object(fields)
+            }
+            Self::Member(object, key) => match (object.evaluate(lookup)?, key) {
+                (ConstValue::Object(fields), ConstKey::Name(name)) => {
+                    fields.into_iter().rev().find(|(key, _)| key == name)?.1
+                }
+                (ConstValue::List(items), ConstKey::Index(index)) if index.fract() == 0.0 && *index >= 0.0 => {
+                    items.into_iter().nth(*index as usize)?
+                }
+                (ConstValue::List(items), ConstKey::Name(name)) if name == "length" => {
+                    ConstValue::Number(items.len() as f64)
+                }
+                (ConstValue::String(value), ConstKey::Name(name)) if name == "length" => {
+                    ConstValue::Number(value.encode_utf16().count() as f64)
+                }
+                _ => return None,
+            },
+            Self::Unary(operator, argument) => match (operator, argument.evaluate(lookup)?) {
+                (UnaryOperator::UnaryNegation, ConstValue::Number(value)) => ConstValue::Number(-value),
+                (UnaryOperator::UnaryPlus, ConstValue::Number(value)) => ConstValue::Number(value),
+                (UnaryOperator::LogicalNot, ConstValue::Boolean(value)) => ConstValue::Boolean(!value),
+                _ => return None,
+            },
+            Self::Binary(operator, left, right) => {
+                let (left, right) = (left.evaluate(lookup)?, right.evaluate(lookup)?);
+                match (operator, left, right) {
+                    (BinaryOperator::Addition, ConstValue::String(left), right) => {
+                        ConstValue::String(left + &right.to_template_string()?)
+                    }
+                    (BinaryOperator::Addition, left, ConstValue::String(right)) => {
+                        ConstValue::String(left.to_template_string()? + &right)
+                    }
+                    (operator, ConstValue::Number(left), ConstValue::Number(right)) => {
+                        let value = match operator {
+                            BinaryOperator::Addition => left + right,
+                            BinaryOperator::Subtraction => left - right,
+                            BinaryOperator::Multiplication => left * right,
+                            BinaryOperator::Division => left / right,
+                            BinaryOperator::Remainder => left % right,
+                            BinaryOperator::Exponential => left.powf(right),
+                            _ => return None,
+                        };
+                        ConstValue::Number(value)
+                    }
+                    _ => return None,
+                }
+            }
+            Self::Template(quasis, expressions) => {
+                let mut value = String::new();
+                for (index, quasi) in quasis.iter().enumerate() {
+                    value.push_str(quasi);
+                    if let Some(expression) = expressions.get(index) {
+                        value.push_str(&expression.evaluate(lookup)?.to_template_string()?);
+                    }
+                }
+                ConstValue::String(value)
+            }
+        })
+    }
+}
+
+impl ConstValue {
+    /// Returns the value as GraphQL input value syntax, or `None` for fragments and
+    /// numbers that GraphQL cannot represent.
+    pub(super) fn to_graphql(&self) -> Option<String> {
+        Some(match self {
+            Self::Null => "null".to_string(),
+            Self::Boolean(value) => value.to_string(),
+            Self::Number(value) => format_number(*value)?,
+            Self::String(value) => graphql_string(value),
+            Self::Enum(value) => value.clone(),
+            Self::List(items) => format!(
+                "[{}]",
+                items
+                    .iter()
+                    .map(Self::to_graphql)
+                    .collect::<Option<Vec<_>>>()?
+                    .join(", ")
+            ),
+            Self::Object(fields) => format!(
+                "{{ {} }}",
+                fields
+                    .iter()
+                    .map(|(key, value)| Some(format!("{}: {}", key, value.to_graphql()?)))
+                    .collect::<Option<Vec<_>>>()?
+                    .join(", ")
+            ),
+            Self::Fragment(_) => return None,
+        })
+    }
+
+    /// Returns the value as it appears when interpolated into a string.
+    fn to_template_string(&self) -> Option<String> {
+        match self {
+            Self::Null => Some("null".to_string()),
+            Self::Boolean(value) => Some(value.to_string()),
+            Self::Number(value) => format_number(*value),
+            Self::String(value) => Some(value.clone()),
+            Self::Enum(_) | Self::List(_) | Self::Object(_) | Self::Fragment(_) => None,
+        }
+    }
+}
+
+/// Sets `key` to `value` the way object literals do: a key that is already present
+/// keeps its position but takes the new value.
+pub(super) fn insert_member<T>(members: &mut Vec<(String, T)>, key: String, value: T) {
+    match members.iter_mut().find(|(existing, _)| *existing == key) {
+        Some((_, existing)) => *existing = value,
+        None => members.push((key, value)),
+    }
+}
+
+fn format_number(value: f64) -> Option<String> {
+    if !value.is_finite() {
+        None
+    } else if value.fract() == 0.0 && value.abs() < 1e21 {
+        Some(format!("{}", value as i128))
+    } else {
+        Some(value.to_string())
+    }
+}
+
+pub(super) fn graphql_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            ch => result.push(ch),
+        }
+    }
+    result.push('"');
+    result
+}
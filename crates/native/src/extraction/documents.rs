@@ -0,0 +1,121 @@
+//! Standalone `.graphql` and `.gql` document files.
+//!
+//! A document file is a GraphQL source as a whole, so it is passed through without
+//! parsing. Fragments defined in other files are declared with comments such as
+//! `#import "./UserFields.graphql"`, which are resolved relative to the file the same
+//! way module imports are.
+//!
+//! Vue `<graphql>` custom blocks are split into document files as well, with the path
+//! of their component as the importable path.
+
+use super::{ExtractResult, TypedGraphqlDocumentImport, TypedGraphqlFileFragmentRegistry};
+use super::{TypedGraphqlResolvedFragmentBindings, registry_file_path};
+use crate::error::MearieError;
+use crate::error::location::Location;
+use crate::source::{Source, SourceBuf};
+
+/// Collects the `#import` comments of a document file. Document files declare no
+/// bindings, so the registry only holds their imports.
+pub(super) fn collect_document_registry(source: &SourceBuf) -> TypedGraphqlFileFragmentRegistry {
+    TypedGraphqlFileFragmentRegistry {
+        document_imports: document_imports(&source.code),
+        ..Default::default()
+    }
+}
+
+/// Returns the document file as a GraphQL source, with an error for every `#import`
+/// that does not refer to another document. Sources of custom blocks are attributed to
+/// the file they are in.
+pub(super) fn extract_document_sources(
+    source: &SourceBuf,
+    resolved: &TypedGraphqlResolvedFragmentBindings,
+) -> ExtractResult {
+    let file_path = source
+        .importable_file_path
+        .clone()
+        .unwrap_or_else(|| source.file_path.clone());
+
+    let errors = resolved
+        .unresolved_document_imports
+        .iter()
+        .map(|import| {
+            let start = import.offset as usize;
+            let end = start + source.code[start..].find('\n').unwrap_or(source.code.len() - start);
+            let (line, column) = Location::position(&Source::from(source), start);
+            let (end_line, end_column) = Location::position(&Source::from(source), end);
+
+            MearieError::extraction(format!(
+                "Unable to resolve '#import \"{}\"' in '{}': it did not match any document file",
+                import.source,
+                registry_file_path(source)
+            ))
+            .at(Location {
+                file_path: file_path.clone(),
+                line,
+                column: Some(column),
+                end_line: Some(end_line),
+                end_column: Some(end_column),
+            })
+        })
+        .collect();
+
+    let sources = if source.code.trim().is_empty() {
+        Vec::new()
+    } else {
+        vec![SourceBuf {
+            file_path,
+            importable_file_path: None,
+            ..source.clone()
+        }]
+    };
+
+    ExtractResult { sources, errors }
+}
+
+/// Parses the `#import "path"` comments of a document, with either quote style.
+fn document_imports(code: &str) -> Vec<TypedGraphqlDocumentImport> {
+    let mut imports = Vec::new();
+    let mut line_start = 0;
+
+    for line in code.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        let offset = line_start + line.len() - trimmed.len();
+        line_start += line.len();
+
+        let Some(rest) = trimmed.strip_prefix("#import") else {
+            continue;
+        };
+        let rest = rest.trim();
+        let Some(quote @ ('"' | '\'')) = rest.chars().next() else {
+            continue;
+        };
+        let Some(end) = rest[1..].find(quote) else {
+            continue;
+        };
+
+        imports.push(TypedGraphqlDocumentImport {
+            source: rest[1..end + 1].to_string(),
+            offset: offset as u32,
+        });
+    }
+
+    imports
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_imports() {
+        let code = "#import \"./UserFields.graphql\"\n  #import './fragments/Post.gql'  \n# import \"./Ignored.graphql\"\nquery GetUser { user { ...UserFields } }\n";
+
+        let imports = document_imports(code);
+
+        assert_eq!(imports.len(), 2);
+        assert_eq!(imports[0].source, "./UserFields.graphql");
+        assert_eq!(imports[0].offset, 0);
+        assert_eq!(imports[1].source, "./fragments/Post.gql");
+        assert_eq!(imports[1].offset as usize, code.find("#import './").unwrap());
+    }
+}
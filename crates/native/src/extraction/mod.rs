@@ -1,17 +1,31 @@
+mod bindings;
+pub mod blocks;
+pub mod config;
+mod constants;
+mod documents;
+mod session;
+mod source_map;
+
+pub use config::{ExtractionConfig, PathAlias, WorkspacePackage};
+pub use session::ExtractionSession;
+
 use crate::error::MearieError;
 use crate::error::location::Location;
-use crate::source::SourceBuf;
+use crate::source::{Source, SourceBuf, SourceMapping, is_graphql_document};
+use bindings::GraphqlBindings;
+use constants::{ConstExpr, ConstValue, graphql_string, insert_member};
 use oxc_allocator::Allocator;
 use oxc_ast::ast::*;
 use oxc_ast_visit::{Visit, walk};
 use oxc_parser::Parser;
-use oxc_span::SourceType;
+use oxc_span::{GetSpan, SourceType, Span};
 use serde::Serialize;
+use source_map::MappedText;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write as _;
 use std::path::Path;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ExtractResult {
     pub sources: Vec<SourceBuf>,
     pub errors: Vec<MearieError>,
@@ -19,8 +33,13 @@ pub struct ExtractResult {
 
 struct Extractor<'a> {
     source: &'a SourceBuf,
+    graphql: GraphqlBindings,
     fragment_bindings: HashMap<String, String>,
     unresolved_fragment_imports: HashMap<String, String>,
+    constants: HashMap<String, ConstValue>,
+    /// Start offsets of the templates extracted as `graphql()` arguments or tagged
+    /// templates, so that a `/* GraphQL */` comment on them does not extract them again.
+    extracted_templates: HashSet<u32>,
     sources: Vec<SourceBuf>,
     errors: Vec<MearieError>,
 }
@@ -57,14 +76,17 @@ impl TypedGraphqlKind {
 struct TypedGraphqlVariableDefinition {
     name: String,
     type_ref: String,
-    default_value: Option<String>,
-    directives: Vec<String>,
+    default_value: Option<MappedText>,
+    directives: Vec<MappedText>,
+    span: Span,
+    name_span: Span,
+    type_span: Span,
 }
 
 struct TypedGraphqlVariableType {
     type_ref: String,
-    default_value: Option<String>,
-    directives: Vec<String>,
+    default_value: Option<MappedText>,
+    directives: Vec<MappedText>,
     state: TypedGraphqlVariableTypeState,
     has_directives: bool,
 }
@@ -74,6 +96,11 @@ struct TypedGraphqlCallbackObject<'a> {
     variable_reference_parameter: Option<&'a str>,
 }
 
+enum TypedGraphqlObjectMember<'a> {
+    Property(&'a str, &'a ObjectProperty<'a>),
+    Spread(&'a SpreadElement<'a>),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum TypedGraphqlVariableTypeState {
     Base,
@@ -90,58 +117,74 @@ impl TypedGraphqlVariableTypeState {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 struct TypedGraphqlLocalExport {
     local_name: String,
     exported_name: String,
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 struct TypedGraphqlImportBinding {
     source: String,
     imported_name: String,
     local_name: String,
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 struct TypedGraphqlReExport {
     source: String,
     imported_name: String,
     exported_name: String,
 }
 
-#[derive(Debug, Default, Clone)]
+/// An `#import "./UserFields.graphql"` comment in a GraphQL document file, at
+/// `offset` in the file.
+#[derive(Debug, Default, Clone, PartialEq)]
+struct TypedGraphqlDocumentImport {
+    source: String,
+    offset: u32,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
 struct TypedGraphqlFileFragmentRegistry {
     local_fragment_bindings: HashMap<String, String>,
+    local_constants: HashMap<String, ConstExpr>,
     local_exports: Vec<TypedGraphqlLocalExport>,
     import_bindings: Vec<TypedGraphqlImportBinding>,
     re_exports: Vec<TypedGraphqlReExport>,
     export_all_sources: Vec<String>,
+    document_imports: Vec<TypedGraphqlDocumentImport>,
 }
 
 impl TypedGraphqlFileFragmentRegistry {
     fn merge(&mut self, other: Self) {
         self.local_fragment_bindings.extend(other.local_fragment_bindings);
+        self.local_constants.extend(other.local_constants);
         self.local_exports.extend(other.local_exports);
         self.import_bindings.extend(other.import_bindings);
         self.re_exports.extend(other.re_exports);
         self.export_all_sources.extend(other.export_all_sources);
+        self.document_imports.extend(other.document_imports);
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 struct TypedGraphqlResolvedFragmentBindings {
     fragment_bindings: HashMap<String, String>,
     unresolved_fragment_imports: HashMap<String, String>,
+    constants: HashMap<String, ConstValue>,
+    unresolved_document_imports: Vec<TypedGraphqlDocumentImport>,
 }
 
 struct TypedGraphqlFragmentBindingCollector {
+    graphql: GraphqlBindings,
     registry: TypedGraphqlFileFragmentRegistry,
 }
 
 impl TypedGraphqlFragmentBindingCollector {
-    fn new() -> Self {
+    fn new(graphql: GraphqlBindings) -> Self {
         Self {
+            graphql,
             registry: TypedGraphqlFileFragmentRegistry::default(),
         }
     }
@@ -166,7 +209,7 @@ impl TypedGraphqlFragmentBindingCollector {
             return None;
         };
 
-        if !matches!(self.strip_expression_wrappers(&member.object), Expression::Identifier(identifier) if identifier.name == "graphql")
+        if !self.graphql.is_graphql(self.strip_expression_wrappers(&member.object))
             || member.property.name != "fragment"
         {
             return None;
@@ -181,6 +224,29 @@ impl TypedGraphqlFragmentBindingCollector {
             })
     }
 
+    /// Returns the fragment name of a `` graphql(`fragment ...`) ``, `` graphql`fragment ...` ``
+    /// or `` /* GraphQL */ `fragment ...` `` document, i.e. the name of its first definition
+    /// if that is a fragment.
+    fn template_fragment_name<'b>(&self, expr: &'b Expression<'b>) -> Option<&'b str> {
+        let template = match self.strip_expression_wrappers(expr) {
+            Expression::CallExpression(call) if self.graphql.is_graphql(&call.callee) && call.arguments.len() == 1 => {
+                match &call.arguments[0] {
+                    Argument::TemplateLiteral(template) => template,
+                    _ => return None,
+                }
+            }
+            Expression::TaggedTemplateExpression(tagged) if self.graphql.is_graphql(&tagged.tag) => &tagged.quasi,
+            Expression::TemplateLiteral(template) if self.graphql.is_graphql_template(template) => template,
+            _ => return None,
+        };
+
+        let mut tokens = graphql_words(template.quasis.first()?.value.raw.as_str());
+        match (tokens.next(), tokens.next()) {
+            (Some("fragment"), Some(name)) => Some(name),
+            _ => None,
+        }
+    }
+
     fn module_export_name<'b>(&self, name: &ModuleExportName<'b>) -> &'b str {
         match name {
             ModuleExportName::IdentifierName(identifier) => identifier.name.as_str(),
@@ -217,6 +283,34 @@ impl TypedGraphqlFragmentBindingCollector {
 }
 
 impl<'a> Visit<'a> for TypedGraphqlFragmentBindingCollector {
+    fn visit_program(&mut self, node: &Program<'a>) {
+        for statement in &node.body {
+            let declaration = match statement {
+                Statement::VariableDeclaration(declaration) => declaration,
+                Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                    Some(Declaration::VariableDeclaration(declaration)) => declaration,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+
+            if declaration.kind != VariableDeclarationKind::Const || declaration.declare {
+                continue;
+            }
+
+            for declarator in &declaration.declarations {
+                if let Some(binding_name) = self.binding_identifier_name(&declarator.id)
+                    && let Some(init) = &declarator.init
+                    && let Some(constant) = ConstExpr::from_expression(init, &self.graphql)
+                {
+                    self.registry.local_constants.insert(binding_name.to_string(), constant);
+                }
+            }
+        }
+
+        walk::walk_program(self, node);
+    }
+
     fn visit_import_declaration(&mut self, node: &ImportDeclaration<'a>) {
         if node.import_kind.is_type() {
             return;
@@ -298,7 +392,9 @@ impl<'a> Visit<'a> for TypedGraphqlFragmentBindingCollector {
         if node.kind == VariableDeclarationKind::Const
             && let Some(binding_name) = self.binding_identifier_name(&node.id)
             && let Some(init) = &node.init
-            && let Some(fragment_name) = self.typed_fragment_call_name(init)
+            && let Some(fragment_name) = self
+                .typed_fragment_call_name(init)
+                .or_else(|| self.template_fragment_name(init))
         {
             self.registry
                 .local_fragment_bindings
@@ -309,13 +405,30 @@ impl<'a> Visit<'a> for TypedGraphqlFragmentBindingCollector {
     }
 }
 
+fn constant_member<'a>(fields: &'a [(String, ConstValue)], name: &str) -> Option<&'a ConstValue> {
+    fields.iter().find(|(key, _)| key == name).map(|(_, value)| value)
+}
+
+/// Splits GraphQL code into names, skipping comments, whitespace, commas and punctuation.
+fn graphql_words(code: &str) -> impl Iterator<Item = &str> {
+    code.lines()
+        .map(|line| line.split_once('#').map_or(line, |(code, _)| code))
+        .flat_map(|line| line.split(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_')))
+        .filter(|word| !word.is_empty())
+}
+
+/// Resolves a binding declared in a file, given the file path and the local name.
+type LocalBindingResolver<'a, T> =
+    fn(&TypedGraphqlCrossFileFragmentResolver<'a>, &str, &str, &mut HashSet<(String, String)>) -> Option<T>;
+
 struct TypedGraphqlCrossFileFragmentResolver<'a> {
     files: &'a HashMap<String, TypedGraphqlFileFragmentRegistry>,
+    config: &'a ExtractionConfig,
 }
 
 impl<'a> TypedGraphqlCrossFileFragmentResolver<'a> {
-    fn new(files: &'a HashMap<String, TypedGraphqlFileFragmentRegistry>) -> Self {
-        Self { files }
+    fn new(files: &'a HashMap<String, TypedGraphqlFileFragmentRegistry>, config: &'a ExtractionConfig) -> Self {
+        Self { files, config }
     }
 
     fn resolve_file_bindings(&self, file_path: &str) -> TypedGraphqlResolvedFragmentBindings {
@@ -326,6 +439,13 @@ impl<'a> TypedGraphqlCrossFileFragmentResolver<'a> {
         let mut resolved = TypedGraphqlResolvedFragmentBindings {
             fragment_bindings: file.local_fragment_bindings.clone(),
             unresolved_fragment_imports: HashMap::new(),
+            constants: HashMap::new(),
+            unresolved_document_imports: file
+                .document_imports
+                .iter()
+                .filter(|import| self.resolve_module_path(file_path, &import.source).is_none())
+                .cloned()
+                .collect(),
         };
 
         for import in &file.import_bindings {
@@ -343,6 +463,16 @@ impl<'a> TypedGraphqlCrossFileFragmentResolver<'a> {
             }
         }
 
+        let constant_names = file
+            .local_constants
+            .keys()
+            .chain(file.import_bindings.iter().map(|import| &import.local_name));
+        for name in constant_names {
+            if let Some(value) = self.resolve_local_constant(file_path, name, &mut HashSet::new()) {
+                resolved.constants.insert(name.clone(), value);
+            }
+        }
+
         resolved
     }
 
@@ -355,21 +485,29 @@ impl<'a> TypedGraphqlCrossFileFragmentResolver<'a> {
         };
 
         let mut seen = HashSet::new();
-        self.resolve_exported_fragment(&resolved_path, &import.imported_name, &mut seen)
-            .ok_or_else(|| {
-                format!(
-                    "module '{}' does not export a typed GraphQL fragment binding named '{}'",
-                    import.source, import.imported_name
-                )
-            })
+        self.resolve_exported_binding(
+            &resolved_path,
+            &import.imported_name,
+            &mut seen,
+            Self::resolve_local_fragment_binding,
+        )
+        .ok_or_else(|| {
+            format!(
+                "module '{}' does not export a typed GraphQL fragment binding named '{}'",
+                import.source, import.imported_name
+            )
+        })
     }
 
-    fn resolve_exported_fragment(
+    /// Follows exports, re-exports and `export *` declarations from `file_path` to the
+    /// binding exported as `export_name`, and resolves it with `resolve_local`.
+    fn resolve_exported_binding<T>(
         &self,
         file_path: &str,
         export_name: &str,
         seen: &mut HashSet<(String, String)>,
-    ) -> Option<String> {
+        resolve_local: LocalBindingResolver<'a, T>,
+    ) -> Option<T> {
         if !seen.insert((file_path.to_string(), export_name.to_string())) {
             return None;
         }
@@ -378,10 +516,9 @@ impl<'a> TypedGraphqlCrossFileFragmentResolver<'a> {
 
         for local_export in &file.local_exports {
             if local_export.exported_name == export_name
-                && let Some(fragment_name) =
-                    self.resolve_local_fragment_binding(file_path, &local_export.local_name, seen)
+                && let Some(value) = resolve_local(self, file_path, &local_export.local_name, seen)
             {
-                return Some(fragment_name);
+                return Some(value);
             }
         }
 
@@ -394,9 +531,10 @@ impl<'a> TypedGraphqlCrossFileFragmentResolver<'a> {
                 continue;
             };
 
-            if let Some(fragment_name) = self.resolve_exported_fragment(&resolved_path, &re_export.imported_name, seen)
+            if let Some(value) =
+                self.resolve_exported_binding(&resolved_path, &re_export.imported_name, seen, resolve_local)
             {
-                return Some(fragment_name);
+                return Some(value);
             }
         }
 
@@ -405,26 +543,24 @@ impl<'a> TypedGraphqlCrossFileFragmentResolver<'a> {
                 continue;
             };
 
-            if let Some(fragment_name) = self.resolve_exported_fragment(&resolved_path, export_name, seen) {
-                return Some(fragment_name);
+            if let Some(value) = self.resolve_exported_binding(&resolved_path, export_name, seen, resolve_local) {
+                return Some(value);
             }
         }
 
         None
     }
 
-    fn resolve_local_fragment_binding(
+    /// Resolves the binding imported as `local_name` in `file_path` with `resolve_local`.
+    fn resolve_imported_binding<T>(
         &self,
         file_path: &str,
         local_name: &str,
         seen: &mut HashSet<(String, String)>,
-    ) -> Option<String> {
+        resolve_local: LocalBindingResolver<'a, T>,
+    ) -> Option<T> {
         let file = self.files.get(file_path)?;
 
-        if let Some(fragment_name) = file.local_fragment_bindings.get(local_name) {
-            return Some(fragment_name.clone());
-        }
-
         for import in &file.import_bindings {
             if import.local_name != local_name {
                 continue;
@@ -434,16 +570,96 @@ impl<'a> TypedGraphqlCrossFileFragmentResolver<'a> {
                 continue;
             };
 
-            if let Some(fragment_name) = self.resolve_exported_fragment(&resolved_path, &import.imported_name, seen) {
-                return Some(fragment_name);
+            if let Some(value) =
+                self.resolve_exported_binding(&resolved_path, &import.imported_name, seen, resolve_local)
+            {
+                return Some(value);
             }
         }
 
         None
     }
 
+    fn resolve_local_fragment_binding(
+        &self,
+        file_path: &str,
+        local_name: &str,
+        seen: &mut HashSet<(String, String)>,
+    ) -> Option<String> {
+        if let Some(fragment_name) = self.files.get(file_path)?.local_fragment_bindings.get(local_name) {
+            return Some(fragment_name.clone());
+        }
+
+        self.resolve_imported_binding(file_path, local_name, seen, Self::resolve_local_fragment_binding)
+    }
+
+    /// Evaluates the constant bound to `local_name` in `file_path`, following imports.
+    /// Fragment bindings evaluate to fragment references, so that constant selection
+    /// objects can include fragment spreads.
+    ///
+    /// `seen` holds the bindings being evaluated, so that cyclic constants fail to
+    /// resolve instead of recursing forever.
+    fn resolve_local_constant(
+        &self,
+        file_path: &str,
+        local_name: &str,
+        seen: &mut HashSet<(String, String)>,
+    ) -> Option<ConstValue> {
+        let file = self.files.get(file_path)?;
+        let Some(constant) = file.local_constants.get(local_name) else {
+            if let Some(fragment_name) = file.local_fragment_bindings.get(local_name) {
+                return Some(ConstValue::Fragment(fragment_name.clone()));
+            }
+            return self.resolve_imported_binding(file_path, local_name, seen, Self::resolve_local_constant);
+        };
+
+        let key = (file_path.to_string(), format!("#{}", local_name));
+        if !seen.insert(key.clone()) {
+            return None;
+        }
+
+        let value = constant.evaluate(&mut |name| {
+            let mut seen = seen.clone();
+            self.resolve_local_constant(file_path, name, &mut seen)
+        });
+
+        seen.remove(&key);
+        value
+    }
+
+    /// Returns the files `file_path` imports or re-exports bindings from, or imports
+    /// with `#import` comments.
+    fn resolve_dependencies(&self, file_path: &str) -> Vec<String> {
+        let Some(file) = self.files.get(file_path) else {
+            return Vec::new();
+        };
+
+        let sources = file
+            .import_bindings
+            .iter()
+            .map(|import| &import.source)
+            .chain(file.re_exports.iter().map(|re_export| &re_export.source))
+            .chain(&file.export_all_sources)
+            .chain(file.document_imports.iter().map(|import| &import.source));
+
+        let mut dependencies: Vec<String> = sources
+            .filter_map(|source| self.resolve_module_path(file_path, source))
+            .collect();
+        dependencies.sort();
+        dependencies.dedup();
+        dependencies
+    }
+
     fn resolve_module_path(&self, file_path: &str, module_source: &str) -> Option<String> {
-        module_resolution_candidates(file_path, module_source)?
+        let candidates = module_resolution_candidates(file_path, module_source).unwrap_or_else(|| {
+            let mut candidates = Vec::new();
+            for base in self.config.alias_bases(module_source) {
+                push_file_candidates(&mut candidates, base);
+            }
+            candidates
+        });
+
+        candidates
             .into_iter()
             .find(|candidate| self.files.contains_key(candidate))
     }
@@ -466,26 +682,31 @@ fn module_resolution_candidates(file_path: &str, module_source: &str) -> Option<
     };
 
     let mut candidates = Vec::new();
-    push_unique_candidate(&mut candidates, base.clone());
+    push_file_candidates(&mut candidates, base);
+    Some(candidates)
+}
+
+/// Pushes the files a module path may refer to: the path itself, the path with a
+/// module extension, and index files in the directory at the path.
+fn push_file_candidates(candidates: &mut Vec<String>, base: String) {
+    push_unique_candidate(candidates, base.clone());
 
     let extension = Path::new(&base).extension().and_then(|extension| extension.to_str());
     if extension.is_none() {
         for extension in TYPED_GRAPHQL_MODULE_EXTENSIONS {
-            push_unique_candidate(&mut candidates, format!("{}.{}", base, extension));
+            push_unique_candidate(candidates, format!("{}.{}", base, extension));
         }
     } else if matches!(extension, Some("js" | "jsx" | "mjs" | "cjs"))
         && let Some((stem, _)) = base.rsplit_once('.')
     {
         for extension in ["ts", "tsx", "mts", "cts"] {
-            push_unique_candidate(&mut candidates, format!("{}.{}", stem, extension));
+            push_unique_candidate(candidates, format!("{}.{}", stem, extension));
         }
     }
 
     for extension in TYPED_GRAPHQL_MODULE_EXTENSIONS {
-        push_unique_candidate(&mut candidates, format!("{}/index.{}", base, extension));
+        push_unique_candidate(candidates, format!("{}/index.{}", base, extension));
     }
-
-    Some(candidates)
 }
 
 fn push_unique_candidate(candidates: &mut Vec<String>, candidate: String) {
@@ -528,50 +749,178 @@ fn registry_file_path(source: &SourceBuf) -> String {
 }
 
 impl<'a> Extractor<'a> {
-    fn new(source: &'a SourceBuf, resolved_fragment_bindings: TypedGraphqlResolvedFragmentBindings) -> Self {
+    fn new(
+        source: &'a SourceBuf,
+        graphql: GraphqlBindings,
+        resolved_fragment_bindings: TypedGraphqlResolvedFragmentBindings,
+    ) -> Self {
         Self {
             source,
+            graphql,
             fragment_bindings: resolved_fragment_bindings.fragment_bindings,
             unresolved_fragment_imports: resolved_fragment_bindings.unresolved_fragment_imports,
+            constants: resolved_fragment_bindings.constants,
+            extracted_templates: HashSet::new(),
             sources: Vec::new(),
             errors: Vec::new(),
         }
     }
 
     fn calculate_line_number(&self, offset: u32) -> u32 {
-        let offset = offset as usize;
-        if offset > self.source.code.len() {
-            return 1;
+        self.calculate_position(offset).0
+    }
+
+    /// Returns the line and column in the host file of `offset` in the source code.
+    fn calculate_position(&self, offset: u32) -> (u32, u32) {
+        let offset = (offset as usize).min(self.source.code.len());
+        Location::position(&Source::from(self.source), offset)
+    }
+
+    /// Creates a source for GraphQL code that starts at `offset` in the source code.
+    fn embedded_source(&self, code: String, offset: u32) -> SourceBuf {
+        let (start_line, start_column) = self.calculate_position(offset);
+
+        SourceBuf {
+            code,
+            file_path: self.source.file_path.clone(),
+            importable_file_path: None,
+            start_line,
+            start_column,
+            offset: self.source.offset + offset,
+            mappings: None,
         }
+    }
+
+    /// Creates a source for GraphQL code lowered from the host code at `offset`, mapping
+    /// each piece of the generated code back to the host code it came from.
+    fn lowered_source(&self, code: MappedText, offset: u32) -> SourceBuf {
+        let mappings: Vec<_> = code
+            .mappings()
+            .iter()
+            .map(|(range, span)| {
+                let (line, column) = self.calculate_position(span.start);
+                let (end_line, end_column) = self.calculate_position(span.end);
+                SourceMapping {
+                    generated_start: range.start as u32,
+                    generated_end: range.end as u32,
+                    line,
+                    column,
+                    end_line,
+                    end_column,
+                }
+            })
+            .collect();
+
+        let mut source = self.embedded_source(code.into_string(), offset);
+        source.mappings = (!mappings.is_empty()).then_some(mappings);
+        source
+    }
+
+    /// Returns the GraphQL code of `template` passed to `graphql()` or marked with a
+    /// `/* GraphQL */` comment.
+    ///
+    /// The document is looked up at runtime by the string the template evaluates to, so
+    /// the code is the cooked text, with escape sequences applied. Interpolated values
+    /// would be stringified into that text, so interpolations are only accepted in
+    /// tagged templates. The text from the first escape sequence on is mapped back to
+    /// the rest of the template.
+    fn extract_template_literal(&self, template: &TemplateLiteral) -> Result<MappedText, MearieError> {
+        if let Some(expression) = template.expressions.first() {
+            return Err(self.span_error(
+                expression.span(),
+                "Template literal interpolation is only supported in tagged templates, e.g. graphql`...`",
+            ));
+        }
+
+        let quasi = &template.quasis[0];
+        let raw = quasi.value.raw.as_str();
+        let cooked = quasi.value.cooked.as_deref().unwrap_or(raw);
+        let escaped = raw
+            .char_indices()
+            .zip(cooked.chars())
+            .find(|((_, raw), cooked)| raw != cooked)
+            .map_or(raw.len().min(cooked.len()), |((index, _), _)| index);
+
+        let mut result = MappedText::new();
+        result.push_str(&cooked[..escaped]);
+        result.push_mapped(
+            &cooked[escaped..],
+            Span::new(quasi.span.start + escaped as u32, quasi.span.end),
+        );
 
-        self.source.code[..offset].bytes().filter(|&b| b == b'\n').count() as u32 + 1
+        Ok(result)
     }
 
-    fn extract_template_literal(&self, template: &TemplateLiteral, line: u32) -> Result<String, MearieError> {
-        if !template.expressions.is_empty() {
-            return Err(MearieError::extraction(
-                "Template literal contains interpolation which is not allowed in GraphQL",
-            )
-            .at(Location {
-                file_path: self.source.file_path.clone(),
-                line,
-                column: None,
-            }));
+    /// Returns the GraphQL code of a tagged template, which is looked up at runtime by
+    /// its raw strings.
+    ///
+    /// Interpolations must refer to fragment documents. Fragments are resolved by name,
+    /// so the interpolations are dropped and the text following each of them is mapped
+    /// back to the template.
+    fn extract_tagged_template(&self, template: &TemplateLiteral) -> Result<MappedText, MearieError> {
+        for expression in &template.expressions {
+            self.resolve_fragment_interpolation(expression)?;
         }
 
-        let mut result = String::new();
-        for quasi in &template.quasis {
-            result.push_str(&quasi.value.raw);
+        let mut result = MappedText::new();
+        for (index, quasi) in template.quasis.iter().enumerate() {
+            if index == 0 {
+                result.push_str(&quasi.value.raw);
+            } else {
+                result.push_mapped(&quasi.value.raw, quasi.span);
+            }
         }
 
         Ok(result)
     }
 
+    fn resolve_fragment_interpolation(&self, expression: &Expression) -> Result<(), MearieError> {
+        let Expression::Identifier(identifier) = self.strip_expression_wrappers(expression) else {
+            return Err(self.span_error(
+                expression.span(),
+                "Template literal interpolation must reference a fragment document by name",
+            ));
+        };
+
+        let name = identifier.name.as_str();
+        if self.fragment_bindings.contains_key(name) {
+            return Ok(());
+        }
+
+        let detail = self.unresolved_fragment_imports.get(name).map_or_else(
+            || {
+                "it was not found in a local fragment declaration or the cross-file exported fragment registry"
+                    .to_string()
+            },
+            |message| message.clone(),
+        );
+
+        Err(self.span_error(
+            identifier.span,
+            format!("Unable to resolve interpolated fragment '{}': {}", name, detail),
+        ))
+    }
+
+    fn span_error(&self, span: Span, message: impl Into<String>) -> MearieError {
+        let (line, column) = self.calculate_position(span.start);
+        let (end_line, end_column) = self.calculate_position(span.end);
+
+        MearieError::extraction(message).at(Location {
+            file_path: self.source.file_path.clone(),
+            line,
+            column: Some(column),
+            end_line: Some(end_line),
+            end_column: Some(end_column),
+        })
+    }
+
     fn extraction_error(&self, line: u32, message: impl Into<String>) -> MearieError {
         MearieError::extraction(message).at(Location {
             file_path: self.source.file_path.clone(),
             line,
             column: None,
+            end_line: None,
+            end_column: None,
         })
     }
 
@@ -580,7 +929,7 @@ impl<'a> Extractor<'a> {
         node: &CallExpression,
         kind: TypedGraphqlKind,
         line: u32,
-    ) -> Result<String, MearieError> {
+    ) -> Result<MappedText, MearieError> {
         match kind {
             TypedGraphqlKind::Fragment => self.lower_typed_graphql_fragment(node, line),
             TypedGraphqlKind::Query | TypedGraphqlKind::Mutation | TypedGraphqlKind::Subscription => {
@@ -594,7 +943,7 @@ impl<'a> Extractor<'a> {
         node: &CallExpression,
         kind: TypedGraphqlKind,
         line: u32,
-    ) -> Result<String, MearieError> {
+    ) -> Result<MappedText, MearieError> {
         if node.arguments.len() != 2 {
             return Err(self.extraction_error(
                 line,
@@ -611,8 +960,9 @@ impl<'a> Extractor<'a> {
             .ok_or_else(|| self.extraction_error(line, "Operation spec must include a select callback"))?;
         let selection = self.expect_selection_callback(select, line)?;
 
-        let mut out = String::new();
-        write!(out, "{} {}", kind.as_graphql_keyword(), name).unwrap();
+        let mut out = MappedText::new();
+        write!(out, "{} ", kind.as_graphql_keyword()).unwrap();
+        out.push_mapped(name, node.arguments[0].span());
         self.write_variable_definitions(&mut out, &variables);
         self.write_directives(&mut out, &directives);
         writeln!(out, " {{").unwrap();
@@ -624,11 +974,12 @@ impl<'a> Extractor<'a> {
             selection.variable_reference_parameter,
         )?;
         writeln!(out, "}}").unwrap();
+        out.map_from(0, node.span);
 
         Ok(out)
     }
 
-    fn lower_typed_graphql_fragment(&self, node: &CallExpression, line: u32) -> Result<String, MearieError> {
+    fn lower_typed_graphql_fragment(&self, node: &CallExpression, line: u32) -> Result<MappedText, MearieError> {
         if node.arguments.len() != 3 {
             return Err(self.extraction_error(
                 line,
@@ -650,10 +1001,12 @@ impl<'a> Extractor<'a> {
             .ok_or_else(|| self.extraction_error(line, "Fragment spec must include a select callback"))?;
         let selection = self.expect_selection_callback(select, line)?;
 
-        let mut out = String::new();
-        write!(out, "fragment {}", name).unwrap();
+        let mut out = MappedText::new();
+        out.push_str("fragment ");
+        out.push_mapped(name, node.arguments[0].span());
         self.write_variable_definitions(&mut out, &variables);
-        write!(out, " on {}", type_name).unwrap();
+        out.push_str(" on ");
+        out.push_mapped(type_name, node.arguments[1].span());
         self.write_directives(&mut out, &directives);
         writeln!(out, " {{").unwrap();
         self.write_selection_set(
@@ -664,6 +1017,7 @@ impl<'a> Extractor<'a> {
             selection.variable_reference_parameter,
         )?;
         writeln!(out, "}}").unwrap();
+        out.map_from(0, node.span);
 
         Ok(out)
     }
@@ -763,9 +1117,9 @@ impl<'a> Extractor<'a> {
             let property = match property {
                 ObjectPropertyKind::ObjectProperty(property) => property,
                 ObjectPropertyKind::SpreadProperty(spread) => {
-                    return Err(self.extraction_error(
-                        self.calculate_line_number(spread.span.start),
-                        "Object spreads in typed GraphQL selections are not supported",
+                    return Err(self.span_error(
+                        spread.span,
+                        "Object spreads are only supported in typed GraphQL selections and arguments",
                     ));
                 }
             };
@@ -789,16 +1143,32 @@ impl<'a> Extractor<'a> {
         &self,
         object: &'b ObjectExpression<'b>,
         line: u32,
-    ) -> Result<Vec<(&'b str, &'b Expression<'b>)>, MearieError> {
-        let mut properties = Vec::new();
+    ) -> Result<Vec<(&'b str, &'b ObjectProperty<'b>)>, MearieError> {
+        self.object_members(object, line)?
+            .into_iter()
+            .map(|member| match member {
+                TypedGraphqlObjectMember::Property(key, property) => Ok((key, property)),
+                TypedGraphqlObjectMember::Spread(spread) => Err(self.span_error(
+                    spread.span,
+                    "Object spreads are only supported in typed GraphQL selections and arguments",
+                )),
+            })
+            .collect()
+    }
+
+    fn object_members<'b>(
+        &self,
+        object: &'b ObjectExpression<'b>,
+        line: u32,
+    ) -> Result<Vec<TypedGraphqlObjectMember<'b>>, MearieError> {
+        let mut members = Vec::new();
 
         for property in &object.properties {
             let property = match property {
                 ObjectPropertyKind::ObjectProperty(property) => property,
-                ObjectPropertyKind::SpreadProperty(_) => {
-                    return Err(
-                        self.extraction_error(line, "Object spreads in typed GraphQL selections are not supported")
-                    );
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    members.push(TypedGraphqlObjectMember::Spread(spread));
+                    continue;
                 }
             };
 
@@ -809,10 +1179,23 @@ impl<'a> Extractor<'a> {
             let key = self.property_key_name(&property.key).ok_or_else(|| {
                 self.extraction_error(line, "Typed GraphQL object keys must be identifiers or string literals")
             })?;
-            properties.push((key, &property.value));
+            members.push(TypedGraphqlObjectMember::Property(key, property.as_ref()));
         }
 
-        Ok(properties)
+        Ok(members)
+    }
+
+    /// Evaluates the source of an object spread such as `...auditFields` to its fields.
+    fn spread_fields(&self, spread: &SpreadElement) -> Result<Vec<(String, ConstValue)>, MearieError> {
+        match ConstExpr::from_expression(&spread.argument, &self.graphql)
+            .and_then(|constant| constant.evaluate(&mut |name| self.lookup_constant(name)))
+        {
+            Some(ConstValue::Object(fields)) => Ok(fields),
+            _ => Err(self.span_error(
+                spread.span,
+                "Object spreads in typed GraphQL objects must spread a constant object",
+            )),
+        }
     }
 
     fn property_key_name<'b>(&self, key: &'b PropertyKey<'b>) -> Option<&'b str> {
@@ -839,8 +1222,8 @@ impl<'a> Extractor<'a> {
         )?;
         let mut defs = Vec::new();
 
-        for (name, value) in self.object_properties(variables, line)? {
-            let variable_type = self.lower_variable_type(value, line)?;
+        for (name, property) in self.object_properties(variables, line)? {
+            let variable_type = self.lower_variable_type(&property.value, line)?;
             if !variable_type.state.is_final() {
                 return Err(self.extraction_error(
                     line,
@@ -852,6 +1235,9 @@ impl<'a> Extractor<'a> {
                 type_ref: variable_type.type_ref,
                 default_value: variable_type.default_value,
                 directives: variable_type.directives,
+                span: property.span,
+                name_span: property.key.span(),
+                type_span: property.value.span(),
             });
         }
 
@@ -1015,7 +1401,7 @@ impl<'a> Extractor<'a> {
         &self,
         directives: Option<&Expression>,
         line: u32,
-    ) -> Result<Vec<String>, MearieError> {
+    ) -> Result<Vec<MappedText>, MearieError> {
         let Some(directives) = directives else {
             return Ok(Vec::new());
         };
@@ -1033,7 +1419,7 @@ impl<'a> Extractor<'a> {
         expr: &Expression,
         line: u32,
         variable_reference_parameter: Option<&str>,
-    ) -> Result<Vec<String>, MearieError> {
+    ) -> Result<Vec<MappedText>, MearieError> {
         let object = self.expect_object_expr(expr, line, "Directive maps must be object literals")?;
         self.lower_directive_map_object(object, line, variable_reference_parameter)
     }
@@ -1043,18 +1429,21 @@ impl<'a> Extractor<'a> {
         object: &ObjectExpression,
         line: u32,
         variable_reference_parameter: Option<&str>,
-    ) -> Result<Vec<String>, MearieError> {
+    ) -> Result<Vec<MappedText>, MearieError> {
         let mut directives = Vec::new();
 
-        for (name, value) in self.object_properties(object, line)? {
-            match self.strip_expression_wrappers(value) {
-                Expression::BooleanLiteral(value) if value.value => directives.push(format!("@{}", name)),
+        for (name, property) in self.object_properties(object, line)? {
+            let mut directive = MappedText::new();
+            directive.push_mapped(&format!("@{}", name), property.key.span());
+
+            match self.strip_expression_wrappers(&property.value) {
+                Expression::BooleanLiteral(value) if value.value => {}
                 Expression::ObjectExpression(args) => {
                     let args = self.lower_arguments_object(args, line, variable_reference_parameter)?;
-                    if args.is_empty() {
-                        directives.push(format!("@{}", name));
-                    } else {
-                        directives.push(format!("@{}({})", name, args.join(", ")));
+                    if !args.is_empty() {
+                        directive.push('(');
+                        directive.append_joined(&args, ", ");
+                        directive.push(')');
                     }
                 }
                 _ => {
@@ -1064,6 +1453,9 @@ impl<'a> Extractor<'a> {
                     ));
                 }
             }
+
+            directive.map_from(0, property.span);
+            directives.push(directive);
         }
 
         Ok(directives)
@@ -1154,7 +1546,7 @@ impl<'a> Extractor<'a> {
         Err(self.extraction_error(line, message))
     }
 
-    fn write_variable_definitions(&self, out: &mut String, variables: &[TypedGraphqlVariableDefinition]) {
+    fn write_variable_definitions(&self, out: &mut MappedText, variables: &[TypedGraphqlVariableDefinition]) {
         if variables.is_empty() {
             return;
         }
@@ -1164,44 +1556,97 @@ impl<'a> Extractor<'a> {
             if index > 0 {
                 out.push_str(", ");
             }
-            write!(out, "${}: {}", variable.name, variable.type_ref).unwrap();
+            let start = out.len();
+            out.push_mapped(&format!("${}", variable.name), variable.name_span);
+            out.push_str(": ");
+            out.push_mapped(&variable.type_ref, variable.type_span);
             if let Some(default_value) = &variable.default_value {
-                write!(out, " = {}", default_value).unwrap();
+                out.push_str(" = ");
+                out.append(default_value);
             }
             self.write_directives(out, &variable.directives);
+            out.map_from(start, variable.span);
+        }
+        out.push(')');
+    }
+
+    fn write_arguments(&self, out: &mut MappedText, arguments: &[MappedText]) {
+        if arguments.is_empty() {
+            return;
         }
+
+        out.push('(');
+        out.append_joined(arguments, ", ");
         out.push(')');
     }
 
-    fn write_directives(&self, out: &mut String, directives: &[String]) {
+    fn write_directives(&self, out: &mut MappedText, directives: &[MappedText]) {
         for directive in directives {
             out.push(' ');
-            out.push_str(directive);
+            out.append(directive);
         }
     }
 
     fn write_selection_set(
         &self,
-        out: &mut String,
+        out: &mut MappedText,
         selection: &ObjectExpression,
         indent: usize,
         line: u32,
         variable_reference_parameter: Option<&str>,
     ) -> Result<(), MearieError> {
-        for (key, value) in self.object_properties(selection, line)? {
-            if key == "$" {
-                self.write_fragment_items(out, value, indent, line, variable_reference_parameter)?;
-            } else {
-                self.write_field_selection(out, key, value, indent, line, variable_reference_parameter)?;
+        let mut selections = Vec::new();
+
+        for member in self.object_members(selection, line)? {
+            match member {
+                TypedGraphqlObjectMember::Property(key, property) => {
+                    let mut text = MappedText::new();
+                    if key == "$" {
+                        self.write_fragment_items(
+                            &mut text,
+                            &property.value,
+                            indent,
+                            line,
+                            variable_reference_parameter,
+                        )?;
+                    } else {
+                        self.write_field_selection(
+                            &mut text,
+                            key,
+                            &property.value,
+                            indent,
+                            line,
+                            variable_reference_parameter,
+                        )?;
+                        text.map_from(0, property.key.span());
+                    }
+                    insert_member(&mut selections, key.to_string(), text);
+                }
+                TypedGraphqlObjectMember::Spread(spread) => {
+                    for (key, value) in self.spread_fields(spread)? {
+                        let mut text = MappedText::new();
+                        if key == "$" {
+                            self.write_constant_fragment_items(&mut text, &value, indent, line)?;
+                        } else {
+                            self.write_constant_field_selection(&mut text, &key, &value, indent, line)?;
+                        }
+                        text.map_from(0, spread.span);
+                        insert_member(&mut selections, key, text);
+                    }
+                }
             }
         }
 
+        for (_, text) in &selections {
+            out.append(text);
+        }
+
         Ok(())
     }
 
     fn write_field_selection(
         &self,
-        out: &mut String,
+        out: &mut MappedText,
         field_name: &str,
         value: &Expression,
         indent: usize,
@@ -1234,7 +1679,7 @@ impl<'a> Extractor<'a> {
 
     fn write_field_tuple(
         &self,
-        out: &mut String,
+        out: &mut MappedText,
         field_name: &str,
         tuple: &ArrayExpression,
         indent: usize,
@@ -1283,7 +1728,7 @@ impl<'a> Extractor<'a> {
     #[allow(clippy::too_many_arguments)]
     fn write_configured_field(
         &self,
-        out: &mut String,
+        out: &mut MappedText,
         field_name: &str,
         config: &ObjectExpression,
         select: &Expression,
@@ -1301,9 +1746,7 @@ impl<'a> Extractor<'a> {
             write!(out, "{}: ", alias).unwrap();
         }
         write!(out, "{}", field_name).unwrap();
-        if !args.is_empty() {
-            write!(out, "({})", args.join(", ")).unwrap();
-        }
+        self.write_arguments(out, &args);
         self.write_directives(out, &directives);
         writeln!(out, " {{").unwrap();
         self.write_selection_set(out, select, indent + 1, line, variable_reference_parameter)?;
@@ -1315,7 +1758,7 @@ impl<'a> Extractor<'a> {
 
     fn write_scalar_config_field(
         &self,
-        out: &mut String,
+        out: &mut MappedText,
         field_name: &str,
         config: &ObjectExpression,
         indent: usize,
@@ -1331,9 +1774,7 @@ impl<'a> Extractor<'a> {
             write!(out, "{}: ", alias).unwrap();
         }
         write!(out, "{}", field_name).unwrap();
-        if !args.is_empty() {
-            write!(out, "({})", args.join(", ")).unwrap();
-        }
+        self.write_arguments(out, &args);
         self.write_directives(out, &directives);
         writeln!(out).unwrap();
 
@@ -1361,7 +1802,7 @@ impl<'a> Extractor<'a> {
         object: &ObjectExpression,
         line: u32,
         variable_reference_parameter: Option<&str>,
-    ) -> Result<Vec<String>, MearieError> {
+    ) -> Result<Vec<MappedText>, MearieError> {
         self.object_property(object, "args")?
             .map(|args| self.lower_arguments(args, line, variable_reference_parameter))
             .transpose()
@@ -1373,7 +1814,7 @@ impl<'a> Extractor<'a> {
         object: &ObjectExpression,
         line: u32,
         variable_reference_parameter: Option<&str>,
-    ) -> Result<Vec<String>, MearieError> {
+    ) -> Result<Vec<MappedText>, MearieError> {
         self.object_property(object, "directives")?
             .map(|directives| self.lower_directive_map(directives, line, variable_reference_parameter))
             .transpose()
@@ -1385,7 +1826,7 @@ impl<'a> Extractor<'a> {
         expr: &Expression,
         line: u32,
         variable_reference_parameter: Option<&str>,
-    ) -> Result<Vec<String>, MearieError> {
+    ) -> Result<Vec<MappedText>, MearieError> {
         let object = self.expect_object_expr(expr, line, "Arguments must be object literals")?;
         self.lower_arguments_object(object, line, variable_reference_parameter)
     }
@@ -1395,22 +1836,36 @@ impl<'a> Extractor<'a> {
         object: &ObjectExpression,
         line: u32,
         variable_reference_parameter: Option<&str>,
-    ) -> Result<Vec<String>, MearieError> {
-        self.object_properties(object, line)?
-            .into_iter()
-            .map(|(name, value)| {
-                Ok(format!(
-                    "{}: {}",
-                    name,
-                    self.lower_value(value, line, variable_reference_parameter)?
-                ))
-            })
-            .collect()
+    ) -> Result<Vec<MappedText>, MearieError> {
+        let mut arguments = Vec::new();
+
+        for member in self.object_members(object, line)? {
+            match member {
+                TypedGraphqlObjectMember::Property(name, property) => {
+                    let mut argument = MappedText::new();
+                    argument.push_mapped(name, property.key.span());
+                    argument.push_str(": ");
+                    argument.append(&self.lower_value(&property.value, line, variable_reference_parameter)?);
+                    argument.map_from(0, property.span);
+                    insert_member(&mut arguments, name.to_string(), argument);
+                }
+                TypedGraphqlObjectMember::Spread(spread) => {
+                    for (name, value) in self.spread_fields(spread)? {
+                        let mut argument =
+                            MappedText::from(format!("{}: {}", name, self.constant_to_graphql(&value, line)?));
+                        argument.map_from(0, spread.span);
+                        insert_member(&mut arguments, name, argument);
+                    }
+                }
+            }
+        }
+
+        Ok(arguments.into_iter().map(|(_, argument)| argument).collect())
     }
 
     fn write_fragment_items(
         &self,
-        out: &mut String,
+        out: &mut MappedText,
         value: &Expression,
         indent: usize,
         line: u32,
@@ -1431,18 +1886,19 @@ impl<'a> Extractor<'a> {
 
     fn write_fragment_item(
         &self,
-        out: &mut String,
+        out: &mut MappedText,
         item: &Expression,
         indent: usize,
         line: u32,
         variable_reference_parameter: Option<&str>,
     ) -> Result<(), MearieError> {
+        let start = out.len();
+
         match self.strip_expression_wrappers(item) {
             Expression::Identifier(identifier) => {
                 let fragment_name = self.fragment_name_from_identifier(identifier.name.as_str(), line)?;
                 self.write_indent(out, indent);
                 writeln!(out, "...{}", fragment_name).unwrap();
-                Ok(())
             }
             Expression::ObjectExpression(object) => {
                 self.write_indent(out, indent);
@@ -1450,21 +1906,25 @@ impl<'a> Extractor<'a> {
                 self.write_selection_set(out, object, indent + 1, line, variable_reference_parameter)?;
                 self.write_indent(out, indent);
                 writeln!(out, "}}").unwrap();
-                Ok(())
             }
             Expression::ArrayExpression(tuple) => {
-                self.write_fragment_tuple(out, tuple, indent, line, variable_reference_parameter)
+                self.write_fragment_tuple(out, tuple, indent, line, variable_reference_parameter)?;
+            }
+            _ => {
+                return Err(self.extraction_error(
+                    line,
+                    "Fragment entries must be fragment identifiers, selection objects, or fragment tuples",
+                ));
             }
-            _ => Err(self.extraction_error(
-                line,
-                "Fragment entries must be fragment identifiers, selection objects, or fragment tuples",
-            )),
         }
+
+        out.map_from(start, item.span());
+        Ok(())
     }
 
     fn write_fragment_tuple(
         &self,
-        out: &mut String,
+        out: &mut MappedText,
         tuple: &ArrayExpression,
         indent: usize,
         line: u32,
@@ -1532,7 +1992,7 @@ impl<'a> Extractor<'a> {
 
     fn write_configured_fragment_spread(
         &self,
-        out: &mut String,
+        out: &mut MappedText,
         config: &ObjectExpression,
         fragment_name: &str,
         indent: usize,
@@ -1544,9 +2004,7 @@ impl<'a> Extractor<'a> {
 
         self.write_indent(out, indent);
         write!(out, "...{}", fragment_name).unwrap();
-        if !args.is_empty() {
-            write!(out, "({})", args.join(", ")).unwrap();
-        }
+        self.write_arguments(out, &args);
         self.write_directives(out, &directives);
         writeln!(out).unwrap();
 
@@ -1555,7 +2013,7 @@ impl<'a> Extractor<'a> {
 
     fn write_inline_fragment(
         &self,
-        out: &mut String,
+        out: &mut MappedText,
         config: &ObjectExpression,
         selection: &ObjectExpression,
         indent: usize,
@@ -1564,14 +2022,18 @@ impl<'a> Extractor<'a> {
     ) -> Result<(), MearieError> {
         let on = self
             .object_property(config, "on")?
-            .map(|on| self.expect_string_expr(on, line, "Inline fragment type conditions must be string literals"))
+            .map(|on| {
+                self.expect_string_expr(on, line, "Inline fragment type conditions must be string literals")
+                    .map(|type_condition| (type_condition, on.span()))
+            })
             .transpose()?;
         let directives = self.lower_directives_property(config, line, variable_reference_parameter)?;
 
         self.write_indent(out, indent);
         out.push_str("...");
-        if let Some(on) = on {
-            write!(out, " on {}", on).unwrap();
+        if let Some((on, span)) = on {
+            out.push_str(" on ");
+            out.push_mapped(on, span);
         }
         self.write_directives(out, &directives);
         writeln!(out, " {{").unwrap();
@@ -1582,63 +2044,291 @@ impl<'a> Extractor<'a> {
         Ok(())
     }
 
-    fn fragment_name_from_identifier<'b>(&'b self, identifier: &str, line: u32) -> Result<&'b str, MearieError> {
-        self.fragment_bindings
-            .get(identifier)
-            .map(String::as_str)
-            .ok_or_else(|| {
-                let detail = self.unresolved_fragment_imports.get(identifier).map_or_else(
-                    || {
-                        "it was not found in a local graphql.fragment declaration or the cross-file exported fragment registry"
-                            .to_string()
-                    },
-                    |message| message.clone(),
-                );
+    /// Writes a field of a spread selection object. Spread selections are constants
+    /// rather than expressions, but follow the same shapes as inline selections.
+    fn write_constant_field_selection(
+        &self,
+        out: &mut MappedText,
+        field_name: &str,
+        value: &ConstValue,
+        indent: usize,
+        line: u32,
+    ) -> Result<(), MearieError> {
+        match value {
+            ConstValue::Boolean(true) => {
+                self.write_indent(out, indent);
+                writeln!(out, "{}", field_name).unwrap();
+            }
+            ConstValue::Object(selection) => {
+                self.write_indent(out, indent);
+                writeln!(out, "{} {{", field_name).unwrap();
+                self.write_constant_selection_set(out, selection, indent + 1, line)?;
+                self.write_indent(out, indent);
+                writeln!(out, "}}").unwrap();
+            }
+            ConstValue::List(tuple) => {
+                let (config, selection) = match tuple.as_slice() {
+                    [config] => (config, None),
+                    [config, ConstValue::Object(selection)] => (config, Some(selection)),
+                    [_, _] => {
+                        return Err(self.extraction_error(line, "Field tuple selection must be an object literal"));
+                    }
+                    _ => {
+                        return Err(self.extraction_error(
+                            line,
+                            "Field tuples must be [config] for scalar fields or [config, selection] for composite fields",
+                        ));
+                    }
+                };
+                let config = self.expect_constant_config(config, line, "Field", &["alias", "args", "directives"])?;
 
-                self.extraction_error(
+                self.write_indent(out, indent);
+                match constant_member(config, "alias") {
+                    Some(ConstValue::String(alias)) => write!(out, "{}: ", alias).unwrap(),
+                    Some(_) => return Err(self.extraction_error(line, "Field alias must be a string literal")),
+                    None => {}
+                }
+                write!(out, "{}", field_name).unwrap();
+                self.write_arguments(out, &self.constant_arguments(config, line)?);
+                self.write_directives(out, &self.constant_directives(config, line)?);
+                if let Some(selection) = selection {
+                    writeln!(out, " {{").unwrap();
+                    self.write_constant_selection_set(out, selection, indent + 1, line)?;
+                    self.write_indent(out, indent);
+                    writeln!(out, "}}").unwrap();
+                } else {
+                    writeln!(out).unwrap();
+                }
+            }
+            _ => {
+                return Err(self.extraction_error(
                     line,
-                    format!(
-                        "Unable to resolve typed GraphQL fragment identifier '{}': {}",
-                        identifier, detail
-                    ),
-                )
-            })
+                    "Field selections must be true, a nested selection object, or a field tuple",
+                ));
+            }
+        }
+
+        Ok(())
     }
 
-    fn lower_value(
+    fn write_constant_selection_set(
         &self,
-        expr: &Expression,
+        out: &mut MappedText,
+        selection: &[(String, ConstValue)],
+        indent: usize,
         line: u32,
-        variable_reference_parameter: Option<&str>,
-    ) -> Result<String, MearieError> {
-        match self.strip_expression_wrappers(expr) {
-            Expression::CallExpression(call) if self.is_graphql_enum_literal_call(call) => {
-                self.lower_graphql_enum_literal_call(call, line)
+    ) -> Result<(), MearieError> {
+        for (key, value) in selection {
+            if key == "$" {
+                self.write_constant_fragment_items(out, value, indent, line)?;
+            } else {
+                self.write_constant_field_selection(out, key, value, indent, line)?;
             }
-            Expression::StringLiteral(value) => Ok(self.graphql_string(value.value.as_str())),
-            Expression::BooleanLiteral(value) => Ok(value.value.to_string()),
-            Expression::NullLiteral(_) => Ok("null".to_string()),
-            Expression::NumericLiteral(value) => Ok(value
-                .raw
-                .as_ref()
-                .map(|raw| raw.as_str().to_string())
-                .unwrap_or_else(|| value.value.to_string())),
-            Expression::Identifier(_) => Err(self.extraction_error(
-                line,
-                "Typed GraphQL values must be static literals, graphql.enum() calls, or variable references",
-            )),
-            Expression::StaticMemberExpression(member) => {
+        }
+
+        Ok(())
+    }
+
+    fn write_constant_fragment_items(
+        &self,
+        out: &mut MappedText,
+        value: &ConstValue,
+        indent: usize,
+        line: u32,
+    ) -> Result<(), MearieError> {
+        let ConstValue::List(items) = value else {
+            return Err(self.extraction_error(line, "The $ selection property must be an array literal"));
+        };
+
+        for item in items {
+            self.write_indent(out, indent);
+            match item {
+                ConstValue::Fragment(fragment_name) => {
+                    writeln!(out, "...{}", fragment_name).unwrap();
+                }
+                ConstValue::Object(selection) => {
+                    writeln!(out, "... {{").unwrap();
+                    self.write_constant_selection_set(out, selection, indent + 1, line)?;
+                    self.write_indent(out, indent);
+                    writeln!(out, "}}").unwrap();
+                }
+                ConstValue::List(tuple) => match tuple.as_slice() {
+                    [config, ConstValue::Fragment(fragment_name)] => {
+                        let config =
+                            self.expect_constant_config(config, line, "Fragment spread", &["args", "directives"])?;
+                        write!(out, "...{}", fragment_name).unwrap();
+                        self.write_arguments(out, &self.constant_arguments(config, line)?);
+                        self.write_directives(out, &self.constant_directives(config, line)?);
+                        writeln!(out).unwrap();
+                    }
+                    [config, ConstValue::Object(selection)] => {
+                        let config =
+                            self.expect_constant_config(config, line, "Inline fragment", &["on", "directives"])?;
+                        out.push_str("...");
+                        match constant_member(config, "on") {
+                            Some(ConstValue::String(on)) => write!(out, " on {}", on).unwrap(),
+                            Some(_) => {
+                                return Err(self.extraction_error(
+                                    line,
+                                    "Inline fragment type conditions must be string literals",
+                                ));
+                            }
+                            None => {}
+                        }
+                        self.write_directives(out, &self.constant_directives(config, line)?);
+                        writeln!(out, " {{").unwrap();
+                        self.write_constant_selection_set(out, selection, indent + 1, line)?;
+                        self.write_indent(out, indent);
+                        writeln!(out, "}}").unwrap();
+                    }
+                    _ => {
+                        return Err(self.extraction_error(
+                            line,
+                            "Fragment tuples must be [config, fragment] or [config, selection]",
+                        ));
+                    }
+                },
+                _ => {
+                    return Err(self.extraction_error(
+                        line,
+                        "Fragment entries must be fragment identifiers, selection objects, or fragment tuples",
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn expect_constant_config<'b>(
+        &self,
+        value: &'b ConstValue,
+        line: u32,
+        tuple_kind: &str,
+        allowed_keys: &[&str],
+    ) -> Result<&'b [(String, ConstValue)], MearieError> {
+        let ConstValue::Object(config) = value else {
+            return Err(self.extraction_error(line, format!("{} tuple config must be an object literal", tuple_kind)));
+        };
+
+        if let Some((unsupported_key, _)) = config.iter().find(|(key, _)| !allowed_keys.contains(&key.as_str())) {
+            return Err(self.extraction_error(
+                line,
+                format!(
+                    "{} tuple config may only contain {}; unsupported key '{}'",
+                    tuple_kind,
+                    allowed_keys.join(", "),
+                    unsupported_key
+                ),
+            ));
+        }
+
+        Ok(config)
+    }
+
+    fn constant_arguments(&self, config: &[(String, ConstValue)], line: u32) -> Result<Vec<MappedText>, MearieError> {
+        match constant_member(config, "args") {
+            Some(ConstValue::Object(args)) => self.constant_argument_list(args, line),
+            Some(_) => Err(self.extraction_error(line, "Arguments must be object literals")),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn constant_argument_list(&self, args: &[(String, ConstValue)], line: u32) -> Result<Vec<MappedText>, MearieError> {
+        args.iter()
+            .map(|(name, value)| {
+                Ok(MappedText::from(format!(
+                    "{}: {}",
+                    name,
+                    self.constant_to_graphql(value, line)?
+                )))
+            })
+            .collect()
+    }
+
+    fn constant_directives(&self, config: &[(String, ConstValue)], line: u32) -> Result<Vec<MappedText>, MearieError> {
+        let directives = match constant_member(config, "directives") {
+            Some(ConstValue::Object(directives)) => directives,
+            Some(_) => return Err(self.extraction_error(line, "Directive maps must be object literals")),
+            None => return Ok(Vec::new()),
+        };
+
+        directives
+            .iter()
+            .map(|(name, value)| {
+                let mut directive = MappedText::from(format!("@{}", name));
+                match value {
+                    ConstValue::Boolean(true) => {}
+                    ConstValue::Object(args) => {
+                        let args = self.constant_argument_list(args, line)?;
+                        if !args.is_empty() {
+                            directive.push('(');
+                            directive.append_joined(&args, ", ");
+                            directive.push(')');
+                        }
+                    }
+                    _ => {
+                        return Err(self.extraction_error(
+                            line,
+                            "Directive values must be object literals, or true for argument-less directives",
+                        ));
+                    }
+                }
+                Ok(directive)
+            })
+            .collect()
+    }
+
+    fn fragment_name_from_identifier<'b>(&'b self, identifier: &str, line: u32) -> Result<&'b str, MearieError> {
+        self.fragment_bindings
+            .get(identifier)
+            .map(String::as_str)
+            .ok_or_else(|| {
+                let detail = self.unresolved_fragment_imports.get(identifier).map_or_else(
+                    || {
+                        "it was not found in a local graphql.fragment declaration or the cross-file exported fragment registry"
+                            .to_string()
+                    },
+                    |message| message.clone(),
+                );
+
+                self.extraction_error(
+                    line,
+                    format!(
+                        "Unable to resolve typed GraphQL fragment identifier '{}': {}",
+                        identifier, detail
+                    ),
+                )
+            })
+    }
+
+    fn lower_value(
+        &self,
+        expr: &Expression,
+        line: u32,
+        variable_reference_parameter: Option<&str>,
+    ) -> Result<MappedText, MearieError> {
+        let mut value = match self.strip_expression_wrappers(expr) {
+            Expression::CallExpression(call) if self.is_graphql_enum_literal_call(call) => {
+                MappedText::from(self.lower_graphql_enum_literal_call(call, line)?)
+            }
+            Expression::StringLiteral(value) => MappedText::from(graphql_string(value.value.as_str())),
+            Expression::BooleanLiteral(value) => MappedText::from(value.value.to_string()),
+            Expression::NullLiteral(_) => MappedText::from("null".to_string()),
+            Expression::NumericLiteral(value) => MappedText::from(
+                value
+                    .raw
+                    .as_ref()
+                    .map(|raw| raw.as_str().to_string())
+                    .unwrap_or_else(|| value.value.to_string()),
+            ),
+            Expression::StaticMemberExpression(member)
                 if matches!(
                     (self.strip_expression_wrappers(&member.object), variable_reference_parameter),
                     (Expression::Identifier(identifier), Some(parameter)) if identifier.name.as_str() == parameter
-                ) {
-                    Ok(format!("${}", member.property.name.as_str()))
-                } else {
-                    Err(self.extraction_error(
-                        line,
-                        "Typed GraphQL values must be static literals, graphql.enum() calls, or variable references",
-                    ))
-                }
+                ) =>
+            {
+                MappedText::from(format!("${}", member.property.name.as_str()))
             }
             Expression::ArrayExpression(array) => {
                 let mut values = Vec::new();
@@ -1648,17 +2338,53 @@ impl<'a> Extractor<'a> {
                     };
                     values.push(self.lower_value(item, line, variable_reference_parameter)?);
                 }
-                Ok(format!("[{}]", values.join(", ")))
+
+                let mut list = MappedText::new();
+                list.push('[');
+                list.append_joined(&values, ", ");
+                list.push(']');
+                list
             }
             Expression::ObjectExpression(object) => {
-                let values = self.lower_arguments_object(object, line, variable_reference_parameter)?;
-                Ok(format!("{{ {} }}", values.join(", ")))
+                let fields = self.lower_arguments_object(object, line, variable_reference_parameter)?;
+
+                let mut value = MappedText::new();
+                value.push_str("{ ");
+                value.append_joined(&fields, ", ");
+                value.push_str(" }");
+                value
             }
-            _ => Err(self.extraction_error(
-                line,
-                "Typed GraphQL values must be static literals, graphql.enum() calls, or variable references",
-            )),
-        }
+            expr => MappedText::from(self.fold_constant(expr, line)?),
+        };
+
+        value.map_from(0, expr.span());
+        Ok(value)
+    }
+
+    /// Evaluates a value built from constants, e.g. `PAGE_SIZE * 2` or `Status.ACTIVE`.
+    fn fold_constant(&self, expr: &Expression, line: u32) -> Result<String, MearieError> {
+        ConstExpr::from_expression(expr, &self.graphql)
+            .and_then(|constant| constant.evaluate(&mut |name| self.lookup_constant(name)))
+            .and_then(|value| value.to_graphql())
+            .ok_or_else(|| self.non_constant_value_error(line))
+    }
+
+    fn constant_to_graphql(&self, value: &ConstValue, line: u32) -> Result<String, MearieError> {
+        value.to_graphql().ok_or_else(|| self.non_constant_value_error(line))
+    }
+
+    fn non_constant_value_error(&self, line: u32) -> MearieError {
+        self.extraction_error(
+            line,
+            "Typed GraphQL values must be static literals, constants, graphql.enum() calls, or variable references",
+        )
+    }
+
+    fn lookup_constant(&self, name: &str) -> Option<ConstValue> {
+        self.constants
+            .get(name)
+            .cloned()
+            .or_else(|| self.fragment_bindings.get(name).cloned().map(ConstValue::Fragment))
     }
 
     fn is_graphql_enum_literal_call(&self, call: &CallExpression) -> bool {
@@ -1666,11 +2392,7 @@ impl<'a> Extractor<'a> {
             return false;
         };
 
-        member.property.name == "enum"
-            && matches!(
-                self.strip_expression_wrappers(&member.object),
-                Expression::Identifier(identifier) if identifier.name == "graphql"
-            )
+        member.property.name == "enum" && self.graphql.is_graphql(self.strip_expression_wrappers(&member.object))
     }
 
     fn lower_graphql_enum_literal_call(&self, call: &CallExpression, line: u32) -> Result<String, MearieError> {
@@ -1688,24 +2410,7 @@ impl<'a> Extractor<'a> {
         }
     }
 
-    fn graphql_string(&self, value: &str) -> String {
-        let mut result = String::with_capacity(value.len() + 2);
-        result.push('"');
-        for ch in value.chars() {
-            match ch {
-                '"' => result.push_str("\\\""),
-                '\\' => result.push_str("\\\\"),
-                '\n' => result.push_str("\\n"),
-                '\r' => result.push_str("\\r"),
-                '\t' => result.push_str("\\t"),
-                ch => result.push(ch),
-            }
-        }
-        result.push('"');
-        result
-    }
-
-    fn write_indent(&self, out: &mut String, indent: usize) {
+    fn write_indent(&self, out: &mut MappedText, indent: usize) {
         for _ in 0..indent {
             out.push_str("  ");
         }
@@ -1714,9 +2419,7 @@ impl<'a> Extractor<'a> {
 
 impl<'a> Visit<'_> for Extractor<'a> {
     fn visit_call_expression(&mut self, node: &CallExpression) {
-        if let Expression::Identifier(ident) = &node.callee
-            && ident.name == "graphql"
-        {
+        if self.graphql.is_graphql(&node.callee) {
             let offset = node.span.start;
             let line = self.calculate_line_number(offset);
 
@@ -1726,19 +2429,19 @@ impl<'a> Visit<'_> for Extractor<'a> {
             }
 
             match &node.arguments[0] {
-                Argument::TemplateLiteral(template) => match self.extract_template_literal(template, line) {
-                    Ok(code) => {
-                        self.sources.push(SourceBuf {
-                            code,
-                            file_path: self.source.file_path.clone(),
-                            importable_file_path: None,
-                            start_line: line,
-                        });
-                    }
-                    Err(e) => {
-                        self.errors.push(e);
+                Argument::TemplateLiteral(template) => {
+                    self.extracted_templates.insert(template.span.start);
+
+                    match self.extract_template_literal(template) {
+                        Ok(code) => {
+                            // The GraphQL code starts right after the opening backtick.
+                            self.sources.push(self.lowered_source(code, template.span.start + 1));
+                        }
+                        Err(e) => {
+                            self.errors.push(e);
+                        }
                     }
-                },
+                }
                 Argument::StringLiteral(_) => {
                     self.errors.push(
                         MearieError::extraction(
@@ -1748,13 +2451,15 @@ impl<'a> Visit<'_> for Extractor<'a> {
                             file_path: self.source.file_path.clone(),
                             line,
                             column: None,
+                            end_line: None,
+                            end_column: None,
                         }),
                     );
                 }
                 _ => {}
             }
         } else if let Expression::StaticMemberExpression(member) = self.strip_expression_wrappers(&node.callee)
-            && matches!(self.strip_expression_wrappers(&member.object), Expression::Identifier(ident) if ident.name == "graphql")
+            && self.graphql.is_graphql(self.strip_expression_wrappers(&member.object))
             && let Some(kind) = TypedGraphqlKind::from_method(member.property.name.as_str())
         {
             let offset = node.span.start;
@@ -1762,12 +2467,7 @@ impl<'a> Visit<'_> for Extractor<'a> {
 
             match self.lower_typed_graphql_call(node, kind, line) {
                 Ok(code) => {
-                    self.sources.push(SourceBuf {
-                        code,
-                        file_path: self.source.file_path.clone(),
-                        importable_file_path: None,
-                        start_line: line,
-                    });
+                    self.sources.push(self.lowered_source(code, offset));
                 }
                 Err(e) => {
                     self.errors.push(e);
@@ -1777,11 +2477,50 @@ impl<'a> Visit<'_> for Extractor<'a> {
 
         walk::walk_call_expression(self, node);
     }
+
+    fn visit_tagged_template_expression(&mut self, node: &TaggedTemplateExpression) {
+        if self.graphql.is_graphql(&node.tag) {
+            self.extracted_templates.insert(node.quasi.span.start);
+
+            match self.extract_tagged_template(&node.quasi) {
+                Ok(code) => {
+                    // The GraphQL code starts right after the opening backtick.
+                    self.sources.push(self.lowered_source(code, node.quasi.span.start + 1));
+                }
+                Err(e) => {
+                    self.errors.push(e);
+                }
+            }
+        }
+
+        walk::walk_tagged_template_expression(self, node);
+    }
+
+    fn visit_template_literal(&mut self, node: &TemplateLiteral) {
+        if self.graphql.is_graphql_template(node) && !self.extracted_templates.contains(&node.span.start) {
+            match self.extract_template_literal(node) {
+                Ok(code) => {
+                    // The GraphQL code starts right after the opening backtick.
+                    self.sources.push(self.lowered_source(code, node.span.start + 1));
+                }
+                Err(e) => {
+                    self.errors.push(e);
+                }
+            }
+        }
+
+        walk::walk_template_literal(self, node);
+    }
 }
 
 fn collect_typed_graphql_fragment_registry(
     source: &SourceBuf,
+    config: &ExtractionConfig,
 ) -> Result<TypedGraphqlFileFragmentRegistry, Vec<MearieError>> {
+    if is_graphql_document(&source.file_path) {
+        return Ok(documents::collect_document_registry(source));
+    }
+
     let allocator = Allocator::default();
     let source_type = SourceType::tsx();
 
@@ -1804,6 +2543,8 @@ fn collect_typed_graphql_fragment_registry(
                     file_path: source.file_path.clone(),
                     line,
                     column: None,
+                    end_line: None,
+                    end_column: None,
                 })
             })
             .collect();
@@ -1811,7 +2552,8 @@ fn collect_typed_graphql_fragment_registry(
         return Err(errors);
     }
 
-    let mut fragment_collector = TypedGraphqlFragmentBindingCollector::new();
+    let graphql = GraphqlBindings::collect(&result.program, config);
+    let mut fragment_collector = TypedGraphqlFragmentBindingCollector::new(graphql);
     fragment_collector.visit_program(&result.program);
 
     Ok(fragment_collector.into_registry())
@@ -1819,8 +2561,13 @@ fn collect_typed_graphql_fragment_registry(
 
 fn extract_graphql_sources_with_fragment_bindings(
     source: &SourceBuf,
+    config: &ExtractionConfig,
     resolved_fragment_bindings: TypedGraphqlResolvedFragmentBindings,
 ) -> ExtractResult {
+    if is_graphql_document(&source.file_path) {
+        return documents::extract_document_sources(source, &resolved_fragment_bindings);
+    }
+
     let allocator = Allocator::default();
     let source_type = SourceType::tsx();
 
@@ -1843,6 +2590,8 @@ fn extract_graphql_sources_with_fragment_bindings(
                     file_path: source.file_path.clone(),
                     line,
                     column: None,
+                    end_line: None,
+                    end_column: None,
                 })
             })
             .collect();
@@ -1853,7 +2602,8 @@ fn extract_graphql_sources_with_fragment_bindings(
         };
     }
 
-    let mut extractor = Extractor::new(source, resolved_fragment_bindings);
+    let graphql = GraphqlBindings::collect(&result.program, config);
+    let mut extractor = Extractor::new(source, graphql, resolved_fragment_bindings);
     extractor.visit_program(&result.program);
 
     ExtractResult {
@@ -1867,46 +2617,14 @@ pub fn extract_graphql_sources(source: SourceBuf) -> ExtractResult {
 }
 
 pub fn extract_graphql_sources_from_documents(sources: Vec<SourceBuf>) -> ExtractResult {
-    let mut registries: HashMap<String, TypedGraphqlFileFragmentRegistry> = HashMap::new();
-    let mut errors = Vec::new();
-
-    for source in &sources {
-        match collect_typed_graphql_fragment_registry(source) {
-            Ok(registry) => {
-                registries
-                    .entry(registry_file_path(source))
-                    .or_default()
-                    .merge(registry);
-            }
-            Err(source_errors) => {
-                errors.extend(source_errors);
-            }
-        }
-    }
-
-    if !errors.is_empty() {
-        return ExtractResult {
-            sources: Vec::new(),
-            errors,
-        };
-    }
-
-    let resolver = TypedGraphqlCrossFileFragmentResolver::new(&registries);
-    let mut extracted_sources = Vec::new();
-    let mut extraction_errors = Vec::new();
-
-    for source in &sources {
-        let file_path = registry_file_path(source);
-        let resolved_fragment_bindings = resolver.resolve_file_bindings(&file_path);
-        let result = extract_graphql_sources_with_fragment_bindings(source, resolved_fragment_bindings);
-        extracted_sources.extend(result.sources);
-        extraction_errors.extend(result.errors);
-    }
+    extract_graphql_sources_with_config(sources, &ExtractionConfig::default())
+}
 
-    ExtractResult {
-        sources: extracted_sources,
-        errors: extraction_errors,
-    }
+/// Extracts GraphQL sources from documents, recognizing `graphql` as configured.
+/// Component and Markdown documents are split into their script blocks first, and
+/// `.graphql` and `.gql` documents are GraphQL sources themselves.
+pub fn extract_graphql_sources_with_config(sources: Vec<SourceBuf>, config: &ExtractionConfig) -> ExtractResult {
+    ExtractionSession::new(config.clone()).extract(sources)
 }
 
 #[cfg(test)]
@@ -1924,15 +2642,31 @@ mod tests {
             file_path: file_path.to_string(),
             importable_file_path: None,
             start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
         }
     }
 
+    /// Extracts from `source` with `graphql` provided as a global, so that it need not
+    /// be imported.
+    fn extract_with_global_graphql(source: SourceBuf) -> ExtractResult {
+        extract_documents_with_global_graphql(vec![source])
+    }
+
+    fn extract_documents_with_global_graphql(sources: Vec<SourceBuf>) -> ExtractResult {
+        extract_graphql_sources_with_config(sources, &ExtractionConfig::new().with_global_graphql(true))
+    }
+
     fn create_source_block(file_path: &str, importable_file_path: &str, code: &str) -> SourceBuf {
         SourceBuf {
             code: code.to_string(),
             file_path: file_path.to_string(),
             importable_file_path: Some(importable_file_path.to_string()),
             start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
         }
     }
 
@@ -1960,7 +2694,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
         assert_contains!(&result.sources[0].code, "GetUser");
@@ -1978,7 +2712,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 3);
         assert_is_empty!(&result.errors);
         assert_contains!(&result.sources[0].code, "GetUser");
@@ -1986,6 +2720,36 @@ mod tests {
         assert_contains!(&result.sources[2].code, "CreateUser");
     }
 
+    #[test]
+    fn test_extracted_source_starts_after_backtick() {
+        let code = "const a = 1;\n    const query = graphql(`query GetUser { user { id } }`);\n";
+        let result = extract_with_global_graphql(create_source(code));
+        assert_is_empty!(&result.errors);
+
+        let source = &result.sources[0];
+        assert_eq!(source.start_line, 2);
+        assert_eq!(source.start_column, 28);
+        assert_eq!(&code[source.offset as usize..][..5], "query");
+    }
+
+    #[test]
+    fn test_extracted_source_is_positioned_in_host_file() {
+        let mut block = create_source("graphql(`query Q { id }`);\ngraphql(`query R { id }`);");
+        block.start_line = 10;
+        block.start_column = 9;
+        block.offset = 200;
+
+        let result = extract_with_global_graphql(block);
+        assert_is_empty!(&result.errors);
+
+        assert_eq!(result.sources[0].start_line, 10);
+        assert_eq!(result.sources[0].start_column, 18);
+        assert_eq!(result.sources[0].offset, 209);
+        assert_eq!(result.sources[1].start_line, 11);
+        assert_eq!(result.sources[1].start_column, 10);
+        assert_eq!(result.sources[1].offset, 236);
+    }
+
     #[test]
     fn test_graphql_with_string_literal_error() {
         let source = r#"
@@ -1994,7 +2758,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_is_empty!(&result.sources);
         assert_len_eq_x!(&result.errors, 1);
     }
@@ -2007,7 +2771,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_is_empty!(&result.sources);
         assert_len_eq_x!(&result.errors, 1);
     }
@@ -2025,7 +2789,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
         assert_contains!(&result.sources[0].code, "GetUser");
@@ -2047,9 +2811,173 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
+        assert_is_empty!(&result.sources);
+        assert_len_eq_x!(&result.errors, 1);
+    }
+
+    #[test]
+    fn test_extract_tagged_template() {
+        let source = r#"
+            import { graphql as gql } from '$mearie';
+
+            const query = graphql`query GetUser { user { id } }`;
+            const fragment = gql`fragment UserFields on User { name }`;
+        "#;
+
+        let result = extract_with_global_graphql(create_source(source));
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 2);
+        assert_eq!(result.sources[0].code, "query GetUser { user { id } }");
+        assert_eq!(result.sources[0].start_line, 4);
+        assert_eq!(result.sources[0].start_column, 35);
+        assert_eq!(result.sources[1].code, "fragment UserFields on User { name }");
+    }
+
+    #[test]
+    fn test_tagged_template_with_interpolation_error() {
+        let source = r#"
+            const query = graphql`
+                query GetUser {
+                    user(id: ${userId}) { id }
+                }
+            `;
+        "#;
+
+        let result = extract_with_global_graphql(create_source(source));
+        assert_is_empty!(&result.sources);
+        assert_len_eq_x!(&result.errors, 1);
+        let location = result.errors[0].location().unwrap();
+        assert_eq!((location.line, location.column), (4, Some(32)));
+        assert_eq!(location.end_column, Some(38));
+    }
+
+    #[test]
+    fn test_ignore_tagged_template_with_other_tag() {
+        let source = r#"
+            const style = css`color: red;`;
+            const query = sql`SELECT 1`;
+        "#;
+
+        let result = extract_graphql_sources(create_source(source));
+        assert_is_empty!(&result.errors);
         assert_is_empty!(&result.sources);
+    }
+
+    #[test]
+    fn test_extract_with_fragment_interpolation() {
+        let source = r#"
+            const UserFieldsFragment = graphql(`fragment UserFields on User { name }`);
+            const TaggedFragment = graphql`
+                # User avatar
+                fragment UserAvatar on User { avatar }
+            `;
+
+            const query = graphql`query GetUser { user { ...UserFields ...UserAvatar } } ${UserFieldsFragment}${TaggedFragment}`;
+        "#;
+
+        let result = extract_with_global_graphql(create_source(source));
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 3);
+        assert_eq!(
+            result.sources[2].code,
+            "query GetUser { user { ...UserFields ...UserAvatar } } "
+        );
+    }
+
+    #[test]
+    fn test_call_form_fragment_interpolation_error() {
+        let source = r#"
+            const UserFieldsFragment = graphql(`fragment UserFields on User { name }`);
+            const query = graphql(`query GetUser { user { ...UserFields } } ${UserFieldsFragment}`);
+        "#;
+
+        let result = extract_with_global_graphql(create_source(source));
+        assert_len_eq_x!(&result.sources, 1);
         assert_len_eq_x!(&result.errors, 1);
+        assert_eq!(
+            result.errors[0].message(),
+            "Template literal interpolation is only supported in tagged templates, e.g. graphql`...`"
+        );
+        let location = result.errors[0].location().unwrap();
+        assert_eq!((location.line, location.column), (3, Some(79)));
+    }
+
+    #[test]
+    fn test_extract_call_form_escapes_as_cooked_text() {
+        let source = r#"
+            const query = graphql(`query GetGreeting { greeting(format: "\\\\d+", locale: "caf\u00e9") }`);
+            const tagged = graphql`query GetTaggedGreeting { greeting(format: "\\\\d+") }`;
+        "#;
+
+        let result = extract_with_global_graphql(create_source(source));
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 2);
+        assert_eq!(
+            result.sources[0].code,
+            r#"query GetGreeting { greeting(format: "\\d+", locale: "café") }"#
+        );
+        let mappings = result.sources[0].mappings.as_deref().unwrap();
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings[0].generated_start, 40);
+        assert_eq!((mappings[0].line, mappings[0].column), (2, 76));
+        assert_eq!(
+            result.sources[1].code,
+            r#"query GetTaggedGreeting { greeting(format: "\\\\d+") }"#
+        );
+    }
+
+    #[test]
+    fn test_extract_with_imported_fragment_interpolation() {
+        let fragment_source = r#"
+            export const UserFieldsFragment = graphql`fragment UserFields on User { name }`;
+        "#;
+
+        let query_source = r#"
+            import { UserFieldsFragment as Fields } from './fragments';
+
+            const query = graphql`
+                ${Fields}
+                query GetUser { user { ...UserFields } }
+            `;
+        "#;
+
+        let result = extract_documents_with_global_graphql(vec![
+            create_source_with_path("/project/fragments.ts", fragment_source),
+            create_source_with_path("/project/query.ts", query_source),
+        ]);
+
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 2);
+
+        let query = &result.sources[1];
+        assert_not_contains!(&query.code, "Fields}");
+        let mappings = query.mappings.as_deref().unwrap();
+        assert_eq!(mappings.len(), 1);
+        assert_eq!((mappings[0].line, mappings[0].column), (5, 26));
+    }
+
+    #[test]
+    fn test_unresolved_fragment_interpolation_error() {
+        let source = r#"
+            import { UserFieldsFragment } from './missing';
+
+            const query = graphql`query GetUser { user { ...UserFields } } ${UserFieldsFragment}`;
+            const other = graphql`query GetUsers { users { id } } ${fragments.user}`;
+        "#;
+
+        let result = extract_with_global_graphql(create_source_with_path("/project/query.ts", source));
+        assert_is_empty!(&result.sources);
+        assert_len_eq_x!(&result.errors, 2);
+        assert_contains!(
+            result.errors[0].message(),
+            "Unable to resolve interpolated fragment 'UserFieldsFragment'"
+        );
+        assert_eq!(result.errors[0].location().unwrap().column, Some(78));
+        assert_eq!(
+            result.errors[1].message(),
+            "Template literal interpolation must reference a fragment document by name"
+        );
     }
 
     #[test]
@@ -2066,7 +2994,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
         assert_contains!(&result.sources[0].code, "UserFields");
@@ -2088,7 +3016,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
         assert_contains!(&result.sources[0].code, "CreateUser");
@@ -2110,7 +3038,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
         assert_contains!(&result.sources[0].code, "OnMessageAdded");
@@ -2139,9 +3067,12 @@ mod tests {
             file_path: "test.ts".to_string(),
             importable_file_path: None,
             start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
         };
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
         assert_contains!(&result.sources[0].code, "GetUser");
@@ -2165,9 +3096,12 @@ mod tests {
             file_path: "test.tsx".to_string(),
             importable_file_path: None,
             start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
         };
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
         assert_contains!(&result.sources[0].code, "GetUser");
@@ -2190,6 +3124,137 @@ mod tests {
         assert_is_empty!(&result.errors);
     }
 
+    #[test]
+    fn test_extract_aliased_graphql_import() {
+        let source = r#"
+            import { graphql as gql } from '$mearie';
+
+            const query = gql(`query GetUser { user { id } }`);
+            const Fragment = gql.fragment('UserFields', 'User', { select: () => ({ id: true }) });
+        "#;
+
+        let result = extract_graphql_sources(create_source(source));
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 2);
+        assert_contains!(&result.sources[0].code, "GetUser");
+        assert_contains!(&result.sources[1].code, "fragment UserFields on User");
+    }
+
+    #[test]
+    fn test_ignore_graphql_from_other_modules() {
+        let source = r#"
+            import { graphql } from 'react-relay';
+
+            const query = graphql(`query GetUser { user { id } }`);
+        "#;
+
+        let result = extract_graphql_sources(create_source(source));
+        assert_is_empty!(&result.sources);
+        assert_is_empty!(&result.errors);
+    }
+
+    #[test]
+    fn test_ignore_unimported_graphql() {
+        let source = r#"
+            const query = graphql(`query GetUser { user { id } }`);
+        "#;
+
+        let result = extract_graphql_sources(create_source(source));
+        assert_is_empty!(&result.sources);
+        assert_is_empty!(&result.errors);
+
+        let result = extract_with_global_graphql(create_source(source));
+        assert_len_eq_x!(&result.sources, 1);
+    }
+
+    #[test]
+    fn test_ignore_graphql_shadowed_by_parameter() {
+        let source = r#"
+            import { graphql } from '$mearie';
+
+            function build(graphql) {
+                return graphql(`not a graphql document`);
+            }
+
+            const query = graphql(`query GetUser { user { id } }`);
+        "#;
+
+        let result = extract_graphql_sources(create_source(source));
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 1);
+        assert_contains!(&result.sources[0].code, "GetUser");
+    }
+
+    #[test]
+    fn test_ignore_graphql_shadowed_by_nested_declaration() {
+        let source = r#"
+            import { graphql } from '$mearie';
+
+            export function load() {
+                if (true) {
+                    const graphql = (strings) => strings.join('');
+                    return graphql`not a graphql document`;
+                }
+                return graphql(`query GetUser { user { id } }`);
+            }
+        "#;
+
+        let result = extract_graphql_sources(create_source(source));
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 1);
+        assert_contains!(&result.sources[0].code, "GetUser");
+
+        let result = extract_with_global_graphql(create_source(
+            "function build(graphql) { return graphql(`not a graphql document`); }",
+        ));
+        assert_is_empty!(&result.sources);
+    }
+
+    #[test]
+    fn test_ignore_locally_declared_graphql() {
+        let source = r#"
+            function graphql(strings) {
+                return strings.join('');
+            }
+
+            const query = graphql(`not a graphql document`);
+        "#;
+
+        let result = extract_graphql_sources(create_source(source));
+        assert_is_empty!(&result.sources);
+        assert_is_empty!(&result.errors);
+    }
+
+    #[test]
+    fn test_ambient_graphql_declaration_is_extracted() {
+        let source = r#"
+            declare function graphql(document: string): unknown;
+
+            const query = graphql(`query GetUser { user { id } }`);
+        "#;
+
+        let result = extract_with_global_graphql(create_source_with_path("test.ts", source));
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 1);
+    }
+
+    #[test]
+    fn test_extract_graphql_from_configured_modules() {
+        let source = r#"
+            import { graphql } from '~/graphql';
+            import { graphql as other } from '$mearie';
+
+            const query = graphql(`query GetUser { user { id } }`);
+            const ignored = other(`query Ignored { user { id } }`);
+        "#;
+
+        let config = ExtractionConfig::new().with_graphql_modules(vec!["~/graphql".to_string()]);
+        let result = extract_graphql_sources_with_config(vec![create_source(source)], &config);
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 1);
+        assert_contains!(&result.sources[0].code, "GetUser");
+    }
+
     #[test]
     fn test_extract_typed_graphql_query() {
         let source = r#"
@@ -2231,7 +3296,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources(create_source(source));
+        let result = extract_with_global_graphql(create_source(source));
 
         assert_len_eq_x!(&result.sources, 2);
         assert_is_empty!(&result.errors);
@@ -2250,6 +3315,36 @@ mod tests {
         assert_contains!(&result.sources[1].code, "... on Admin @include(if: true)");
     }
 
+    #[test]
+    fn test_typed_graphql_maps_generated_code_to_builder_code() {
+        let source = [
+            "graphql.query('GetUser', {",
+            "  variables: (t) => ({ id: t.ID.nonNull() }),",
+            "  select: ($) => ({",
+            "    user: [{ args: { id: $.id } }, { name: true }],",
+            "  }),",
+            "});",
+        ]
+        .join("\n");
+
+        let result = extract_with_global_graphql(create_source(&source));
+        assert_is_empty!(&result.errors);
+
+        let extracted = &result.sources[0];
+        let position_of = |text: &str| {
+            let source = Source::from(extracted);
+            let mapping = source.mapping_at(extracted.code.find(text).unwrap()).unwrap();
+            (mapping.line, mapping.column, mapping.end_column)
+        };
+
+        assert_eq!(position_of("GetUser"), (1, 15, 24));
+        assert_eq!(position_of("$id: ID!"), (2, 24, 26));
+        assert_eq!(position_of("ID!"), (2, 28, 42));
+        assert_eq!(position_of("$id)"), (4, 26, 30));
+        assert_eq!(position_of("name"), (4, 38, 42));
+        assert_eq!(position_of("query "), (1, 1, 3));
+    }
+
     #[test]
     fn test_extract_typed_graphql_uses_callback_parameter_for_variable_references() {
         let source = r#"
@@ -2270,7 +3365,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources(create_source(source));
+        let result = extract_with_global_graphql(create_source(source));
 
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
@@ -2307,7 +3402,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources(create_source(source));
+        let result = extract_with_global_graphql(create_source(source));
 
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
@@ -2334,7 +3429,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources(create_source(source));
+        let result = extract_with_global_graphql(create_source(source));
 
         assert_is_empty!(&result.sources);
         assert_len_eq_x!(&result.errors, 1);
@@ -2357,7 +3452,7 @@ mod tests {
                 });
             "#,
             r#"
-                const status = 'ACTIVE';
+                let status = 'ACTIVE';
                 const query = graphql.query('FindUsers', {
                     select: () => ({
                         users: [{ args: { status } }, {
@@ -2369,13 +3464,13 @@ mod tests {
         ];
 
         for source in cases {
-            let result = extract_graphql_sources(create_source(source));
+            let result = extract_with_global_graphql(create_source(source));
 
             assert_is_empty!(&result.sources);
             assert_len_eq_x!(&result.errors, 1);
             assert_contains!(
                 &format!("{:?}", result.errors[0]),
-                "Typed GraphQL values must be static literals, graphql.enum() calls, or variable references"
+                "Typed GraphQL values must be static literals, constants, graphql.enum() calls, or variable references"
             );
         }
     }
@@ -2383,7 +3478,7 @@ mod tests {
     #[test]
     fn test_extract_typed_graphql_rejects_non_variable_member_values() {
         let source = r#"
-            const Status = { ACTIVE: 'ACTIVE' };
+            const Status = loadStatuses();
             const query = graphql.query('FindUsers', {
                 select: () => ({
                     users: [{ args: { status: Status.ACTIVE } }, {
@@ -2393,14 +3488,209 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources(create_source(source));
+        let result = extract_with_global_graphql(create_source(source));
 
         assert_is_empty!(&result.sources);
         assert_len_eq_x!(&result.errors, 1);
         assert_contains!(
             &format!("{:?}", result.errors[0]),
-            "Typed GraphQL values must be static literals, graphql.enum() calls, or variable references"
+            "Typed GraphQL values must be static literals, constants, graphql.enum() calls, or variable references"
+        );
+    }
+
+    #[test]
+    fn test_extract_typed_graphql_folds_constant_values() {
+        let source = r#"
+            const PAGE_SIZE = 20;
+            const CACHE_TTL = 60 * 5;
+            const PREFIX = 'user';
+            const Status = { ACTIVE: graphql.enum('ACTIVE'), ARCHIVED: 'ARCHIVED' } as const;
+            const ROLES = ['admin', 'editor'] as const;
+            export const FILTER = { roles: ROLES, name: `${PREFIX}-${PAGE_SIZE}` };
+
+            const query = graphql.query('FindUsers', {
+                directives: () => ({ cache: { ttl: CACHE_TTL } }),
+                select: () => ({
+                    users: [{ args: { first: PAGE_SIZE, last: -PAGE_SIZE / 4, status: Status.ACTIVE, role: ROLES[1], filter: FILTER } }, {
+                        id: true,
+                    }],
+                }),
+            });
+        "#;
+
+        let result = extract_with_global_graphql(create_source(source));
+
+        assert_is_empty!(&result.errors);
+        let query = &result.sources[0].code;
+        assert_contains!(query, "@cache(ttl: 300)");
+        assert_contains!(
+            query,
+            r#"users(first: 20, last: -5, status: ACTIVE, role: "editor", filter: { roles: ["admin", "editor"], name: "user-20" })"#
+        );
+    }
+
+    #[test]
+    fn test_extract_typed_graphql_folds_constants_imported_from_other_files() {
+        let constants_source = r#"
+            const BASE = 10;
+            export const PAGE_SIZE = BASE * 2;
+            export { BASE as MIN_SIZE };
+        "#;
+
+        let index_source = r#"
+            export * from './constants';
+        "#;
+
+        let query_source = r#"
+            import { PAGE_SIZE, MIN_SIZE as MIN } from './config';
+
+            const query = graphql.query('FindUsers', {
+                select: () => ({
+                    users: [{ args: { first: PAGE_SIZE, min: MIN } }, { id: true }],
+                }),
+            });
+        "#;
+
+        let result = extract_documents_with_global_graphql(vec![
+            create_source_with_path("/project/config/constants.ts", constants_source),
+            create_source_with_path("/project/config/index.ts", index_source),
+            create_source_with_path("/project/query.ts", query_source),
+        ]);
+
+        assert_is_empty!(&result.errors);
+        assert_contains!(
+            source_code_containing(&result, "query FindUsers"),
+            "users(first: 20, min: 10)"
+        );
+    }
+
+    #[test]
+    fn test_extract_typed_graphql_rejects_values_depending_on_runtime_data() {
+        let cases = [
+            "const PAGE_SIZE = getPageSize();",
+            "const PAGE_SIZE = BASE * 2; let BASE = 10;",
+            "const PAGE_SIZE = PAGE_SIZE + 1;",
+            "const PAGE_SIZE = { size: 10 }.missing;",
+            "import { PAGE_SIZE } from './missing';",
+        ];
+
+        for declaration in cases {
+            let source = format!(
+                r#"
+                    {declaration}
+                    const query = graphql.query('FindUsers', {{
+                        select: () => ({{
+                            users: [{{ args: {{ first: PAGE_SIZE }} }}, {{ id: true }}],
+                        }}),
+                    }});
+                "#
+            );
+
+            let result = extract_with_global_graphql(create_source_with_path("/project/query.ts", &source));
+
+            assert_is_empty!(&result.sources);
+            assert_len_eq_x!(&result.errors, 1);
+            assert_contains!(
+                result.errors[0].message(),
+                "Typed GraphQL values must be static literals, constants"
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_typed_graphql_spreads_constant_selections() {
+        let source = r#"
+            const UserCard = graphql.fragment('UserCard', 'User', {
+                select: () => ({ name: true }),
+            });
+            const auditFields = { createdAt: true, updatedAt: true };
+            const authorFields = {
+                ...auditFields,
+                author: { $: [UserCard] },
+                avatar: [{ alias: 'thumbnail', args: { size: 64 } }],
+            } as const;
+            const paging = { first: 10, after: null };
+
+            const query = graphql.query('FindPosts', {
+                select: () => ({
+                    posts: [{ args: { ...paging, first: 20 } }, {
+                        id: true,
+                        ...authorFields,
+                        updatedAt: [{ directives: { skip: { if: true } } }],
+                    }],
+                }),
+            });
+        "#;
+
+        let result = extract_with_global_graphql(create_source(source));
+
+        assert_is_empty!(&result.errors);
+        let query = source_code_containing(&result, "query FindPosts");
+        assert_contains!(query, "posts(first: 20, after: null) {");
+        assert_contains!(
+            query,
+            "    id\n    createdAt\n    updatedAt @skip(if: true)\n    author {\n      ...UserCard\n    }\n    thumbnail: avatar(size: 64)\n"
+        );
+    }
+
+    #[test]
+    fn test_extract_typed_graphql_spreads_selections_imported_from_other_files() {
+        let fragment_source = r#"
+            export const UserCard = graphql.fragment('UserCard', 'User', {
+                select: () => ({ name: true }),
+            });
+        "#;
+
+        let fields_source = r#"
+            import { UserCard } from './UserCard';
+
+            export const userFields = { id: true, $: [[{ on: 'User' }, { $: [UserCard] }]] };
+        "#;
+
+        let query_source = r#"
+            import { userFields } from './fields';
+
+            const query = graphql.query('FindUser', {
+                select: () => ({
+                    user: { ...userFields },
+                }),
+            });
+        "#;
+
+        let result = extract_documents_with_global_graphql(vec![
+            create_source_with_path("/project/UserCard.ts", fragment_source),
+            create_source_with_path("/project/fields.ts", fields_source),
+            create_source_with_path("/project/query.ts", query_source),
+        ]);
+
+        assert_is_empty!(&result.errors);
+        assert_contains!(
+            source_code_containing(&result, "query FindUser"),
+            "  user {\n    id\n    ... on User {\n      ...UserCard\n    }\n  }\n"
+        );
+    }
+
+    #[test]
+    fn test_extract_typed_graphql_rejects_spreads_of_runtime_objects() {
+        let source = r#"
+            const auditFields = loadAuditFields();
+
+            const query = graphql.query('FindPosts', {
+                select: () => ({
+                    posts: { id: true, ...auditFields },
+                }),
+            });
+        "#;
+
+        let result = extract_with_global_graphql(create_source(source));
+
+        assert_is_empty!(&result.sources);
+        assert_len_eq_x!(&result.errors, 1);
+        assert_contains!(
+            result.errors[0].message(),
+            "Object spreads in typed GraphQL objects must spread a constant object"
         );
+        assert_eq!(result.errors[0].location().unwrap().line, 6);
     }
 
     #[test]
@@ -2421,7 +3711,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources(create_source(source));
+        let result = extract_with_global_graphql(create_source(source));
 
         assert_len_eq_x!(&result.sources, 2);
         assert_is_empty!(&result.errors);
@@ -2452,7 +3742,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources_from_documents(vec![
+        let result = extract_documents_with_global_graphql(vec![
             create_source_with_path("/project/movie-card.ts", fragment_source),
             create_source_with_path("/project/query.ts", query_source),
         ]);
@@ -2464,6 +3754,100 @@ mod tests {
         assert_not_contains!(query, "...ImportedMovieCard");
     }
 
+    #[test]
+    fn test_extract_typed_graphql_resolves_fragment_imported_through_path_alias() {
+        let fragment_source = r#"
+            import { graphql } from '$mearie';
+
+            export const UserCardFragment = graphql.fragment('UserCard', 'User', {
+                select: () => ({ name: true }),
+            });
+        "#;
+
+        let query_source = r#"
+            import { graphql } from '$mearie';
+            import { UserCardFragment } from '@/components/UserCard';
+
+            const query = graphql.query('GetUser', {
+                select: () => ({
+                    user: [{}, { $: [UserCardFragment] }],
+                }),
+            });
+        "#;
+
+        let sources = vec![
+            create_source_with_path("/project/src/components/UserCard/index.tsx", fragment_source),
+            create_source_with_path("/project/src/pages/user.ts", query_source),
+        ];
+
+        let result = extract_graphql_sources_from_documents(sources.clone());
+        assert_len_eq_x!(&result.errors, 1);
+        assert_contains!(
+            result.errors[0].message(),
+            "'@/components/UserCard' did not match any document file"
+        );
+
+        let config = ExtractionConfig::new()
+            .with_base_url("/project")
+            .with_paths(vec![PathAlias {
+                pattern: "@/*".to_string(),
+                targets: vec!["src/*".to_string()],
+            }]);
+        let result = extract_graphql_sources_with_config(sources, &config);
+        assert_is_empty!(&result.errors);
+        assert_contains!(source_code_containing(&result, "query GetUser"), "...UserCard");
+    }
+
+    #[test]
+    fn test_extract_typed_graphql_resolves_fragment_from_workspace_package() {
+        let fragment_source = r#"
+            import { graphql } from '$mearie';
+
+            export const UserCardFragment = graphql.fragment('UserCard', 'User', {
+                select: () => ({ name: true }),
+            });
+        "#;
+
+        let index_source = r#"
+            export { UserCardFragment as UserCard } from './fragments/user-card';
+        "#;
+
+        let query_source = r#"
+            import { graphql } from '$mearie';
+            import { UserCard } from '@acme/ui';
+            import { UserCardFragment } from '@acme/ui/fragments/user-card';
+
+            const query = graphql.query('GetUser', {
+                select: () => ({
+                    user: [{}, { $: [UserCard] }],
+                    viewer: [{}, { $: [UserCardFragment] }],
+                }),
+            });
+        "#;
+
+        let config = ExtractionConfig::new().with_packages(vec![WorkspacePackage {
+            name: "@acme/ui".to_string(),
+            root: "/repo/packages/ui".to_string(),
+            exports: vec![
+                (".".to_string(), "./src/index.js".to_string()),
+                ("./fragments/*".to_string(), "./src/fragments/*.ts".to_string()),
+            ],
+        }]);
+
+        let result = extract_graphql_sources_with_config(
+            vec![
+                create_source_with_path("/repo/packages/ui/src/fragments/user-card.ts", fragment_source),
+                create_source_with_path("/repo/packages/ui/src/index.ts", index_source),
+                create_source_with_path("/repo/apps/web/src/user.ts", query_source),
+            ],
+            &config,
+        );
+
+        assert_is_empty!(&result.errors);
+        let query = source_code_containing(&result, "query GetUser");
+        assert_eq!(query.matches("...UserCard").count(), 2);
+    }
+
     #[test]
     fn test_extract_typed_graphql_resolves_imported_fragment_from_importable_block_path() {
         let fragment_source = r#"
@@ -2486,7 +3870,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources_from_documents(vec![
+        let result = extract_documents_with_global_graphql(vec![
             create_source_block(
                 "/project/UserCard.svelte.instance.ts",
                 "/project/UserCard.svelte",
@@ -2502,6 +3886,184 @@ mod tests {
         assert_not_contains!(query, "...UserCardFragment");
     }
 
+    #[test]
+    fn test_extract_from_component_files() {
+        let component_source = r#"<template>
+  <UserCard :user="user" />
+</template>
+
+<script setup lang="ts">
+const query = graphql(`
+  query GetUser {
+    user {
+      ...UserCard
+    }
+  }
+`);
+</script>
+"#;
+
+        let fragment_source = r#"<script lang="ts">
+  export const UserCardFragment = graphql.fragment('UserCard', 'User', {
+    select: () => ({ name: true }),
+  });
+</script>
+
+<p>{name}</p>
+"#;
+
+        let result = extract_documents_with_global_graphql(vec![
+            create_source_with_path("/project/UserPage.vue", component_source),
+            create_source_with_path("/project/UserCard.svelte", fragment_source),
+        ]);
+
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 2);
+        let query = result
+            .sources
+            .iter()
+            .find(|source| source.code.contains("query GetUser"))
+            .unwrap();
+        assert_eq!(query.file_path, "/project/UserPage.vue");
+        assert_eq!((query.start_line, query.start_column), (6, 24));
+        let fragment = result
+            .sources
+            .iter()
+            .find(|source| source.code.contains("fragment UserCard"))
+            .unwrap();
+        assert_eq!(fragment.file_path, "/project/UserCard.svelte.instance.ts");
+    }
+
+    #[test]
+    fn test_extract_from_vue_graphql_blocks() {
+        let component_source = r#"<template>
+  <p>{{ user.name }}</p>
+</template>
+
+<graphql>
+#import "./UserFields.graphql"
+
+query GetUser {
+  user { ...UserFields }
+}
+</graphql>
+"#;
+
+        let result = extract_graphql_sources_from_documents(vec![
+            create_source_with_path("/project/UserPage.vue", component_source),
+            create_source_with_path("/project/UserFields.graphql", "fragment UserFields on User { name }"),
+        ]);
+
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 2);
+        let query = &result.sources[0];
+        assert_starts_with!(query.code, "\n#import \"./UserFields.graphql\"\n\nquery GetUser");
+        assert_eq!(query.file_path, "/project/UserPage.vue");
+        assert_eq!(query.importable_file_path, None);
+        assert_eq!((query.start_line, query.start_column), (5, 10));
+    }
+
+    #[test]
+    fn test_extract_graphql_comment_templates() {
+        let source = r#"
+            const UserFields = /* GraphQL */ `
+              fragment UserFields on User { name }
+            `;
+
+            const query = /* graphql */ `
+              query GetUser { user { ...UserFields } }
+            `;
+
+            const text = /* SQL */ `SELECT 1`;
+            const plain = `query NotGraphQL { user { id } }`;
+        "#;
+
+        let result = extract_graphql_sources(create_source_with_path("/project/legacy.ts", source));
+
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 2);
+        assert_contains!(result.sources[0].code, "fragment UserFields on User");
+        assert_eq!(result.sources[0].file_path, "/project/legacy.ts");
+        assert_eq!(result.sources[0].start_line, 2);
+        assert_contains!(result.sources[1].code, "query GetUser");
+        assert_eq!(result.sources[1].start_line, 6);
+    }
+
+    #[test]
+    fn test_graphql_comment_template_interpolation_error() {
+        let source = r#"
+            const UserFields = /* GraphQL */ `fragment UserFields on User { name }`;
+            const query = /* GraphQL */ `query GetUser { user { ...UserFields } } ${UserFields}`;
+        "#;
+
+        let result = extract_graphql_sources(create_source_with_path("/project/legacy.ts", source));
+
+        assert_len_eq_x!(&result.sources, 1);
+        assert_len_eq_x!(&result.errors, 1);
+        assert_eq!(
+            result.errors[0].message(),
+            "Template literal interpolation is only supported in tagged templates, e.g. graphql`...`"
+        );
+    }
+
+    #[test]
+    fn test_graphql_comment_on_graphql_template_extracts_once() {
+        let source = r#"
+            import { graphql } from '$mearie';
+
+            const UserFields = graphql(/* GraphQL */ `
+              fragment UserFields on User { name }
+            `);
+
+            const query = graphql/* GraphQL */`
+              query GetUser { user { ...UserFields } }
+              ${UserFields}
+            `;
+        "#;
+
+        let result = extract_graphql_sources(create_source_with_path("/project/legacy.ts", source));
+
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 2);
+        assert_contains!(result.sources[0].code, "fragment UserFields on User");
+        assert_contains!(result.sources[1].code, "query GetUser");
+    }
+
+    #[test]
+    fn test_extract_from_graphql_document_files() {
+        let query_source = r#"#import "./fragments/UserFields.graphql"
+
+query GetUser {
+  user {
+    ...UserFields
+  }
+}
+"#;
+
+        let fragment_source = "fragment UserFields on User {\n  name\n}\n";
+
+        let result = extract_graphql_sources_from_documents(vec![
+            create_source_with_path("/project/GetUser.graphql", query_source),
+            create_source_with_path("/project/fragments/UserFields.graphql", fragment_source),
+        ]);
+
+        assert_is_empty!(&result.errors);
+        assert_len_eq_x!(&result.sources, 2);
+        assert_eq!(result.sources[0].code, query_source);
+        assert_eq!(result.sources[0].file_path, "/project/GetUser.graphql");
+        assert_eq!(result.sources[1].code, fragment_source);
+
+        let result = extract_graphql_sources_from_documents(vec![create_source_with_path(
+            "/project/GetUser.gql",
+            &query_source.replace("fragments/", ""),
+        )]);
+
+        assert_len_eq_x!(&result.errors, 1);
+        assert_contains!(result.errors[0].message(), "#import \"./UserFields.graphql\"");
+        let location = result.errors[0].location().unwrap();
+        assert_eq!((location.line, location.column), (1, Some(1)));
+    }
+
     #[test]
     fn test_extract_typed_graphql_merges_fragment_registry_blocks_by_importable_path() {
         let fragment_block = r#"
@@ -2528,7 +4090,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources_from_documents(vec![
+        let result = extract_documents_with_global_graphql(vec![
             create_source_block(
                 "/project/UserCard.vue.script.ts",
                 "/project/UserCard.vue",
@@ -2577,7 +4139,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources_from_documents(vec![
+        let result = extract_documents_with_global_graphql(vec![
             create_source_with_path("/project/movie-card.ts", fragment_source),
             create_source_with_path("/project/fragments/index.ts", barrel_source),
             create_source_with_path("/project/query.ts", query_source),
@@ -2610,7 +4172,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources(create_source(source));
+        let result = extract_with_global_graphql(create_source(source));
 
         assert_len_eq_x!(&result.errors, 1);
         assert_contains!(
@@ -2635,7 +4197,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources(create_source(source));
+        let result = extract_with_global_graphql(create_source(source));
 
         assert_is_empty!(&result.sources);
         assert_len_eq_x!(&result.errors, 1);
@@ -2663,7 +4225,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources_from_documents(vec![
+        let result = extract_documents_with_global_graphql(vec![
             create_source_with_path("/project/movie-card.ts", fragment_source),
             create_source_with_path("/project/query.ts", query_source),
         ]);
@@ -2689,7 +4251,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources(create_source(source));
+        let result = extract_with_global_graphql(create_source(source));
 
         assert_is_empty!(&result.sources);
         assert_len_eq_x!(&result.errors, 1);
@@ -2712,7 +4274,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources(create_source(source));
+        let result = extract_with_global_graphql(create_source(source));
 
         assert_is_empty!(&result.sources);
         assert_len_eq_x!(&result.errors, 1);
@@ -2735,7 +4297,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources(create_source(source));
+        let result = extract_with_global_graphql(create_source(source));
 
         assert_is_empty!(&result.sources);
         assert_len_eq_x!(&result.errors, 1);
@@ -2758,7 +4320,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources(create_source(source));
+        let result = extract_with_global_graphql(create_source(source));
 
         assert_is_empty!(&result.sources);
         assert_len_eq_x!(&result.errors, 1);
@@ -2781,7 +4343,7 @@ mod tests {
             });
         "#;
 
-        let result = extract_graphql_sources(create_source(source));
+        let result = extract_with_global_graphql(create_source(source));
 
         assert_is_empty!(&result.sources);
         assert_len_eq_x!(&result.errors, 1);
@@ -2820,7 +4382,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 2);
         assert_is_empty!(&result.errors);
     }
@@ -2848,7 +4410,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
         assert_contains!(&result.sources[0].code, "GetUserWithPosts");
@@ -2871,7 +4433,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
         assert_contains!(&result.sources[0].code, "@include");
@@ -2890,7 +4452,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
         assert_contains!(&result.sources[0].code, "admin:");
@@ -2912,7 +4474,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
         assert_contains!(&result.sources[0].code, "... on User");
@@ -2932,7 +4494,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
         assert_contains!(&result.sources[0].code, "...UserFields");
@@ -2944,7 +4506,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
 
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
@@ -2961,7 +4523,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 1);
         assert_is_empty!(&result.errors);
         assert_gt!(result.sources[0].start_line, 0);
@@ -3005,7 +4567,7 @@ mod tests {
 
         let source = create_source(source);
 
-        let result = extract_graphql_sources(source);
+        let result = extract_with_global_graphql(source);
         assert_len_eq_x!(&result.sources, 2usize);
         assert_len_eq_x!(&result.errors, 1usize);
         assert_contains!(&result.sources[0].code, "GetUser");
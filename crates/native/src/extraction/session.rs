@@ -0,0 +1,344 @@
+use super::{
+    ExtractResult, ExtractionConfig, TypedGraphqlCrossFileFragmentResolver, TypedGraphqlFileFragmentRegistry,
+    TypedGraphqlResolvedFragmentBindings, blocks, collect_typed_graphql_fragment_registry,
+    extract_graphql_sources_with_fragment_bindings, registry_file_path,
+};
+use crate::error::MearieError;
+use crate::source::SourceBuf;
+use rayon::prelude::*;
+use rustc_hash::FxHasher;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+
+/// Extracts GraphQL sources from the same set of documents repeatedly, such as in
+/// watch mode, reusing the work done for documents that did not change.
+///
+/// Documents are cached by the hash of their content. Bindings are only resolved
+/// again for files whose registry changed and the files importing them, and sources
+/// are only extracted again from blocks whose code or resolved bindings changed.
+/// Documents are parsed and extracted in parallel.
+pub struct ExtractionSession {
+    config: ExtractionConfig,
+    documents: HashMap<String, CachedDocument>,
+    registries: HashMap<String, TypedGraphqlFileFragmentRegistry>,
+    dependencies: HashMap<String, Vec<String>>,
+    bindings: HashMap<String, TypedGraphqlResolvedFragmentBindings>,
+}
+
+struct CachedDocument {
+    hash: u64,
+    blocks: Vec<CachedBlock>,
+}
+
+struct CachedBlock {
+    source: SourceBuf,
+    registry_path: String,
+    registry: Result<TypedGraphqlFileFragmentRegistry, Vec<MearieError>>,
+    /// The bindings the block was last extracted with, and the result.
+    extracted: Option<(TypedGraphqlResolvedFragmentBindings, ExtractResult)>,
+}
+
+impl ExtractionSession {
+    pub fn new(config: ExtractionConfig) -> Self {
+        Self {
+            config,
+            documents: HashMap::new(),
+            registries: HashMap::new(),
+            dependencies: HashMap::new(),
+            bindings: HashMap::new(),
+        }
+    }
+
+    /// Extracts GraphQL sources from `documents`. Documents that were passed to an
+    /// earlier call but are missing from `documents` are removed from the session.
+    pub fn extract(&mut self, documents: Vec<SourceBuf>) -> ExtractResult {
+        let mut changed_paths = HashSet::new();
+        let mut previous_documents = std::mem::take(&mut self.documents);
+        let mut order = Vec::with_capacity(documents.len());
+        let mut changed_documents = Vec::new();
+
+        for document in documents {
+            let key = document.file_path.clone();
+            let hash = content_hash(&document);
+            order.push(key.clone());
+
+            match previous_documents.remove(&key) {
+                Some(cached) if cached.hash == hash => {
+                    self.documents.insert(key, cached);
+                }
+                previous => {
+                    let previous_blocks = previous.into_iter().flat_map(|previous| previous.blocks);
+                    changed_paths.extend(previous_blocks.map(|block| block.registry_path));
+                    changed_documents.push((key, hash, document));
+                }
+            }
+        }
+
+        let config = &self.config;
+        let parsed: Vec<_> = changed_documents
+            .into_par_iter()
+            .map(|(key, hash, document)| {
+                let blocks: Vec<CachedBlock> = blocks::split_source_blocks(document)
+                    .into_iter()
+                    .map(|source| CachedBlock {
+                        registry_path: registry_file_path(&source),
+                        registry: collect_typed_graphql_fragment_registry(&source, config),
+                        source,
+                        extracted: None,
+                    })
+                    .collect();
+                (key, CachedDocument { hash, blocks })
+            })
+            .collect();
+
+        for (key, document) in parsed {
+            changed_paths.extend(document.blocks.iter().map(|block| block.registry_path.clone()));
+            self.documents.insert(key, document);
+        }
+
+        for removed in previous_documents.into_values() {
+            changed_paths.extend(removed.blocks.into_iter().map(|block| block.registry_path));
+        }
+
+        let dirty_paths = self.update_registries(changed_paths, &order);
+        self.update_bindings(dirty_paths);
+
+        let errors: Vec<MearieError> = order
+            .iter()
+            .filter_map(|key| self.documents.get(key))
+            .flat_map(|document| &document.blocks)
+            .filter_map(|block| block.registry.as_ref().err())
+            .flatten()
+            .cloned()
+            .collect();
+        if !errors.is_empty() {
+            return ExtractResult {
+                sources: Vec::new(),
+                errors,
+            };
+        }
+
+        let (config, bindings) = (&self.config, &self.bindings);
+        self.documents
+            .par_iter_mut()
+            .flat_map_iter(|(_, document)| &mut document.blocks)
+            .for_each(|block| {
+                let block_bindings = bindings.get(&block.registry_path).cloned().unwrap_or_default();
+                if !matches!(&block.extracted, Some((previous, _)) if *previous == block_bindings) {
+                    let extracted =
+                        extract_graphql_sources_with_fragment_bindings(&block.source, config, block_bindings.clone());
+                    block.extracted = Some((block_bindings, extracted));
+                }
+            });
+
+        let mut result = ExtractResult {
+            sources: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        for block in order
+            .iter()
+            .filter_map(|key| self.documents.get(key))
+            .flat_map(|document| &document.blocks)
+        {
+            if let Some((_, extracted)) = &block.extracted {
+                result.sources.extend(extracted.sources.iter().cloned());
+                result.errors.extend(extracted.errors.iter().cloned());
+            }
+        }
+
+        result
+    }
+
+    /// Rebuilds the registries of `changed_paths` from their blocks, and returns the
+    /// files whose bindings may resolve differently as a result.
+    fn update_registries(&mut self, changed_paths: HashSet<String>, order: &[String]) -> HashSet<String> {
+        let mut path_blocks: HashMap<&str, Vec<&TypedGraphqlFileFragmentRegistry>> = HashMap::new();
+        for block in order
+            .iter()
+            .filter_map(|key| self.documents.get(key))
+            .flat_map(|document| &document.blocks)
+        {
+            if changed_paths.contains(&block.registry_path)
+                && let Ok(registry) = &block.registry
+            {
+                path_blocks.entry(&block.registry_path).or_default().push(registry);
+            }
+        }
+
+        let mut dirty_paths = HashSet::new();
+        for path in &changed_paths {
+            let registry = path_blocks.get(path.as_str()).map(|registries| {
+                let mut merged = TypedGraphqlFileFragmentRegistry::default();
+                for registry in registries {
+                    merged.merge((*registry).clone());
+                }
+                merged
+            });
+
+            if registry.as_ref() != self.registries.get(path) {
+                dirty_paths.insert(path.clone());
+                match registry {
+                    Some(registry) => self.registries.insert(path.clone(), registry),
+                    None => self.registries.remove(path),
+                };
+            }
+        }
+
+        // Adding or removing a file can change what other files' imports resolve to,
+        // so dependencies are resolved again for every file.
+        let resolver = TypedGraphqlCrossFileFragmentResolver::new(&self.registries, &self.config);
+        let dependencies: HashMap<String, Vec<String>> = self
+            .registries
+            .keys()
+            .map(|path| (path.clone(), resolver.resolve_dependencies(path)))
+            .collect();
+
+        for (path, file_dependencies) in &dependencies {
+            if self.dependencies.get(path) != Some(file_dependencies) {
+                dirty_paths.insert(path.clone());
+            }
+        }
+        self.dependencies = dependencies;
+
+        let mut importers: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (path, file_dependencies) in &self.dependencies {
+            for dependency in file_dependencies {
+                importers.entry(dependency).or_default().push(path);
+            }
+        }
+
+        let mut queue: VecDeque<String> = dirty_paths.iter().cloned().collect();
+        while let Some(path) = queue.pop_front() {
+            for importer in importers.get(path.as_str()).into_iter().flatten() {
+                if dirty_paths.insert(importer.to_string()) {
+                    queue.push_back(importer.to_string());
+                }
+            }
+        }
+
+        dirty_paths
+    }
+
+    fn update_bindings(&mut self, dirty_paths: HashSet<String>) {
+        let resolver = TypedGraphqlCrossFileFragmentResolver::new(&self.registries, &self.config);
+
+        self.bindings.retain(|path, _| self.registries.contains_key(path));
+        let stale_paths: Vec<&String> = self
+            .registries
+            .keys()
+            .filter(|path| dirty_paths.contains(*path) || !self.bindings.contains_key(*path))
+            .collect();
+        let resolved: Vec<_> = stale_paths
+            .into_par_iter()
+            .map(|path| (path.clone(), resolver.resolve_file_bindings(path)))
+            .collect();
+        self.bindings.extend(resolved);
+    }
+}
+
+/// Hashes everything about a document that extraction depends on.
+fn content_hash(source: &SourceBuf) -> u64 {
+    let mut hasher = FxHasher::default();
+    source.code.hash(&mut hasher);
+    source.importable_file_path.hash(&mut hasher);
+    source.start_line.hash(&mut hasher);
+    source.start_column.hash(&mut hasher);
+    source.offset.hash(&mut hasher);
+    source.mappings.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assertables::*;
+
+    fn create_source(file_path: &str, code: &str) -> SourceBuf {
+        SourceBuf {
+            code: code.to_string(),
+            file_path: file_path.to_string(),
+            importable_file_path: None,
+            start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
+        }
+    }
+
+    const FRAGMENT: &str = r#"
+        import { graphql } from '$mearie';
+
+        export const UserCard = graphql.fragment('UserCard', 'User', {
+            select: () => ({ name: true }),
+        });
+    "#;
+
+    const QUERY: &str = r#"
+        import { graphql } from '$mearie';
+        import { UserCard } from './UserCard';
+
+        const query = graphql.query('GetUser', {
+            select: () => ({ user: { $: [UserCard] } }),
+        });
+    "#;
+
+    #[test]
+    fn test_session_reuses_unchanged_documents() {
+        let mut session = ExtractionSession::new(ExtractionConfig::default());
+        let documents = vec![
+            create_source("/project/UserCard.ts", FRAGMENT),
+            create_source("/project/query.ts", QUERY),
+        ];
+
+        let first = session.extract(documents.clone());
+        assert_is_empty!(&first.errors);
+        assert_len_eq_x!(&first.sources, 2);
+
+        session.documents.get_mut("/project/query.ts").unwrap().blocks[0].extracted = None;
+        session.documents.get_mut("/project/UserCard.ts").unwrap().blocks[0]
+            .extracted
+            .as_mut()
+            .unwrap()
+            .1
+            .sources
+            .clear();
+
+        let second = session.extract(documents);
+        assert_is_empty!(&second.errors);
+        assert_len_eq_x!(&second.sources, 1);
+        assert_contains!(second.sources[0].code, "query GetUser");
+    }
+
+    #[test]
+    fn test_session_resolves_importers_of_changed_files_again() {
+        let mut session = ExtractionSession::new(ExtractionConfig::default());
+        session.extract(vec![
+            create_source("/project/UserCard.ts", FRAGMENT),
+            create_source("/project/query.ts", QUERY),
+        ]);
+
+        let renamed = FRAGMENT.replace("'UserCard'", "'UserProfile'");
+        let result = session.extract(vec![
+            create_source("/project/UserCard.ts", &renamed),
+            create_source("/project/query.ts", QUERY),
+        ]);
+
+        assert_is_empty!(&result.errors);
+        let query = result
+            .sources
+            .iter()
+            .find(|source| source.code.contains("query GetUser"))
+            .unwrap();
+        assert_contains!(query.code, "...UserProfile");
+
+        let result = session.extract(vec![create_source("/project/query.ts", QUERY)]);
+
+        assert_is_empty!(&result.sources);
+        assert_len_eq_x!(&result.errors, 1);
+        assert_contains!(
+            result.errors[0].message(),
+            "Unable to resolve typed GraphQL fragment identifier"
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use oxc_span::Span;
+use std::fmt;
+use std::ops::Range;
+
+/// Generated GraphQL text that remembers which host code each piece was lowered from.
+///
+/// Mapped ranges may nest. The innermost range containing a position decides where
+/// that position came from.
+#[derive(Debug, Default, Clone)]
+pub(super) struct MappedText {
+    text: String,
+    mappings: Vec<(Range<usize>, Span)>,
+}
+
+impl MappedText {
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    pub(super) fn into_string(self) -> String {
+        self.text
+    }
+
+    pub(super) fn mappings(&self) -> &[(Range<usize>, Span)] {
+        &self.mappings
+    }
+
+    pub(super) fn push(&mut self, ch: char) {
+        self.text.push(ch);
+    }
+
+    pub(super) fn push_str(&mut self, text: &str) {
+        self.text.push_str(text);
+    }
+
+    /// Appends `text` generated from the host code at `span`.
+    pub(super) fn push_mapped(&mut self, text: &str, span: Span) {
+        let start = self.len();
+        self.push_str(text);
+        self.map_from(start, span);
+    }
+
+    /// Maps everything written since `start` to the host code at `span`.
+    pub(super) fn map_from(&mut self, start: usize, span: Span) {
+        if start < self.len() {
+            self.mappings.push((start..self.len(), span));
+        }
+    }
+
+    /// Appends another text along with its mappings.
+    pub(super) fn append(&mut self, other: &MappedText) {
+        let offset = self.len();
+        self.text.push_str(&other.text);
+        self.mappings.extend(
+            other
+                .mappings
+                .iter()
+                .map(|(range, span)| (range.start + offset..range.end + offset, *span)),
+        );
+    }
+
+    /// Appends `items` separated by `separator`.
+    pub(super) fn append_joined(&mut self, items: &[MappedText], separator: &str) {
+        for (index, item) in items.iter().enumerate() {
+            if index > 0 {
+                self.push_str(separator);
+            }
+            self.append(item);
+        }
+    }
+}
+
+impl From<String> for MappedText {
+    fn from(text: String) -> Self {
+        Self {
+            text,
+            mappings: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Write for MappedText {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_shifts_mappings() {
+        let mut value = MappedText::new();
+        value.push_mapped("1", Span::new(20, 21));
+
+        let mut out = MappedText::new();
+        out.push_str("id: ");
+        out.append(&value);
+
+        assert_eq!(out.text, "id: 1");
+        assert_eq!(out.mappings(), &[(4..5, Span::new(20, 21))]);
+    }
+
+    #[test]
+    fn test_map_from_skips_empty_ranges() {
+        let mut out = MappedText::new();
+        out.push_str("field");
+        out.map_from(5, Span::new(0, 1));
+        out.map_from(0, Span::new(2, 3));
+
+        assert_eq!(out.mappings(), &[(0..5, Span::new(2, 3))]);
+    }
+}
@@ -1,9 +1,11 @@
 use crate::arena::Arena;
-use crate::extraction::{extract_graphql_sources, extract_graphql_sources_from_documents};
+use crate::extraction::{
+    ExtractionConfig, ExtractionSession, PathAlias, WorkspacePackage, extract_graphql_sources_with_config,
+};
 use crate::pipeline::{Pipeline, PipelineConfig};
 use crate::source::{Source, SourceBuf};
 use napi_derive::napi;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::Serialize;
 use std::collections::HashMap;
 
@@ -23,14 +25,79 @@ pub struct GenerateCodeResult {
     pub errors: serde_json::Value,
 }
 
+#[napi(object)]
+pub struct ExtractGraphQLSourcesConfig {
+    pub graphql_modules: Option<Vec<String>>,
+    pub global_graphql: Option<bool>,
+    pub base_url: Option<String>,
+    pub paths: Option<HashMap<String, Vec<String>>>,
+    pub packages: Option<Vec<WorkspacePackageConfig>>,
+}
+
+#[napi(object)]
+pub struct WorkspacePackageConfig {
+    pub name: String,
+    pub root: String,
+    pub exports: Option<HashMap<String, String>>,
+}
+
 #[napi(object)]
 pub struct GenerateCodeConfig {
     pub scalars: Option<HashMap<String, String>>,
+    pub key_fields: Option<HashMap<String, Vec<String>>>,
+    pub document_files: Option<Vec<String>>,
+}
+
+fn extraction_config(config: Option<ExtractGraphQLSourcesConfig>) -> ExtractionConfig {
+    let Some(config) = config else {
+        return ExtractionConfig::default();
+    };
+
+    let mut extraction_config = ExtractionConfig::new();
+
+    if let Some(graphql_modules) = config.graphql_modules {
+        extraction_config = extraction_config.with_graphql_modules(graphql_modules);
+    }
+
+    if let Some(global_graphql) = config.global_graphql {
+        extraction_config = extraction_config.with_global_graphql(global_graphql);
+    }
+
+    if let Some(base_url) = config.base_url {
+        extraction_config = extraction_config.with_base_url(base_url);
+    }
+
+    if let Some(paths) = config.paths {
+        extraction_config = extraction_config.with_paths(
+            paths
+                .into_iter()
+                .map(|(pattern, targets)| PathAlias { pattern, targets })
+                .collect(),
+        );
+    }
+
+    if let Some(packages) = config.packages {
+        extraction_config = extraction_config.with_packages(
+            packages
+                .into_iter()
+                .map(|package| WorkspacePackage {
+                    name: package.name,
+                    root: package.root,
+                    exports: package.exports.unwrap_or_default().into_iter().collect(),
+                })
+                .collect(),
+        );
+    }
+
+    extraction_config
 }
 
 #[napi(js_name = "extractGraphQLSources")]
-pub fn napi_extract_graphql_sources(source: SourceBuf) -> ExtractGraphQLSourcesResult {
-    let result = extract_graphql_sources(source);
+pub fn napi_extract_graphql_sources(
+    source: SourceBuf,
+    config: Option<ExtractGraphQLSourcesConfig>,
+) -> ExtractGraphQLSourcesResult {
+    let result = extract_graphql_sources_with_config(vec![source], &extraction_config(config));
     ExtractGraphQLSourcesResult {
         sources: result.sources,
         errors: serde_json::to_value(&result.errors).unwrap_or(serde_json::Value::Array(vec![])),
@@ -38,28 +105,71 @@ pub fn napi_extract_graphql_sources(source: SourceBuf) -> ExtractGraphQLSourcesR
 }
 
 #[napi(js_name = "extractGraphQLSourcesFromDocuments")]
-pub fn napi_extract_graphql_sources_from_documents(sources: Vec<SourceBuf>) -> ExtractGraphQLSourcesResult {
-    let result = extract_graphql_sources_from_documents(sources);
+pub fn napi_extract_graphql_sources_from_documents(
+    sources: Vec<SourceBuf>,
+    config: Option<ExtractGraphQLSourcesConfig>,
+) -> ExtractGraphQLSourcesResult {
+    let result = extract_graphql_sources_with_config(sources, &extraction_config(config));
     ExtractGraphQLSourcesResult {
         sources: result.sources,
         errors: serde_json::to_value(&result.errors).unwrap_or(serde_json::Value::Array(vec![])),
     }
 }
 
+/// Extracts GraphQL sources from the documents of a project across repeated calls,
+/// reusing the results for documents whose content did not change.
+#[napi(js_name = "ExtractionSession")]
+pub struct NapiExtractionSession {
+    session: ExtractionSession,
+}
+
+#[napi]
+impl NapiExtractionSession {
+    #[napi(constructor)]
+    pub fn new(config: Option<ExtractGraphQLSourcesConfig>) -> Self {
+        Self {
+            session: ExtractionSession::new(extraction_config(config)),
+        }
+    }
+
+    /// Extracts GraphQL sources from all documents of the project. Documents missing
+    /// since the previous call are forgotten.
+    #[napi]
+    pub fn extract(&mut self, sources: Vec<SourceBuf>) -> ExtractGraphQLSourcesResult {
+        let result = self.session.extract(sources);
+        ExtractGraphQLSourcesResult {
+            sources: result.sources,
+            errors: serde_json::to_value(&result.errors).unwrap_or(serde_json::Value::Array(vec![])),
+        }
+    }
+}
+
+/// Schemas whose file path ends with `.json` are loaded as introspection results;
+/// all other schemas are parsed as SDL.
 #[napi(js_name = "generateCode")]
 pub fn napi_generate_code(
     schemas: Vec<SourceBuf>,
     documents: Vec<SourceBuf>,
     config: Option<GenerateCodeConfig>,
 ) -> GenerateCodeResult {
-    let schemas = schemas.iter().map(|source| source.into()).collect::<Vec<Source>>();
+    let (introspections, schemas): (Vec<&SourceBuf>, Vec<&SourceBuf>) =
+        schemas.iter().partition(|source| source.file_path.ends_with(".json"));
+    let introspections = introspections
+        .into_iter()
+        .map(|source| source.into())
+        .collect::<Vec<Source>>();
+    let schemas = schemas.into_iter().map(|source| source.into()).collect::<Vec<Source>>();
     let documents = documents.iter().map(|source| source.into()).collect::<Vec<Source>>();
 
     let pipeline_config = config
-        .and_then(|c| c.scalars)
-        .map(|scalars| {
-            let scalar_map: FxHashMap<String, String> = scalars.into_iter().collect();
-            PipelineConfig::new().with_scalar_map(scalar_map)
+        .map(|c| {
+            let scalar_map: FxHashMap<String, String> = c.scalars.unwrap_or_default().into_iter().collect();
+            let key_fields: FxHashMap<String, Vec<String>> = c.key_fields.unwrap_or_default().into_iter().collect();
+            let document_files: FxHashSet<String> = c.document_files.unwrap_or_default().into_iter().collect();
+            PipelineConfig::new()
+                .with_scalar_map(scalar_map)
+                .with_key_fields(key_fields)
+                .with_document_files(document_files)
         })
         .unwrap_or_default();
 
@@ -71,6 +181,10 @@ pub fn napi_generate_code(
         pipeline_builder = pipeline_builder.with_schema(schema);
     }
 
+    for introspection in introspections {
+        pipeline_builder = pipeline_builder.with_introspection(introspection);
+    }
+
     for document in documents {
         pipeline_builder = pipeline_builder.with_document(document);
     }
@@ -1,20 +1,66 @@
+use crate::error::location::Span;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Name<'a>(&'a str);
+/// A GraphQL name along with its span in the source.
+///
+/// Names compare and hash by their text only, so the same name parsed at different
+/// places is equal. Names that do not come from a source have an empty span.
+#[derive(Clone, Copy)]
+pub struct Name<'a> {
+    value: &'a str,
+    span: Span,
+}
 
 impl<'a> Name<'a> {
     /// Creates a new Name from a string slice.
     #[inline]
     pub const fn new(value: &'a str) -> Self {
-        Self(value)
+        Self {
+            value,
+            span: Span::empty(),
+        }
+    }
+
+    /// Creates a new Name located at the given span.
+    #[inline]
+    pub const fn with_span(value: &'a str, span: Span) -> Self {
+        Self { value, span }
     }
 
     /// Returns the underlying string slice.
     #[inline]
     pub const fn as_str(self) -> &'a str {
-        self.0
+        self.value
+    }
+
+    /// Returns the span of the name in the source.
+    #[inline]
+    pub const fn span(self) -> Span {
+        self.span
+    }
+}
+
+impl fmt::Debug for Name<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Name").field(&self.value).finish()
+    }
+}
+
+impl PartialEq for Name<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for Name<'_> {}
+
+impl Hash for Name<'_> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
     }
 }
 
@@ -30,27 +76,27 @@ impl<'a> Deref for Name<'a> {
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        self.0
+        self.value
     }
 }
 
 impl<'a> fmt::Display for Name<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(self.0)
+        f.write_str(self.value)
     }
 }
 
 impl<'a> PartialEq<str> for Name<'a> {
     #[inline]
     fn eq(&self, other: &str) -> bool {
-        self.0 == other
+        self.value == other
     }
 }
 
 impl<'a> PartialEq<&str> for Name<'a> {
     #[inline]
     fn eq(&self, other: &&str) -> bool {
-        self.0 == *other
+        self.value == *other
     }
 }
 
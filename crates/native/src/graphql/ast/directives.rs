@@ -1,14 +1,13 @@
 use super::types::{ArgumentName, DirectiveName};
 use super::values::Value;
 use crate::error::location::Span;
-use bumpalo::collections::Vec;
 
 /// Represents a GraphQL directive with its name and arguments.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Directive<'a> {
     pub span: Span,
     pub name: DirectiveName<'a>,
-    pub arguments: Vec<'a, Argument<'a>>,
+    pub arguments: &'a [Argument<'a>],
 }
 
 impl<'a> Directive<'a> {
@@ -55,3 +54,32 @@ pub enum DirectiveLocation {
     InputObject,
     InputFieldDefinition,
 }
+
+impl DirectiveLocation {
+    /// Parses a directive location from its GraphQL name (e.g. `FIELD_DEFINITION`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        use DirectiveLocation::*;
+        match name {
+            "QUERY" => Some(Query),
+            "MUTATION" => Some(Mutation),
+            "SUBSCRIPTION" => Some(Subscription),
+            "FIELD" => Some(Field),
+            "FRAGMENT_DEFINITION" => Some(FragmentDefinition),
+            "FRAGMENT_SPREAD" => Some(FragmentSpread),
+            "INLINE_FRAGMENT" => Some(InlineFragment),
+            "VARIABLE_DEFINITION" => Some(VariableDefinition),
+            "SCHEMA" => Some(Schema),
+            "SCALAR" => Some(Scalar),
+            "OBJECT" => Some(Object),
+            "FIELD_DEFINITION" => Some(FieldDefinition),
+            "ARGUMENT_DEFINITION" => Some(ArgumentDefinition),
+            "INTERFACE" => Some(Interface),
+            "UNION" => Some(Union),
+            "ENUM" => Some(Enum),
+            "ENUM_VALUE" => Some(EnumValue),
+            "INPUT_OBJECT" => Some(InputObject),
+            "INPUT_FIELD_DEFINITION" => Some(InputFieldDefinition),
+            _ => None,
+        }
+    }
+}
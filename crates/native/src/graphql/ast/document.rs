@@ -5,13 +5,12 @@ use super::operations::OperationDefinition;
 use super::types::{Type, TypeName};
 use super::values::Value;
 use crate::source::Source;
-use bumpalo::collections::Vec;
 
 /// Represents a complete GraphQL document containing definitions.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Document<'a> {
     pub source: &'a Source<'a>,
-    pub definitions: Vec<'a, Definition<'a>>,
+    pub definitions: &'a [Definition<'a>],
 }
 
 impl<'a> Document<'a> {
@@ -90,7 +89,7 @@ pub enum TypeSystemExtension<'a> {
 #[derive(Debug, Clone, PartialEq)]
 pub struct SchemaDefinition<'a> {
     pub description: Option<Description<'a>>,
-    pub directives: Vec<'a, Directive<'a>>,
+    pub directives: &'a [Directive<'a>],
     pub query: Option<TypeName<'a>>,
     pub mutation: Option<TypeName<'a>>,
     pub subscription: Option<TypeName<'a>>,
@@ -99,7 +98,7 @@ pub struct SchemaDefinition<'a> {
 /// Represents a schema extension.
 #[derive(Debug, Clone, PartialEq)]
 pub struct SchemaExtension<'a> {
-    pub directives: Vec<'a, Directive<'a>>,
+    pub directives: &'a [Directive<'a>],
     pub query: Option<TypeName<'a>>,
     pub mutation: Option<TypeName<'a>>,
     pub subscription: Option<TypeName<'a>>,
@@ -116,6 +115,20 @@ pub enum TypeDefinition<'a> {
     InputObject(InputObjectTypeDefinition<'a>),
 }
 
+impl<'a> TypeDefinition<'a> {
+    /// Returns the name of the defined type.
+    pub fn name(&self) -> TypeName<'a> {
+        match self {
+            Self::Scalar(scalar) => scalar.name,
+            Self::Object(obj) => obj.name,
+            Self::Interface(iface) => iface.name,
+            Self::Union(union) => union.name,
+            Self::Enum(enum_type) => enum_type.name,
+            Self::InputObject(input) => input.name,
+        }
+    }
+}
+
 /// Represents a type extension in a GraphQL schema.
 #[derive(Debug, Clone, PartialEq)]
 pub enum TypeExtension<'a> {
@@ -132,14 +145,14 @@ pub enum TypeExtension<'a> {
 pub struct ScalarTypeDefinition<'a> {
     pub description: Option<Description<'a>>,
     pub name: TypeName<'a>,
-    pub directives: Vec<'a, Directive<'a>>,
+    pub directives: &'a [Directive<'a>],
 }
 
 /// Represents a scalar type extension.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ScalarTypeExtension<'a> {
     pub name: TypeName<'a>,
-    pub directives: Vec<'a, Directive<'a>>,
+    pub directives: &'a [Directive<'a>],
 }
 
 /// Represents an object type definition.
@@ -147,18 +160,18 @@ pub struct ScalarTypeExtension<'a> {
 pub struct ObjectTypeDefinition<'a> {
     pub description: Option<Description<'a>>,
     pub name: TypeName<'a>,
-    pub implements: Vec<'a, TypeName<'a>>,
-    pub directives: Vec<'a, Directive<'a>>,
-    pub fields: Vec<'a, FieldDefinition<'a>>,
+    pub implements: &'a [TypeName<'a>],
+    pub directives: &'a [Directive<'a>],
+    pub fields: &'a [FieldDefinition<'a>],
 }
 
 /// Represents an object type extension.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ObjectTypeExtension<'a> {
     pub name: TypeName<'a>,
-    pub implements: Vec<'a, TypeName<'a>>,
-    pub directives: Vec<'a, Directive<'a>>,
-    pub fields: Vec<'a, FieldDefinition<'a>>,
+    pub implements: &'a [TypeName<'a>],
+    pub directives: &'a [Directive<'a>],
+    pub fields: &'a [FieldDefinition<'a>],
 }
 
 /// Represents a field definition in an object or interface type.
@@ -166,9 +179,9 @@ pub struct ObjectTypeExtension<'a> {
 pub struct FieldDefinition<'a> {
     pub description: Option<Description<'a>>,
     pub name: super::types::FieldName<'a>,
-    pub arguments: Vec<'a, InputValueDefinition<'a>>,
+    pub arguments: &'a [InputValueDefinition<'a>],
     pub typ: Type<'a>,
-    pub directives: Vec<'a, Directive<'a>>,
+    pub directives: &'a [Directive<'a>],
 }
 
 /// Represents an input value definition (argument or input field).
@@ -178,7 +191,7 @@ pub struct InputValueDefinition<'a> {
     pub name: super::types::ArgumentName<'a>,
     pub typ: Type<'a>,
     pub default_value: Option<Value<'a>>,
-    pub directives: Vec<'a, Directive<'a>>,
+    pub directives: &'a [Directive<'a>],
 }
 
 /// Represents an interface type definition.
@@ -186,18 +199,18 @@ pub struct InputValueDefinition<'a> {
 pub struct InterfaceTypeDefinition<'a> {
     pub description: Option<Description<'a>>,
     pub name: TypeName<'a>,
-    pub implements: Vec<'a, TypeName<'a>>,
-    pub directives: Vec<'a, Directive<'a>>,
-    pub fields: Vec<'a, FieldDefinition<'a>>,
+    pub implements: &'a [TypeName<'a>],
+    pub directives: &'a [Directive<'a>],
+    pub fields: &'a [FieldDefinition<'a>],
 }
 
 /// Represents an interface type extension.
 #[derive(Debug, Clone, PartialEq)]
 pub struct InterfaceTypeExtension<'a> {
     pub name: TypeName<'a>,
-    pub implements: Vec<'a, TypeName<'a>>,
-    pub directives: Vec<'a, Directive<'a>>,
-    pub fields: Vec<'a, FieldDefinition<'a>>,
+    pub implements: &'a [TypeName<'a>],
+    pub directives: &'a [Directive<'a>],
+    pub fields: &'a [FieldDefinition<'a>],
 }
 
 /// Represents a union type definition.
@@ -205,16 +218,16 @@ pub struct InterfaceTypeExtension<'a> {
 pub struct UnionTypeDefinition<'a> {
     pub description: Option<Description<'a>>,
     pub name: TypeName<'a>,
-    pub directives: Vec<'a, Directive<'a>>,
-    pub members: Vec<'a, TypeName<'a>>,
+    pub directives: &'a [Directive<'a>],
+    pub members: &'a [TypeName<'a>],
 }
 
 /// Represents a union type extension.
 #[derive(Debug, Clone, PartialEq)]
 pub struct UnionTypeExtension<'a> {
     pub name: TypeName<'a>,
-    pub directives: Vec<'a, Directive<'a>>,
-    pub members: Vec<'a, TypeName<'a>>,
+    pub directives: &'a [Directive<'a>],
+    pub members: &'a [TypeName<'a>],
 }
 
 /// Represents an enum type definition.
@@ -222,16 +235,16 @@ pub struct UnionTypeExtension<'a> {
 pub struct EnumTypeDefinition<'a> {
     pub description: Option<Description<'a>>,
     pub name: TypeName<'a>,
-    pub directives: Vec<'a, Directive<'a>>,
-    pub values: Vec<'a, EnumValueDefinition<'a>>,
+    pub directives: &'a [Directive<'a>],
+    pub values: &'a [EnumValueDefinition<'a>],
 }
 
 /// Represents an enum type extension.
 #[derive(Debug, Clone, PartialEq)]
 pub struct EnumTypeExtension<'a> {
     pub name: TypeName<'a>,
-    pub directives: Vec<'a, Directive<'a>>,
-    pub values: Vec<'a, EnumValueDefinition<'a>>,
+    pub directives: &'a [Directive<'a>],
+    pub values: &'a [EnumValueDefinition<'a>],
 }
 
 /// Represents an enum value definition.
@@ -239,7 +252,7 @@ pub struct EnumTypeExtension<'a> {
 pub struct EnumValueDefinition<'a> {
     pub description: Option<Description<'a>>,
     pub value: Name<'a>,
-    pub directives: Vec<'a, Directive<'a>>,
+    pub directives: &'a [Directive<'a>],
 }
 
 /// Represents an input object type definition.
@@ -247,16 +260,16 @@ pub struct EnumValueDefinition<'a> {
 pub struct InputObjectTypeDefinition<'a> {
     pub description: Option<Description<'a>>,
     pub name: TypeName<'a>,
-    pub directives: Vec<'a, Directive<'a>>,
-    pub fields: Vec<'a, InputValueDefinition<'a>>,
+    pub directives: &'a [Directive<'a>],
+    pub fields: &'a [InputValueDefinition<'a>],
 }
 
 /// Represents an input object type extension.
 #[derive(Debug, Clone, PartialEq)]
 pub struct InputObjectTypeExtension<'a> {
     pub name: TypeName<'a>,
-    pub directives: Vec<'a, Directive<'a>>,
-    pub fields: Vec<'a, InputValueDefinition<'a>>,
+    pub directives: &'a [Directive<'a>],
+    pub fields: &'a [InputValueDefinition<'a>],
 }
 
 /// Represents a directive definition in a GraphQL schema.
@@ -264,7 +277,7 @@ pub struct InputObjectTypeExtension<'a> {
 pub struct DirectiveDefinition<'a> {
     pub description: Option<Description<'a>>,
     pub name: super::types::DirectiveName<'a>,
-    pub arguments: Vec<'a, InputValueDefinition<'a>>,
+    pub arguments: &'a [InputValueDefinition<'a>],
     pub repeatable: bool,
-    pub locations: Vec<'a, DirectiveLocation>,
+    pub locations: &'a [DirectiveLocation],
 }
@@ -2,16 +2,15 @@ use super::directives::Directive;
 use super::operations::{SelectionSet, VariableDefinition};
 use super::types::{FragmentName, TypeName};
 use crate::error::location::Span;
-use bumpalo::collections::Vec;
 
 /// Represents a GraphQL fragment definition.
 #[derive(Debug, Clone, PartialEq)]
 pub struct FragmentDefinition<'a> {
     pub span: Span,
     pub name: FragmentName<'a>,
-    pub variable_definitions: Vec<'a, VariableDefinition<'a>>,
+    pub variable_definitions: &'a [VariableDefinition<'a>],
     pub type_condition: TypeName<'a>,
-    pub directives: Vec<'a, Directive<'a>>,
+    pub directives: &'a [Directive<'a>],
     pub selection_set: SelectionSet<'a>,
 }
 
@@ -3,7 +3,6 @@ use super::directives::{Argument, Directive};
 use super::types::{FieldName, Type, VariableName};
 use super::values::Value;
 use crate::error::location::Span;
-use bumpalo::collections::Vec;
 
 /// Represents a GraphQL operation definition (query, mutation, or subscription).
 #[derive(Debug, Clone, PartialEq)]
@@ -11,8 +10,8 @@ pub struct OperationDefinition<'a> {
     pub span: Span,
     pub operation_type: OperationType,
     pub name: Option<Name<'a>>,
-    pub variable_definitions: Vec<'a, VariableDefinition<'a>>,
-    pub directives: Vec<'a, Directive<'a>>,
+    pub variable_definitions: &'a [VariableDefinition<'a>],
+    pub directives: &'a [Directive<'a>],
     pub selection_set: SelectionSet<'a>,
 }
 
@@ -49,13 +48,13 @@ pub struct VariableDefinition<'a> {
     pub variable: VariableName<'a>,
     pub typ: Type<'a>,
     pub default_value: Option<Value<'a>>,
-    pub directives: Vec<'a, Directive<'a>>,
+    pub directives: &'a [Directive<'a>],
 }
 
 /// Represents a set of GraphQL selections.
 #[derive(Debug, Clone, PartialEq)]
 pub struct SelectionSet<'a> {
-    pub selections: Vec<'a, Selection<'a>>,
+    pub selections: &'a [Selection<'a>],
 }
 
 impl<'a> SelectionSet<'a> {
@@ -96,8 +95,8 @@ pub struct Field<'a> {
     pub span: Span,
     pub alias: Option<FieldName<'a>>,
     pub name: FieldName<'a>,
-    pub arguments: Vec<'a, Argument<'a>>,
-    pub directives: Vec<'a, Directive<'a>>,
+    pub arguments: &'a [Argument<'a>],
+    pub directives: &'a [Directive<'a>],
     pub selection_set: SelectionSet<'a>,
 }
 
@@ -114,8 +113,8 @@ impl<'a> Field<'a> {
 pub struct FragmentSpread<'a> {
     pub span: Span,
     pub fragment_name: super::types::FragmentName<'a>,
-    pub arguments: Vec<'a, Argument<'a>>,
-    pub directives: Vec<'a, Directive<'a>>,
+    pub arguments: &'a [Argument<'a>],
+    pub directives: &'a [Directive<'a>],
 }
 
 /// Represents an inline fragment in a GraphQL selection.
@@ -123,6 +122,6 @@ pub struct FragmentSpread<'a> {
 pub struct InlineFragment<'a> {
     pub span: Span,
     pub type_condition: Option<super::types::TypeName<'a>>,
-    pub directives: Vec<'a, Directive<'a>>,
+    pub directives: &'a [Directive<'a>],
     pub selection_set: SelectionSet<'a>,
 }
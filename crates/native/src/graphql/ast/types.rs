@@ -1,4 +1,5 @@
 use super::common::Name;
+use crate::error::location::Span;
 use std::fmt;
 use std::ops::Deref;
 
@@ -46,6 +47,12 @@ macro_rules! impl_name_newtype {
             pub fn as_str(self) -> &'a str {
                 self.0.as_str()
             }
+
+            /// Returns the span of the name in the source.
+            #[inline]
+            pub const fn span(self) -> Span {
+                self.0.span()
+            }
         }
 
         impl<'a> From<Name<'a>> for $type<'a> {
@@ -134,6 +141,19 @@ impl<'a> Type<'a> {
     }
 }
 
+impl<'a> fmt::Display for Type<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Named(n) => write!(f, "{}", n.name),
+            Type::List(t) => write!(f, "[{}]", t),
+            Type::NonNull(nn) => match nn {
+                NonNullType::Named(n) => write!(f, "{}!", n.name),
+                NonNullType::List(t) => write!(f, "[{}]!", t),
+            },
+        }
+    }
+}
+
 /// Represents a named GraphQL type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct NamedType<'a> {
@@ -154,3 +174,14 @@ pub enum NonNullType<'a> {
     Named(NamedType<'a>),
     List(&'a Type<'a>),
 }
+
+impl<'a> NonNullType<'a> {
+    /// Returns the wrapped type without the non-null modifier.
+    #[inline]
+    pub fn to_nullable(&self) -> Type<'a> {
+        match self {
+            NonNullType::Named(n) => Type::Named(*n),
+            NonNullType::List(t) => Type::List(t),
+        }
+    }
+}
@@ -1,10 +1,42 @@
 use super::common::Name;
 use super::types::ArgumentName;
-use bumpalo::collections::Vec;
+use crate::error::location::Span;
 
-/// Represents a GraphQL value, which can be a variable reference, literal, or composite structure.
+/// Represents a GraphQL value along with its span in the source.
+///
+/// Values compare by their kind only, so equal literals written at different places
+/// are equal. Values that do not come from a source have an empty span.
+#[derive(Debug, Clone)]
+pub struct Value<'a> {
+    pub span: Span,
+    pub kind: ValueKind<'a>,
+}
+
+impl<'a> Value<'a> {
+    /// Creates a new Value located at the given span.
+    #[inline]
+    pub const fn new(kind: ValueKind<'a>, span: Span) -> Self {
+        Self { span, kind }
+    }
+}
+
+impl<'a> From<ValueKind<'a>> for Value<'a> {
+    #[inline]
+    fn from(kind: ValueKind<'a>) -> Self {
+        Self::new(kind, Span::empty())
+    }
+}
+
+impl PartialEq for Value<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+/// The kind of a GraphQL value, which can be a variable reference, literal, or composite structure.
 #[derive(Debug, Clone, PartialEq)]
-pub enum Value<'a> {
+pub enum ValueKind<'a> {
     Variable(Name<'a>),
     Int(&'a str),
     Float(&'a str),
@@ -12,13 +44,14 @@ pub enum Value<'a> {
     Boolean(bool),
     Null,
     Enum(Name<'a>),
-    List(Vec<'a, Value<'a>>),
-    Object(Vec<'a, ObjectField<'a>>),
+    List(&'a [Value<'a>]),
+    Object(&'a [ObjectField<'a>]),
 }
 
 /// Represents a field in a GraphQL object value.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ObjectField<'a> {
+    pub span: Span,
     pub name: ArgumentName<'a>,
     pub value: Value<'a>,
 }
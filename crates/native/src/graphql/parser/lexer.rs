@@ -1,8 +1,37 @@
+use crate::error::location::Span;
 use logos::{Lexer, Logos};
 use serde::Serialize;
 use std::fmt;
 
-fn parse_block_string<'a>(lex: &mut Lexer<'a, Token<'a>>) -> Option<&'a str> {
+/// Reason the lexer rejected a piece of input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LexErrorKind {
+    #[default]
+    UnexpectedCharacter,
+    UnterminatedString,
+    UnterminatedBlockString,
+    InvalidEscapeSequence,
+    InvalidNumber,
+}
+
+/// Error produced by the lexer.
+///
+/// The span points at the offending input: the opening quotes of an unterminated
+/// string, the escape sequence itself, or the rejected characters otherwise.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LexError {
+    pub kind: LexErrorKind,
+    pub span: Span,
+}
+
+impl LexError {
+    #[inline]
+    pub const fn new(kind: LexErrorKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+}
+
+fn parse_block_string<'a>(lex: &mut Lexer<'a, Token<'a>>) -> Result<&'a str, LexError> {
     let start = lex.span().start;
     let after_opening = lex.span().end;
     let remainder = lex.remainder();
@@ -22,7 +51,7 @@ fn parse_block_string<'a>(lex: &mut Lexer<'a, Token<'a>>) -> Option<&'a str> {
             if consecutive_quotes >= 3 {
                 let end = after_opening + i;
                 lex.bump(i);
-                return Some(&lex.source()[start..end]);
+                return Ok(&lex.source()[start..end]);
             }
             consecutive_quotes = 0;
             i += 1;
@@ -32,13 +61,152 @@ fn parse_block_string<'a>(lex: &mut Lexer<'a, Token<'a>>) -> Option<&'a str> {
     if consecutive_quotes >= 3 {
         let end = after_opening + i;
         lex.bump(i);
-        return Some(&lex.source()[start..end]);
+        return Ok(&lex.source()[start..end]);
+    }
+
+    lex.bump(bytes.len());
+    Err(LexError::new(
+        LexErrorKind::UnterminatedBlockString,
+        Span::new(start, after_opening),
+    ))
+}
+
+/// Classifies input that matched no token and consumes the whole offending lexeme, so
+/// that lexing resumes at a sensible boundary.
+fn lex_error<'a>(lex: &mut Lexer<'a, Token<'a>>) -> LexError {
+    let start = lex.span().start;
+    let consumed = lex.span().end - start;
+    let source = lex.source();
+    let rest = &source[start..];
+
+    let (error, len) = if rest.starts_with('"') {
+        string_error(start, rest)
+    } else if rest.starts_with('-')
+        || (rest.starts_with('.') && source[..start].ends_with(|c: char| c.is_ascii_digit()))
+    {
+        let number_start = if rest.starts_with('.') {
+            source[..start]
+                .trim_end_matches(|c: char| c.is_ascii_digit() || matches!(c, '-' | '.'))
+                .len()
+        } else {
+            start
+        };
+        let len = 1 + rest[1..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '+' | '-')))
+            .unwrap_or(rest.len() - 1);
+        (
+            LexError::new(LexErrorKind::InvalidNumber, Span::new(number_start, start + len)),
+            len,
+        )
+    } else {
+        let len = rest.chars().next().map_or(consumed, char::len_utf8);
+        (
+            LexError::new(LexErrorKind::UnexpectedCharacter, Span::new(start, start + len)),
+            len,
+        )
+    };
+
+    if len > consumed {
+        lex.bump(len - consumed);
+    }
+    error
+}
+
+/// Scans a string literal that failed to lex, returning the error and the length of
+/// input to skip. Strings cannot span lines, so an unterminated one ends at the newline.
+fn string_error(start: usize, rest: &str) -> (LexError, usize) {
+    let mut invalid_escape = None;
+    let mut chars = rest.char_indices().skip(1);
+
+    while let Some((i, ch)) = chars.next() {
+        match ch {
+            '"' => {
+                let span = invalid_escape.unwrap_or(Span::new(start, start + i + 1));
+                return (LexError::new(LexErrorKind::InvalidEscapeSequence, span), i + 1);
+            }
+            '\n' | '\r' => break,
+            '\\' => {
+                let escape = &rest[i + 1..];
+                let len = match escape.chars().next() {
+                    Some('"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't') => 1,
+                    Some('u')
+                        if escape
+                            .get(1..5)
+                            .is_some_and(|hex| hex.chars().all(|c| c.is_ascii_hexdigit())) =>
+                    {
+                        5
+                    }
+                    Some(c) => {
+                        let len = if c == 'u' {
+                            1 + escape[1..].chars().take(4).take_while(char::is_ascii_hexdigit).count()
+                        } else {
+                            c.len_utf8()
+                        };
+                        invalid_escape.get_or_insert(Span::new(start + i, start + i + 1 + len));
+                        len
+                    }
+                    None => 0,
+                };
+                for _ in 0..escape[..len].chars().count() {
+                    chars.next();
+                }
+            }
+            _ => {}
+        }
     }
 
-    None
+    let len = rest.find(['\n', '\r']).unwrap_or(rest.len());
+    (
+        LexError::new(LexErrorKind::UnterminatedString, Span::new(start, start + 1)),
+        len,
+    )
+}
+
+/// Kind of input the lexer skips between tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriviaKind {
+    /// Spaces and tabs.
+    Whitespace,
+    /// A line terminator: `\n`, `\r\n` or `\r`.
+    Newline,
+    /// A comma, which GraphQL treats like whitespace.
+    Comma,
+    /// A `#` comment, without the line terminator ending it.
+    Comment,
+    /// Input the lexer rejected.
+    Skipped,
+}
+
+/// Splits `text`, the input between two tokens starting at `offset` in the source,
+/// into trivia. Every byte of `text` belongs to exactly one piece.
+pub fn lex_trivia(text: &str, offset: usize) -> impl Iterator<Item = (TriviaKind, Span)> + '_ {
+    let mut position = 0;
+
+    std::iter::from_fn(move || {
+        let rest = &text[position..];
+        let (kind, len) = match rest.chars().next()? {
+            ' ' | '\t' => (
+                TriviaKind::Whitespace,
+                rest.find(|c| c != ' ' && c != '\t').unwrap_or(rest.len()),
+            ),
+            '\r' if rest.starts_with("\r\n") => (TriviaKind::Newline, 2),
+            '\n' | '\r' => (TriviaKind::Newline, 1),
+            ',' => (TriviaKind::Comma, 1),
+            '#' => (TriviaKind::Comment, rest.find(['\n', '\r']).unwrap_or(rest.len())),
+            _ => (
+                TriviaKind::Skipped,
+                rest.find([' ', '\t', '\n', '\r', ',', '#']).unwrap_or(rest.len()),
+            ),
+        };
+
+        let span = Span::new(offset + position, offset + position + len);
+        position += len;
+        Some((kind, span))
+    })
 }
 
 #[derive(Logos, Debug, Clone, Copy, PartialEq, Eq)]
+#[logos(error(LexError, lex_error))]
 #[logos(skip r"[ \t\n\r,]+")]
 #[logos(skip(r"#[^\n]*", allow_greedy = true))]
 pub enum Token<'a> {
@@ -151,11 +319,15 @@ pub enum Token<'a> {
     IntValue(&'a str),
 
     // spell-checker:disable-next-line
-    #[regex(r#""([^"\\]|\\["\\\/bfnrt]|\\u[0-9a-fA-F]{4})*""#, |lex| lex.slice())]
+    #[regex(r#""([^"\\\n\r]|\\["\\\/bfnrt]|\\u[0-9a-fA-F]{4})*""#, |lex| lex.slice())]
     StringValue(&'a str),
 
     #[token(r#"""""#, parse_block_string)]
     BlockStringValue(&'a str),
+
+    /// The end of input. Never produced by the lexer; the parser substitutes it once
+    /// the input runs out.
+    Eof,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
@@ -241,8 +413,34 @@ impl<'a> Token<'a> {
             Self::IntValue(_) => TokenKind::IntValue,
             Self::FloatValue(_) => TokenKind::FloatValue,
             Self::StringValue(_) | Self::BlockStringValue(_) => TokenKind::StringValue,
+            Self::Eof => TokenKind::Eof,
         }
     }
+
+    /// Returns true for names and for keywords that may be used where a name is expected.
+    #[inline]
+    pub const fn is_name_or_keyword(&self) -> bool {
+        matches!(
+            self,
+            Self::Name(_)
+                | Self::Type
+                | Self::Interface
+                | Self::Union
+                | Self::Enum
+                | Self::Input
+                | Self::Scalar
+                | Self::Schema
+                | Self::Query
+                | Self::Mutation
+                | Self::Subscription
+                | Self::Fragment
+                | Self::On
+                | Self::Extend
+                | Self::Implements
+                | Self::Directive
+                | Self::Repeatable
+        )
+    }
 }
 
 impl fmt::Display for TokenKind {
@@ -331,6 +529,7 @@ impl fmt::Display for Token<'_> {
             Token::FloatValue(val) => write!(f, "{}", val),
             Token::StringValue(val) => write!(f, "\"{}\"", val),
             Token::BlockStringValue(val) => write!(f, "\"\"\"{}\"\"\"", val),
+            Token::Eof => write!(f, "end of input"),
         }
     }
 }
@@ -629,4 +828,89 @@ line 2
         assert_eq!(tokens[4].0, Token::Input);
         assert_eq!(tokens[5].0, Token::Scalar);
     }
+
+    fn lex_results(input: &str) -> Vec<Result<Token<'_>, LexError>> {
+        Token::lexer(input).collect()
+    }
+
+    #[test]
+    fn test_unexpected_character_error() {
+        let results = lex_results("a ^ b");
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[1],
+            Err(LexError::new(LexErrorKind::UnexpectedCharacter, Span::new(2, 3)))
+        );
+        assert_eq!(results[2], Ok(Token::Name("b")));
+    }
+
+    #[test]
+    fn test_unterminated_string_error_stops_at_line_end() {
+        let results = lex_results("\"abc\nname");
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0],
+            Err(LexError::new(LexErrorKind::UnterminatedString, Span::new(0, 1)))
+        );
+        assert_eq!(results[1], Ok(Token::Name("name")));
+    }
+
+    #[test]
+    fn test_unterminated_block_string_error() {
+        let results = lex_results("a \"\"\"abc\n def");
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[1],
+            Err(LexError::new(LexErrorKind::UnterminatedBlockString, Span::new(2, 5)))
+        );
+    }
+
+    #[test]
+    fn test_invalid_escape_sequence_error() {
+        let results = lex_results(r#""a\qb\u12" c"#);
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0],
+            Err(LexError::new(LexErrorKind::InvalidEscapeSequence, Span::new(2, 4)))
+        );
+        assert_eq!(results[1], Ok(Token::Name("c")));
+    }
+
+    #[test]
+    fn test_invalid_escape_sequence_before_multibyte_character() {
+        let results = lex_results("{ a(b: \"\\u123é\") }");
+        assert_eq!(
+            results[5],
+            Err(LexError::new(LexErrorKind::InvalidEscapeSequence, Span::new(8, 13)))
+        );
+        assert_eq!(results[6], Ok(Token::ParenClose));
+    }
+
+    #[test]
+    fn test_invalid_number_error() {
+        let results = lex_results("1.e5 x");
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0], Ok(Token::IntValue("1")));
+        assert_eq!(
+            results[1],
+            Err(LexError::new(LexErrorKind::InvalidNumber, Span::new(0, 4)))
+        );
+        assert_eq!(results[2], Ok(Token::Name("x")));
+    }
+
+    #[test]
+    fn test_lex_trivia() {
+        let trivia: Vec<_> = lex_trivia(" \t,# note\r\n\n?", 10).collect();
+        assert_eq!(
+            trivia,
+            vec![
+                (TriviaKind::Whitespace, Span::new(10, 12)),
+                (TriviaKind::Comma, Span::new(12, 13)),
+                (TriviaKind::Comment, Span::new(13, 19)),
+                (TriviaKind::Newline, Span::new(19, 21)),
+                (TriviaKind::Newline, Span::new(21, 22)),
+                (TriviaKind::Skipped, Span::new(22, 23)),
+            ]
+        );
+    }
 }
@@ -0,0 +1,246 @@
+use super::lexer::{Token, TriviaKind, lex_trivia};
+use crate::arena::Arena;
+use crate::error::location::Span;
+use crate::graphql::ast::Document;
+use logos::Logos;
+use std::fmt;
+
+/// A piece of input between tokens: whitespace, a line terminator, a comma or a comment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trivia<'a> {
+    pub kind: TriviaKind,
+    pub text: &'a str,
+    pub span: Span,
+}
+
+/// A token with the trivia attached to it.
+///
+/// A token owns the trivia after it on the same line as its trailing trivia, and the
+/// rest of the trivia since the previous token as its leading trivia. A comment on a
+/// line of its own thus leads the next token, while a comment after a field on the
+/// same line trails the field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyntaxToken<'a> {
+    pub token: Token<'a>,
+    pub text: &'a str,
+    pub span: Span,
+    pub leading_trivia: &'a [Trivia<'a>],
+    pub trailing_trivia: &'a [Trivia<'a>],
+}
+
+/// A parsed document together with every token and piece of trivia of its source.
+///
+/// Writing out the tokens with their trivia, followed by `end_trivia`, reproduces the
+/// source exactly. The trivia of an AST node is the leading trivia of its first token
+/// and the trailing trivia of its last token, which are looked up by the span of the
+/// node, e.g. the comments above a field with `leading_trivia(field.span)`.
+#[derive(Debug, Clone, Copy)]
+pub struct LosslessDocument<'a> {
+    pub document: &'a Document<'a>,
+    pub tokens: &'a [SyntaxToken<'a>],
+    /// Trivia after the last token that is not on its line, such as a final newline.
+    pub end_trivia: &'a [Trivia<'a>],
+}
+
+impl<'a> LosslessDocument<'a> {
+    pub(super) fn new(arena: &'a Arena, document: &'a Document<'a>) -> Self {
+        let code = document.source.code;
+        let allocator = arena.allocator();
+        let trivia_between = |start: usize, end: usize| -> Vec<Trivia<'a>> {
+            lex_trivia(&code[start..end], start)
+                .map(|(kind, span)| Trivia {
+                    kind,
+                    text: &code[span.start..span.end],
+                    span,
+                })
+                .collect()
+        };
+
+        // Input the lexer rejects is left out here and ends up in the trivia around it.
+        let significant: Vec<(Token<'a>, Span)> = Token::lexer(code)
+            .spanned()
+            .filter_map(|(token, range)| Some((token.ok()?, Span::from(range))))
+            .collect();
+
+        let mut tokens = Vec::with_capacity(significant.len());
+        let mut leading = trivia_between(0, significant.first().map_or(code.len(), |(_, span)| span.start));
+
+        for (index, &(token, span)) in significant.iter().enumerate() {
+            let next_start = significant.get(index + 1).map_or(code.len(), |(_, span)| span.start);
+            let mut trailing = trivia_between(span.end, next_start);
+            let line_end = trailing
+                .iter()
+                .position(|trivia| trivia.kind == TriviaKind::Newline)
+                .unwrap_or(trailing.len());
+            let next_leading = trailing.split_off(line_end);
+
+            tokens.push(SyntaxToken {
+                token,
+                text: &code[span.start..span.end],
+                span,
+                leading_trivia: allocator.alloc_slice_copy(&leading),
+                trailing_trivia: allocator.alloc_slice_copy(&trailing),
+            });
+            leading = next_leading;
+        }
+
+        Self {
+            document,
+            tokens: allocator.alloc_slice_copy(&tokens),
+            end_trivia: allocator.alloc_slice_copy(&leading),
+        }
+    }
+
+    /// Returns the tokens inside `span`.
+    pub fn tokens_in(&self, span: Span) -> &'a [SyntaxToken<'a>] {
+        let start = self.tokens.partition_point(|token| token.span.start < span.start);
+        let end = self.tokens.partition_point(|token| token.span.end <= span.end);
+        &self.tokens[start..end.max(start)]
+    }
+
+    /// Returns the first token of the node at `span`.
+    pub fn first_token(&self, span: Span) -> Option<&'a SyntaxToken<'a>> {
+        self.tokens_in(span).first()
+    }
+
+    /// Returns the last token of the node at `span`.
+    pub fn last_token(&self, span: Span) -> Option<&'a SyntaxToken<'a>> {
+        self.tokens_in(span).last()
+    }
+
+    /// Returns the trivia before the node at `span`.
+    pub fn leading_trivia(&self, span: Span) -> &'a [Trivia<'a>] {
+        self.first_token(span).map_or(&[], |token| token.leading_trivia)
+    }
+
+    /// Returns the trivia after the node at `span`, up to the end of its last line.
+    pub fn trailing_trivia(&self, span: Span) -> &'a [Trivia<'a>] {
+        self.last_token(span).map_or(&[], |token| token.trailing_trivia)
+    }
+
+    /// Returns `span` extended over the trivia of the node, which is the range of
+    /// source to move or remove together with the node.
+    pub fn full_span(&self, span: Span) -> Span {
+        let start = self
+            .leading_trivia(span)
+            .first()
+            .map_or(span.start, |trivia| trivia.span.start);
+        let end = self
+            .trailing_trivia(span)
+            .last()
+            .map_or(span.end, |trivia| trivia.span.end);
+        Span::new(start, end)
+    }
+}
+
+impl fmt::Display for LosslessDocument<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in self.tokens {
+            for trivia in token.leading_trivia {
+                f.write_str(trivia.text)?;
+            }
+            f.write_str(token.text)?;
+            for trivia in token.trailing_trivia {
+                f.write_str(trivia.text)?;
+            }
+        }
+
+        for trivia in self.end_trivia {
+            f.write_str(trivia.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphql::ast::*;
+    use crate::graphql::parser::Parser;
+    use crate::source::Source;
+
+    fn parse_lossless<'a>(arena: &'a Arena, code: &'a str) -> LosslessDocument<'a> {
+        let source = arena.alloc(Source::ephemeral(code));
+        Parser::new(arena).with_source(source).parse_lossless().unwrap()
+    }
+
+    fn fields<'a>(document: &'a Document<'a>) -> Vec<&'a Field<'a>> {
+        let Some(operation) = document.operations().next() else {
+            return Vec::new();
+        };
+        operation
+            .selection_set
+            .selections
+            .iter()
+            .filter_map(|selection| match selection {
+                Selection::Field(field) => Some(field),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_round_trips_source() {
+        let arena = Arena::new();
+        let code = "# Fetches a user\r\nquery GetUser($id: ID!, $full: Boolean = false) {\n  user(id: $id) { id,, name } # trailing\n\n  # between fields\n  ... on Query { __typename }\n}\n\n# end\n";
+
+        let lossless = parse_lossless(&arena, code);
+
+        assert_eq!(lossless.to_string(), code);
+        assert_eq!(lossless.end_trivia.last().map(|trivia| trivia.text), Some("\n"));
+    }
+
+    #[test]
+    fn test_attaches_comments_to_fields() {
+        let arena = Arena::new();
+        let code = "query {\n  # the id\n  id # primary key\n  name,\n}";
+
+        let lossless = parse_lossless(&arena, code);
+        let fields = fields(lossless.document);
+
+        let leading: Vec<_> = lossless.leading_trivia(fields[0].span).iter().map(|t| t.kind).collect();
+        assert_eq!(
+            leading,
+            vec![
+                TriviaKind::Newline,
+                TriviaKind::Whitespace,
+                TriviaKind::Comment,
+                TriviaKind::Newline,
+                TriviaKind::Whitespace,
+            ]
+        );
+        assert_eq!(lossless.leading_trivia(fields[0].span)[2].text, "# the id");
+        let trailing = lossless.trailing_trivia(fields[0].span);
+        assert_eq!(trailing.last().map(|trivia| trivia.text), Some("# primary key"));
+
+        let trailing: Vec<_> = lossless
+            .trailing_trivia(fields[1].span)
+            .iter()
+            .map(|t| t.kind)
+            .collect();
+        assert_eq!(trailing, vec![TriviaKind::Comma]);
+
+        let full_span = lossless.full_span(fields[0].span);
+        assert_eq!(
+            &code[full_span.start..full_span.end],
+            "\n  # the id\n  id # primary key"
+        );
+    }
+
+    #[test]
+    fn test_tokens_in_node() {
+        let arena = Arena::new();
+        let code = "{ user(id: 1) { name } }";
+
+        let lossless = parse_lossless(&arena, code);
+        let user = fields(lossless.document)[0];
+
+        let texts: Vec<_> = lossless.tokens_in(user.span).iter().map(|token| token.text).collect();
+        assert_eq!(texts, vec!["user", "(", "id", ":", "1", ")", "{", "name", "}"]);
+        assert_eq!(
+            lossless.first_token(user.span).map(|token| token.token),
+            Some(Token::Name("user"))
+        );
+    }
+}
@@ -1,7 +1,9 @@
 pub mod lexer;
+pub mod lossless;
 #[allow(clippy::module_inception)]
 pub mod parser;
 pub mod state;
 
+pub use lossless::{LosslessDocument, SyntaxToken, Trivia};
 pub use parser::Parser;
 pub use state::{Complete, Failed, Parsing, Ready, Uninitialized};
@@ -1,8 +1,9 @@
-use super::lexer::Token;
+use super::lexer::{LexError, LexErrorKind, Token};
+use super::lossless::LosslessDocument;
 use super::state::*;
 use crate::arena::Arena;
-use crate::error::MearieError;
 use crate::error::location::*;
+use crate::error::{ErrorCode, MearieError};
 use crate::graphql::ast::*;
 use crate::source::Source;
 use bumpalo::{Bump, collections::Vec};
@@ -14,7 +15,12 @@ pub struct Parser<'a, State = Uninitialized> {
     arena: Option<&'a Arena>,
     source: Option<&'a Source<'a>>,
     lexer: Option<Lexer<'a, Token<'a>>>,
-    peek: Option<Token<'a>>,
+    peek: Option<Option<Token<'a>>>,
+    current: Option<Token<'a>>,
+    prev_end: usize,
+    depth: usize,
+    invalid: Option<MearieError>,
+    recovered: Option<std::vec::Vec<MearieError>>,
     _state: PhantomData<State>,
 }
 
@@ -26,6 +32,11 @@ impl<'a> Parser<'a, Uninitialized> {
             source: None,
             lexer: None,
             peek: None,
+            current: None,
+            prev_end: 0,
+            depth: 0,
+            invalid: None,
+            recovered: None,
             _state: PhantomData,
         }
     }
@@ -39,6 +50,11 @@ impl<'a> Parser<'a, Ready> {
             source: Some(source),
             lexer: Some(Token::lexer(source.code)),
             peek: None,
+            current: None,
+            prev_end: 0,
+            depth: 0,
+            invalid: None,
+            recovered: None,
             _state: PhantomData,
         }
     }
@@ -47,9 +63,87 @@ impl<'a> Parser<'a, Ready> {
 impl<'a> Parser<'a, Parsing> {
     /// Parses the source into a Document, performing strict validation.
     pub fn parse(mut self) -> Result<&'a Document<'a>, MearieError> {
-        let doc = self.parse_document()?;
+        let doc = self.parse_document();
+        if let Some(error) = self.invalid.take() {
+            return Err(error);
+        }
+        let arena = self.arena.unwrap();
+        Ok(arena.alloc(doc?))
+    }
+
+    /// Parses the source into a Document like [`parse`](Self::parse), keeping the
+    /// comments, whitespace and commas the AST leaves out attached to its tokens.
+    pub fn parse_lossless(self) -> Result<LosslessDocument<'a>, MearieError> {
         let arena = self.arena.unwrap();
-        Ok(arena.alloc(doc))
+        let document = self.parse()?;
+        Ok(LosslessDocument::new(arena, document))
+    }
+
+    /// Parses the source into a Document, recovering from syntax errors instead of
+    /// stopping at the first one.
+    ///
+    /// An error inside a selection set skips ahead to the next selection at the same
+    /// depth, so the enclosing definition is kept with the broken selection left out.
+    /// Any other error drops the definition and skips ahead to the next definition
+    /// keyword at the top level. The returned document holds every definition that
+    /// could be parsed, alongside all syntax errors in source order.
+    pub fn parse_recovering(mut self) -> (&'a Document<'a>, std::vec::Vec<MearieError>) {
+        self.recovered = Some(std::vec::Vec::new());
+
+        let source = self.source.unwrap();
+        let mut definitions = Vec::new_in(self.allocator());
+
+        while !self.at_end() {
+            let start = self.peek_offset();
+            match self.parse_definition() {
+                Ok(definition) => definitions.push(definition),
+                Err(error) => {
+                    self.recover(error);
+                    self.synchronize(start, 0, |token| {
+                        matches!(
+                            token,
+                            Token::Query
+                                | Token::Mutation
+                                | Token::Subscription
+                                | Token::Fragment
+                                | Token::Type
+                                | Token::Interface
+                                | Token::Union
+                                | Token::Enum
+                                | Token::Input
+                                | Token::Scalar
+                                | Token::Schema
+                                | Token::Directive
+                                | Token::Extend
+                        )
+                    });
+                }
+            }
+        }
+
+        let arena = self.arena.unwrap();
+        let errors = self.recovered.take().unwrap_or_default();
+        (
+            arena.alloc(Document {
+                source,
+                definitions: definitions.into_bump_slice(),
+            }),
+            errors,
+        )
+    }
+
+    /// Parses the source as a single constant value literal, such as an
+    /// introspection `defaultValue`.
+    pub fn parse_const_value(mut self) -> Result<Value<'a>, MearieError> {
+        let value = self.parse_value();
+        let at_end = self.at_end();
+        if let Some(error) = self.invalid.take() {
+            return Err(error);
+        }
+        if !at_end {
+            return Err(self.error("end of value"));
+        }
+        value
     }
 
     fn allocator(&self) -> &'a Bump {
@@ -58,21 +152,81 @@ impl<'a> Parser<'a, Parsing> {
 
     fn peek_token(&mut self) -> Token<'a> {
         if self.peek.is_none() {
-            self.peek = self.lexer.as_mut().unwrap().next().and_then(|r| r.ok());
+            self.peek = Some(self.lex());
         }
-        self.peek.unwrap_or(Token::BraceClose)
+        self.peek.flatten().unwrap_or(Token::Eof)
     }
 
     fn next_token(&mut self) -> Token<'a> {
-        if let Some(token) = self.peek.take() {
-            return token;
-        }
-        self.lexer
-            .as_mut()
-            .unwrap()
-            .next()
-            .and_then(|r| r.ok())
-            .unwrap_or(Token::BraceClose)
+        self.current = match self.peek.take() {
+            Some(token) => token,
+            None => self.lex(),
+        };
+        let token = self.current.unwrap_or(Token::Eof);
+        self.prev_end = self.span().end;
+
+        match token {
+            Token::BraceOpen | Token::ParenOpen | Token::BracketOpen => self.depth += 1,
+            Token::BraceClose | Token::ParenClose | Token::BracketClose => self.depth = self.depth.saturating_sub(1),
+            _ => {}
+        }
+
+        token
+    }
+
+    /// Returns the next valid token, or `None` at the end of input.
+    ///
+    /// Input rejected by the lexer is skipped. The first such error fails a strict
+    /// parse, while a recovering parse reports every one of them.
+    fn lex(&mut self) -> Option<Token<'a>> {
+        loop {
+            match self.lexer.as_mut().unwrap().next()? {
+                Ok(token) => return Some(token),
+                Err(error) => {
+                    let error = self.lex_error(error);
+                    if let Some(error) = self.recover(error) {
+                        self.invalid.get_or_insert(error);
+                    }
+                }
+            }
+        }
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.peek_token();
+        self.peek.flatten().is_none()
+    }
+
+    fn peek_offset(&mut self) -> usize {
+        self.peek_token();
+        self.span().start
+    }
+
+    /// Records a syntax error when recovering, or hands it back to the caller otherwise.
+    fn recover(&mut self, error: MearieError) -> Option<MearieError> {
+        match &mut self.recovered {
+            Some(errors) => {
+                errors.push(error);
+                None
+            }
+            None => Some(error),
+        }
+    }
+
+    /// Skips tokens until the parser is back at `depth` and looking at a token accepted
+    /// by `boundary`. At least one token is consumed if nothing was since `start`, so
+    /// recovery always makes progress.
+    fn synchronize(&mut self, start: usize, depth: usize, boundary: impl Fn(Token<'a>) -> bool) {
+        if !self.at_end() && self.peek_offset() == start {
+            self.next_token();
+        }
+
+        while !self.at_end() {
+            if self.depth <= depth && boundary(self.peek_token()) {
+                return;
+            }
+            self.next_token();
+        }
     }
 
     #[inline]
@@ -82,61 +236,71 @@ impl<'a> Parser<'a, Parsing> {
     }
 
     fn error(&self, expected: &'static str) -> MearieError {
-        let token = self.peek.unwrap_or(Token::BraceClose);
-        let location = Location::from_span(self.source.unwrap(), self.span());
-
-        MearieError::parse(format!(
-            "unexpected token: expected {}, found {:?}",
-            expected,
-            token.kind()
-        ))
-        .at(location)
+        let source = self.source.unwrap();
+        let token = self.peek.unwrap_or(self.current);
+
+        let (message, span) = match token {
+            Some(token) => (
+                format!("unexpected token: expected {}, found {:?}", expected, token.kind()),
+                self.span(),
+            ),
+            None => (
+                format!("unexpected end of input: expected {}", expected),
+                Span::new(source.code.len(), source.code.len()),
+            ),
+        };
+
+        MearieError::parse(message)
+            .with_code(ErrorCode::SyntaxError)
+            .at(Location::from_span(source, span))
+    }
+
+    fn lex_error(&self, error: LexError) -> MearieError {
+        let source = self.source.unwrap();
+        let location = Location::from_span(source, error.span);
+        let text = &source.code[error.span.start..error.span.end];
+
+        let message = match error.kind {
+            LexErrorKind::UnexpectedCharacter => format!("unexpected character '{}'", text),
+            LexErrorKind::UnterminatedString => format!("unterminated string starting at line {}", location.line),
+            LexErrorKind::UnterminatedBlockString => {
+                format!("unterminated block string starting at line {}", location.line)
+            }
+            LexErrorKind::InvalidEscapeSequence => format!("invalid escape sequence '{}' in string", text),
+            LexErrorKind::InvalidNumber => format!("invalid number '{}'", text),
+        };
+
+        MearieError::parse(message)
+            .with_code(ErrorCode::SyntaxError)
+            .at(location)
     }
 
     fn peek_is_name_or_keyword(&mut self) -> bool {
-        matches!(
-            self.peek_token(),
-            Token::Name(_)
-                | Token::Type
-                | Token::Interface
-                | Token::Union
-                | Token::Enum
-                | Token::Input
-                | Token::Scalar
-                | Token::Schema
-                | Token::Query
-                | Token::Mutation
-                | Token::Subscription
-                | Token::Fragment
-                | Token::On
-                | Token::Extend
-                | Token::Implements
-                | Token::Directive
-                | Token::Repeatable
-        )
+        self.peek_token().is_name_or_keyword()
     }
 
-    fn next_name_or_keyword(&mut self, error_msg: &'static str) -> Result<&'a str, MearieError> {
-        match self.next_token() {
-            Token::Name(name) => Ok(name),
-            Token::Input => Ok("input"),
-            Token::Query => Ok("query"),
-            Token::Mutation => Ok("mutation"),
-            Token::Subscription => Ok("subscription"),
-            Token::Type => Ok("type"),
-            Token::Interface => Ok("interface"),
-            Token::Union => Ok("union"),
-            Token::Enum => Ok("enum"),
-            Token::Scalar => Ok("scalar"),
-            Token::Schema => Ok("schema"),
-            Token::Fragment => Ok("fragment"),
-            Token::On => Ok("on"),
-            Token::Extend => Ok("extend"),
-            Token::Implements => Ok("implements"),
-            Token::Directive => Ok("directive"),
-            Token::Repeatable => Ok("repeatable"),
-            _ => Err(self.error(error_msg)),
-        }
+    fn next_name_or_keyword(&mut self, error_msg: &'static str) -> Result<Name<'a>, MearieError> {
+        let name = match self.next_token() {
+            Token::Name(name) => name,
+            Token::Input => "input",
+            Token::Query => "query",
+            Token::Mutation => "mutation",
+            Token::Subscription => "subscription",
+            Token::Type => "type",
+            Token::Interface => "interface",
+            Token::Union => "union",
+            Token::Enum => "enum",
+            Token::Scalar => "scalar",
+            Token::Schema => "schema",
+            Token::Fragment => "fragment",
+            Token::On => "on",
+            Token::Extend => "extend",
+            Token::Implements => "implements",
+            Token::Directive => "directive",
+            Token::Repeatable => "repeatable",
+            _ => return Err(self.error(error_msg)),
+        };
+        Ok(Name::with_span(name, self.span()))
     }
 
     fn unescape_string(&self, s: &'a str) -> Result<&'a str, MearieError> {
@@ -221,19 +385,19 @@ impl<'a> Parser<'a, Parsing> {
         }
     }
 
-    fn parse_arguments(&mut self) -> Result<Vec<'a, Argument<'a>>, MearieError> {
+    fn parse_arguments(&mut self) -> Result<&'a [Argument<'a>], MearieError> {
         let mut arguments = Vec::new_in(self.allocator());
         if matches!(self.peek_token(), Token::ParenOpen) {
             self.next_token();
             while !matches!(self.peek_token(), Token::ParenClose) {
-                let start = self.span().start;
+                let start = self.peek_offset();
                 let name = ArgumentName::from(self.next_name_or_keyword("argument name")?);
 
                 if !matches!(self.next_token(), Token::Colon) {
                     return Err(self.error(":"));
                 }
                 let value = self.parse_value()?;
-                let end = self.span().end;
+                let end = self.prev_end;
                 arguments.push(Argument {
                     span: Span::new(start, end),
                     name,
@@ -242,62 +406,68 @@ impl<'a> Parser<'a, Parsing> {
             }
             self.next_token();
         }
-        Ok(arguments)
+        Ok(arguments.into_bump_slice())
     }
 
-    fn parse_directives(&mut self) -> Result<Vec<'a, Directive<'a>>, MearieError> {
+    fn parse_directives(&mut self) -> Result<&'a [Directive<'a>], MearieError> {
         let mut directives = Vec::new_in(self.allocator());
         while matches!(self.peek_token(), Token::At) {
-            let start = self.span().start;
+            let start = self.peek_offset();
             self.next_token();
             let name = self.next_name_or_keyword("directive name")?;
             let arguments = self.parse_arguments()?;
-            let end = self.span().end;
+            let end = self.prev_end;
             directives.push(Directive {
                 span: Span::new(start, end),
                 name: DirectiveName::from(name),
                 arguments,
             });
         }
-        Ok(directives)
+        Ok(directives.into_bump_slice())
     }
 
     fn parse_value(&mut self) -> Result<Value<'a>, MearieError> {
+        let start = self.peek_offset();
+        let kind = self.parse_value_kind()?;
+        Ok(Value::new(kind, Span::new(start, self.prev_end)))
+    }
+
+    fn parse_value_kind(&mut self) -> Result<ValueKind<'a>, MearieError> {
         match self.peek_token() {
             Token::Null => {
                 self.next_token();
-                Ok(Value::Null)
+                Ok(ValueKind::Null)
             }
             Token::True => {
                 self.next_token();
-                Ok(Value::Boolean(true))
+                Ok(ValueKind::Boolean(true))
             }
             Token::False => {
                 self.next_token();
-                Ok(Value::Boolean(false))
+                Ok(ValueKind::Boolean(false))
             }
             Token::Dollar => {
                 self.next_token();
-                let name = Name::from(self.next_name_or_keyword("variable name")?);
-                Ok(Value::Variable(name))
+                let name = self.next_name_or_keyword("variable name")?;
+                Ok(ValueKind::Variable(name))
             }
             Token::IntValue(val) => {
                 self.next_token();
-                Ok(Value::Int(val))
+                Ok(ValueKind::Int(val))
             }
             Token::FloatValue(val) => {
                 self.next_token();
-                Ok(Value::Float(val))
+                Ok(ValueKind::Float(val))
             }
             Token::StringValue(val) => {
                 self.next_token();
                 let unescaped = self.unescape_string(val)?;
-                Ok(Value::String(unescaped))
+                Ok(ValueKind::String(unescaped))
             }
             Token::BlockStringValue(val) => {
                 self.next_token();
                 let unescaped = self.unescape_block_string(val)?;
-                Ok(Value::String(unescaped))
+                Ok(ValueKind::String(unescaped))
             }
             Token::BracketOpen => {
                 self.next_token();
@@ -306,26 +476,31 @@ impl<'a> Parser<'a, Parsing> {
                     values.push(self.parse_value()?);
                 }
                 self.next_token();
-                Ok(Value::List(values))
+                Ok(ValueKind::List(values.into_bump_slice()))
             }
             Token::BraceOpen => {
                 self.next_token();
                 let mut fields = Vec::new_in(self.allocator());
                 while !matches!(self.peek_token(), Token::BraceClose) {
+                    let start = self.peek_offset();
                     let name = ArgumentName::from(self.next_name_or_keyword("field name")?);
 
                     if !matches!(self.next_token(), Token::Colon) {
                         return Err(self.error(":"));
                     }
                     let value = self.parse_value()?;
-                    fields.push(ObjectField { name, value });
+                    fields.push(ObjectField {
+                        span: Span::new(start, self.prev_end),
+                        name,
+                        value,
+                    });
                 }
                 self.next_token();
-                Ok(Value::Object(fields))
+                Ok(ValueKind::Object(fields.into_bump_slice()))
             }
             _ if self.peek_is_name_or_keyword() => {
                 let name = self.next_name_or_keyword("enum value")?;
-                Ok(Value::Enum(Name::from(name)))
+                Ok(ValueKind::Enum(name))
             }
             _ => Err(self.error("value")),
         }
@@ -361,7 +536,7 @@ impl<'a> Parser<'a, Parsing> {
     }
 
     fn parse_variable_definition(&mut self) -> Result<VariableDefinition<'a>, MearieError> {
-        let start = self.span().start;
+        let start = self.peek_offset();
 
         if !matches!(self.next_token(), Token::Dollar) {
             return Err(self.error("$"));
@@ -383,7 +558,7 @@ impl<'a> Parser<'a, Parsing> {
         };
 
         let directives = self.parse_directives()?;
-        let end = self.span().end;
+        let end = self.prev_end;
 
         Ok(VariableDefinition {
             span: Span::new(start, end),
@@ -394,7 +569,7 @@ impl<'a> Parser<'a, Parsing> {
         })
     }
 
-    fn parse_variable_definitions(&mut self) -> Result<Vec<'a, VariableDefinition<'a>>, MearieError> {
+    fn parse_variable_definitions(&mut self) -> Result<&'a [VariableDefinition<'a>], MearieError> {
         let mut variable_definitions = Vec::new_in(self.allocator());
         if matches!(self.peek_token(), Token::ParenOpen) {
             self.next_token();
@@ -403,7 +578,7 @@ impl<'a> Parser<'a, Parsing> {
             }
             self.next_token();
         }
-        Ok(variable_definitions)
+        Ok(variable_definitions.into_bump_slice())
     }
 
     fn parse_selection_set(&mut self) -> Result<SelectionSet<'a>, MearieError> {
@@ -411,19 +586,52 @@ impl<'a> Parser<'a, Parsing> {
 
         if matches!(self.peek_token(), Token::BraceOpen) {
             self.next_token();
-            while !matches!(self.peek_token(), Token::BraceClose) {
-                selections.push(self.parse_selection()?);
+            let depth = self.depth;
+            loop {
+                match self.peek_token() {
+                    Token::BraceClose => break,
+                    Token::Eof => return Err(self.error("}")),
+                    _ => {}
+                }
+                let start = self.peek_offset();
+                match self.parse_selection() {
+                    Ok(selection) => selections.push(selection),
+                    Err(error) => {
+                        if let Some(error) = self.recover(error) {
+                            return Err(error);
+                        }
+                        self.synchronize(start, depth, |token| {
+                            matches!(token, Token::BraceClose | Token::Spread) || token.is_name_or_keyword()
+                        });
+                        // The error already covers the rest of the input.
+                        if self.at_end() {
+                            return Ok(SelectionSet {
+                                selections: selections.into_bump_slice(),
+                            });
+                        }
+                    }
+                }
             }
             self.next_token();
         }
 
-        Ok(SelectionSet { selections })
+        Ok(SelectionSet {
+            selections: selections.into_bump_slice(),
+        })
+    }
+
+    /// Parses the selection set an operation or fragment definition must end with.
+    fn parse_required_selection_set(&mut self) -> Result<SelectionSet<'a>, MearieError> {
+        if !matches!(self.peek_token(), Token::BraceOpen) {
+            return Err(self.error("{"));
+        }
+        self.parse_selection_set()
     }
 
     fn parse_selection(&mut self) -> Result<Selection<'a>, MearieError> {
         match self.peek_token() {
             Token::Spread => {
-                let start = self.span().start;
+                let start = self.peek_offset();
                 self.next_token();
                 if matches!(self.peek_token(), Token::On) {
                     self.next_token();
@@ -431,7 +639,7 @@ impl<'a> Parser<'a, Parsing> {
                     let type_condition = Some(TypeName::from(name));
                     let directives = self.parse_directives()?;
                     let selection_set = self.parse_selection_set()?;
-                    let end = self.span().end;
+                    let end = self.prev_end;
                     Ok(Selection::InlineFragment(InlineFragment {
                         span: Span::new(start, end),
                         type_condition,
@@ -441,7 +649,7 @@ impl<'a> Parser<'a, Parsing> {
                 } else if matches!(self.peek_token(), Token::BraceOpen | Token::At) {
                     let directives = self.parse_directives()?;
                     let selection_set = self.parse_selection_set()?;
-                    let end = self.span().end;
+                    let end = self.prev_end;
                     Ok(Selection::InlineFragment(InlineFragment {
                         span: Span::new(start, end),
                         type_condition: None,
@@ -452,7 +660,7 @@ impl<'a> Parser<'a, Parsing> {
                     let fragment_name = self.next_name_or_keyword("fragment name")?;
                     let arguments = self.parse_arguments()?;
                     let directives = self.parse_directives()?;
-                    let end = self.span().end;
+                    let end = self.prev_end;
                     Ok(Selection::FragmentSpread(FragmentSpread {
                         span: Span::new(start, end),
                         fragment_name: FragmentName::from(fragment_name),
@@ -469,7 +677,7 @@ impl<'a> Parser<'a, Parsing> {
     }
 
     fn parse_field(&mut self) -> Result<Field<'a>, MearieError> {
-        let start = self.span().start;
+        let start = self.peek_offset();
         let name_or_alias = self.next_name_or_keyword("field name")?;
 
         let (alias, name) = if matches!(self.peek_token(), Token::Colon) {
@@ -483,7 +691,7 @@ impl<'a> Parser<'a, Parsing> {
         let arguments = self.parse_arguments()?;
         let directives = self.parse_directives()?;
         let selection_set = self.parse_selection_set()?;
-        let end = self.span().end;
+        let end = self.prev_end;
 
         Ok(Field {
             span: Span::new(start, end),
@@ -496,17 +704,17 @@ impl<'a> Parser<'a, Parsing> {
     }
 
     fn parse_operation_definition(&mut self) -> Result<OperationDefinition<'a>, MearieError> {
-        let start = self.span().start;
+        let start = self.peek_offset();
         let operation_type = match self.peek_token() {
             Token::BraceOpen => {
                 let selection_set = self.parse_selection_set()?;
-                let end = self.span().end;
+                let end = self.prev_end;
                 return Ok(OperationDefinition {
                     span: Span::new(start, end),
                     operation_type: OperationType::Query,
                     name: None,
-                    variable_definitions: Vec::new_in(self.allocator()),
-                    directives: Vec::new_in(self.allocator()),
+                    variable_definitions: &[],
+                    directives: &[],
                     selection_set,
                 });
             }
@@ -526,7 +734,7 @@ impl<'a> Parser<'a, Parsing> {
         };
 
         let name = if self.peek_is_name_or_keyword() {
-            Some(Name::from(self.next_name_or_keyword("operation name")?))
+            Some(self.next_name_or_keyword("operation name")?)
         } else {
             None
         };
@@ -534,8 +742,8 @@ impl<'a> Parser<'a, Parsing> {
         let variable_definitions = self.parse_variable_definitions()?;
 
         let directives = self.parse_directives()?;
-        let selection_set = self.parse_selection_set()?;
-        let end = self.span().end;
+        let selection_set = self.parse_required_selection_set()?;
+        let end = self.prev_end;
 
         Ok(OperationDefinition {
             span: Span::new(start, end),
@@ -548,7 +756,7 @@ impl<'a> Parser<'a, Parsing> {
     }
 
     fn parse_fragment_definition(&mut self) -> Result<FragmentDefinition<'a>, MearieError> {
-        let start = self.span().start;
+        let start = self.peek_offset();
 
         if !matches!(self.next_token(), Token::Fragment) {
             return Err(self.error("fragment"));
@@ -565,8 +773,8 @@ impl<'a> Parser<'a, Parsing> {
         let type_condition = TypeName::from(self.next_name_or_keyword("type name")?);
 
         let directives = self.parse_directives()?;
-        let selection_set = self.parse_selection_set()?;
-        let end = self.span().end;
+        let selection_set = self.parse_required_selection_set()?;
+        let end = self.prev_end;
 
         Ok(FragmentDefinition {
             span: Span::new(start, end),
@@ -582,16 +790,14 @@ impl<'a> Parser<'a, Parsing> {
         let source = self.source.unwrap();
         let mut definitions = Vec::new_in(self.allocator());
 
-        loop {
-            match self.peek_token() {
-                Token::BraceClose => break,
-                _ => {
-                    definitions.push(self.parse_definition()?);
-                }
-            }
+        while !self.at_end() {
+            definitions.push(self.parse_definition()?);
         }
 
-        Ok(Document { source, definitions })
+        Ok(Document {
+            source,
+            definitions: definitions.into_bump_slice(),
+        })
     }
 
     fn parse_definition(&mut self) -> Result<Definition<'a>, MearieError> {
@@ -652,6 +858,7 @@ impl<'a> Parser<'a, Parsing> {
         }
 
         match self.peek_token() {
+            Token::Schema => Ok(TypeSystemExtension::Schema(self.parse_schema_extension()?)),
             Token::Scalar | Token::Type | Token::Interface | Token::Union | Token::Enum | Token::Input => {
                 Ok(TypeSystemExtension::Type(self.parse_type_extension()?))
             }
@@ -695,7 +902,7 @@ impl<'a> Parser<'a, Parsing> {
         Ok(FieldDefinition {
             description,
             name,
-            arguments,
+            arguments: arguments.into_bump_slice(),
             typ,
             directives,
         })
@@ -767,9 +974,9 @@ impl<'a> Parser<'a, Parsing> {
         Ok(ObjectTypeDefinition {
             description,
             name,
-            implements,
+            implements: implements.into_bump_slice(),
             directives,
-            fields,
+            fields: fields.into_bump_slice(),
         })
     }
 
@@ -810,16 +1017,16 @@ impl<'a> Parser<'a, Parsing> {
         Ok(InterfaceTypeDefinition {
             description,
             name,
-            implements,
+            implements: implements.into_bump_slice(),
             directives,
-            fields,
+            fields: fields.into_bump_slice(),
         })
     }
 
     fn parse_enum_value_definition(&mut self) -> Result<EnumValueDefinition<'a>, MearieError> {
         let description = self.parse_description()?;
 
-        let value = Name::from(self.next_name_or_keyword("enum value")?);
+        let value = self.next_name_or_keyword("enum value")?;
 
         let directives = self.parse_directives()?;
 
@@ -855,7 +1062,7 @@ impl<'a> Parser<'a, Parsing> {
             description,
             name,
             directives,
-            values,
+            values: values.into_bump_slice(),
         })
     }
 
@@ -891,7 +1098,7 @@ impl<'a> Parser<'a, Parsing> {
             description,
             name,
             directives,
-            members,
+            members: members.into_bump_slice(),
         })
     }
 
@@ -939,36 +1146,10 @@ impl<'a> Parser<'a, Parsing> {
             description,
             name,
             directives,
-            fields,
+            fields: fields.into_bump_slice(),
         })
     }
 
-    fn parse_directive_location(&self, name: &str) -> Option<DirectiveLocation> {
-        use DirectiveLocation::*;
-        match name {
-            "QUERY" => Some(Query),
-            "MUTATION" => Some(Mutation),
-            "SUBSCRIPTION" => Some(Subscription),
-            "FIELD" => Some(Field),
-            "FRAGMENT_DEFINITION" => Some(FragmentDefinition),
-            "FRAGMENT_SPREAD" => Some(FragmentSpread),
-            "INLINE_FRAGMENT" => Some(InlineFragment),
-            "VARIABLE_DEFINITION" => Some(VariableDefinition),
-            "SCHEMA" => Some(Schema),
-            "SCALAR" => Some(Scalar),
-            "OBJECT" => Some(Object),
-            "FIELD_DEFINITION" => Some(FieldDefinition),
-            "ARGUMENT_DEFINITION" => Some(ArgumentDefinition),
-            "INTERFACE" => Some(Interface),
-            "UNION" => Some(Union),
-            "ENUM" => Some(Enum),
-            "ENUM_VALUE" => Some(EnumValue),
-            "INPUT_OBJECT" => Some(InputObject),
-            "INPUT_FIELD_DEFINITION" => Some(InputFieldDefinition),
-            _ => None,
-        }
-    }
-
     fn parse_directive_definition(
         &mut self,
         description: Option<Description<'a>>,
@@ -1009,7 +1190,7 @@ impl<'a> Parser<'a, Parsing> {
         }
 
         while let Token::Name(loc_name) = self.peek_token() {
-            if let Some(location) = self.parse_directive_location(loc_name) {
+            if let Some(location) = DirectiveLocation::from_name(loc_name) {
                 self.next_token();
                 locations.push(location);
                 if matches!(self.peek_token(), Token::Pipe) {
@@ -1025,9 +1206,9 @@ impl<'a> Parser<'a, Parsing> {
         Ok(DirectiveDefinition {
             description,
             name,
-            arguments,
+            arguments: arguments.into_bump_slice(),
             repeatable,
-            locations,
+            locations: locations.into_bump_slice(),
         })
     }
 
@@ -1083,6 +1264,52 @@ impl<'a> Parser<'a, Parsing> {
         })
     }
 
+    fn parse_schema_extension(&mut self) -> Result<SchemaExtension<'a>, MearieError> {
+        if !matches!(self.next_token(), Token::Schema) {
+            return Err(self.error("schema"));
+        }
+
+        let directives = self.parse_directives()?;
+
+        let mut query = None;
+        let mut mutation = None;
+        let mut subscription = None;
+
+        if matches!(self.peek_token(), Token::BraceOpen) {
+            self.next_token();
+            while !matches!(self.peek_token(), Token::BraceClose) {
+                let op_type = match self.next_token() {
+                    Token::Name(name) => name,
+                    Token::Query => "query",
+                    Token::Mutation => "mutation",
+                    Token::Subscription => "subscription",
+                    _ => return Err(self.error("operation type")),
+                };
+
+                if !matches!(self.next_token(), Token::Colon) {
+                    return Err(self.error(":"));
+                }
+
+                let type_name = TypeName::from(self.next_name_or_keyword("type name")?);
+
+                match op_type {
+                    "query" => query = Some(type_name),
+                    "mutation" => mutation = Some(type_name),
+                    "subscription" => subscription = Some(type_name),
+                    _ => return Err(self.error("operation type")),
+                }
+            }
+            self.next_token();
+        }
+
+        Ok(SchemaExtension {
+            directives,
+            query,
+            mutation,
+            subscription,
+        })
+    }
+
     fn parse_object_type_extension(&mut self) -> Result<ObjectTypeExtension<'a>, MearieError> {
         if !matches!(self.next_token(), Token::Type) {
             return Err(self.error("type"));
@@ -1116,9 +1343,9 @@ impl<'a> Parser<'a, Parsing> {
 
         Ok(ObjectTypeExtension {
             name,
-            implements,
+            implements: implements.into_bump_slice(),
             directives,
-            fields,
+            fields: fields.into_bump_slice(),
         })
     }
 
@@ -1155,9 +1382,9 @@ impl<'a> Parser<'a, Parsing> {
 
         Ok(InterfaceTypeExtension {
             name,
-            implements,
+            implements: implements.into_bump_slice(),
             directives,
-            fields,
+            fields: fields.into_bump_slice(),
         })
     }
 
@@ -1201,7 +1428,7 @@ impl<'a> Parser<'a, Parsing> {
         Ok(UnionTypeExtension {
             name,
             directives,
-            members,
+            members: members.into_bump_slice(),
         })
     }
 
@@ -1226,7 +1453,7 @@ impl<'a> Parser<'a, Parsing> {
         Ok(EnumTypeExtension {
             name,
             directives,
-            values,
+            values: values.into_bump_slice(),
         })
     }
 
@@ -1251,7 +1478,7 @@ impl<'a> Parser<'a, Parsing> {
         Ok(InputObjectTypeExtension {
             name,
             directives,
-            fields,
+            fields: fields.into_bump_slice(),
         })
     }
 }
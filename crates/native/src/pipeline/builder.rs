@@ -5,6 +5,7 @@ use crate::source::Source;
 pub struct PipelineBuilder<'a> {
     arena: &'a Arena,
     schemas: Vec<Source<'a>>,
+    introspections: Vec<Source<'a>>,
     documents: Vec<Source<'a>>,
     config: PipelineConfig,
 }
@@ -14,6 +15,7 @@ impl<'a> PipelineBuilder<'a> {
         Self {
             arena,
             schemas: Vec::new(),
+            introspections: Vec::new(),
             documents: Vec::new(),
             config: PipelineConfig::default(),
         }
@@ -29,6 +31,17 @@ impl<'a> PipelineBuilder<'a> {
         self
     }
 
+    /// Adds a schema given as an introspection result JSON instead of SDL.
+    pub fn with_introspection(mut self, source: Source<'a>) -> Self {
+        self.introspections.push(source);
+        self
+    }
+
+    pub fn with_introspections(mut self, sources: Vec<Source<'a>>) -> Self {
+        self.introspections.extend(sources);
+        self
+    }
+
     pub fn with_document(mut self, source: Source<'a>) -> Self {
         self.documents.push(source);
         self
@@ -45,6 +58,12 @@ impl<'a> PipelineBuilder<'a> {
     }
 
     pub fn build(self) -> Pipeline<'a> {
-        Pipeline::new(self.arena, self.schemas, self.documents, self.config)
+        Pipeline::new(
+            self.arena,
+            self.schemas,
+            self.introspections,
+            self.documents,
+            self.config,
+        )
     }
 }
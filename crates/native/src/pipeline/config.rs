@@ -1,8 +1,13 @@
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 #[derive(Debug, Clone, Default)]
 pub struct PipelineConfig {
     pub scalar_map: FxHashMap<String, String>,
+    /// Entity key fields per object type. An empty list opts the type out of normalization.
+    pub key_fields: FxHashMap<String, Vec<String>>,
+    /// Paths of the files documents were read from. A `.graphql` or `.gql` document
+    /// file among them gets a `.d.ts` declaration written next to it.
+    pub document_files: FxHashSet<String>,
 }
 
 impl PipelineConfig {
@@ -14,4 +19,14 @@ impl PipelineConfig {
         self.scalar_map = scalar_map;
         self
     }
+
+    pub fn with_key_fields(mut self, key_fields: FxHashMap<String, Vec<String>>) -> Self {
+        self.key_fields = key_fields;
+        self
+    }
+
+    pub fn with_document_files(mut self, document_files: FxHashSet<String>) -> Self {
+        self.document_files = document_files;
+        self
+    }
 }
@@ -8,14 +8,16 @@ use crate::arena::Arena;
 use crate::codegen::{CodegenContext, Generator};
 use crate::error::MearieError;
 use crate::graphql::parser::Parser;
-use crate::schema::{DocumentIndex, SchemaBuilder};
+use crate::schema::{DocumentIndex, SchemaBuilder, parse_introspection};
 use crate::source::{Source, SourceBuf};
 use crate::transformation::transform_document;
 use crate::validation::{ValidationContext, Validator, visitor::VisitNode};
+use rayon::prelude::*;
 
 pub struct Pipeline<'a> {
     arena: &'a Arena,
     schemas: Vec<Source<'a>>,
+    introspections: Vec<Source<'a>>,
     documents: Vec<Source<'a>>,
     config: PipelineConfig,
 }
@@ -40,12 +42,14 @@ impl<'a> Pipeline<'a> {
     pub(crate) fn new(
         arena: &'a Arena,
         schemas: Vec<Source<'a>>,
+        introspections: Vec<Source<'a>>,
         documents: Vec<Source<'a>>,
         config: PipelineConfig,
     ) -> Self {
         Self {
             arena,
             schemas,
+            introspections,
             documents,
             config,
         }
@@ -54,13 +58,14 @@ impl<'a> Pipeline<'a> {
     /// Process the pipeline: parse, validate, transform, and generate code.
     ///
     /// The pipeline performs the following steps:
-    /// 1. Parse all schema documents
-    /// 2. Build SchemaIndex
-    /// 3. Parse all executable documents
-    /// 4. Build DocumentIndex
-    /// 5. Validate all documents
-    /// 6. Transform documents (add __typename and id fields)
-    /// 7. Generate TypeScript code
+    /// 1. Parse all schema documents (SDL and introspection results)
+    /// 2. Merge type system extensions, resolve entity keys and build SchemaIndex
+    /// 3. Validate the schema
+    /// 4. Parse all executable documents in parallel, recovering from syntax errors
+    /// 5. Build DocumentIndex
+    /// 6. Validate all documents in parallel
+    /// 7. Transform documents (add __typename and id fields)
+    /// 8. Generate TypeScript code
     ///
     /// # Returns
     ///
@@ -87,22 +92,44 @@ impl<'a> Pipeline<'a> {
             }
         }
 
+        for source in &self.introspections {
+            let document = parse_introspection(self.arena, source);
+            if let Err(e) = document.and_then(|doc| schema_builder.add_document(doc)) {
+                errors.push(e);
+            }
+        }
+
+        errors.extend(schema_builder.apply_extensions(self.arena));
+        errors.extend(schema_builder.apply_key_fields(&self.config.key_fields));
+
         let schema_index = schema_builder.build();
+        errors.extend(crate::schema::validate(&schema_index));
+
+        let parsed: Vec<_> = self
+            .documents
+            .par_iter()
+            .map(|source| Parser::new(self.arena).with_source(source).parse_recovering())
+            .collect();
 
         let mut document_index = DocumentIndex::new();
-        for source in &self.documents {
-            let document = Parser::new(self.arena).with_source(source).parse();
-            if let Err(e) = document.and_then(|doc| document_index.add_document(doc)) {
+        for (document, syntax_errors) in parsed {
+            errors.extend(syntax_errors);
+            if let Err(e) = document_index.add_document(document) {
                 errors.push(e);
             }
         }
 
-        for document in document_index.documents() {
-            let mut validator = Validator::default();
-            let mut ctx = ValidationContext::new(&schema_index, &document_index, document);
-            document.visit(&mut ctx, &mut validator);
-            errors.extend(ctx.errors().iter().cloned());
-        }
+        let documents = document_index.documents().collect::<Vec<_>>();
+        let validation_errors: Vec<_> = documents
+            .par_iter()
+            .map(|&document| {
+                let mut validator = Validator::default();
+                let mut ctx = ValidationContext::new(&schema_index, &document_index, document);
+                document.visit(&mut ctx, &mut validator);
+                ctx.errors().to_vec()
+            })
+            .collect();
+        errors.extend(validation_errors.into_iter().flatten());
 
         for document in document_index.documents().collect::<Vec<_>>() {
             let transformed = transform_document(self.arena, document, &schema_index);
@@ -496,6 +523,569 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_schema_extensions_across_files() {
+        let arena = Arena::new();
+
+        let base_schema = r#"
+            type Query { user: User }
+            type User { id: ID! }
+        "#;
+
+        let extension_schema = r#"
+            extend type Query { viewer: User }
+            extend type User { name: String! }
+        "#;
+
+        let operation_code = r#"
+            query GetViewer {
+                viewer {
+                    id
+                    name
+                }
+            }
+        "#;
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral(extension_schema))
+            .with_schema(Source::ephemeral(base_schema))
+            .with_document(Source::ephemeral(operation_code))
+            .build()
+            .process();
+
+        assert!(output.errors.is_empty(), "Expected no errors, got: {:?}", output.errors);
+
+        let types_file = output.sources.iter().find(|s| s.file_path == "types.d.ts").unwrap();
+        assert!(
+            types_file.code.contains("name: $Scalars[\"String\"];"),
+            "Types should contain the extended field, but got:\n{}",
+            types_file.code
+        );
+    }
+
+    #[test]
+    fn test_schema_extension_errors_are_reported() {
+        let arena = Arena::new();
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral("type Query { a: String }"))
+            .with_schema(Source::ephemeral("extend type Missing { b: String }"))
+            .build()
+            .process();
+
+        assert_eq!(output.errors.len(), 1);
+        assert_eq!(
+            output.errors[0].message(),
+            "Cannot extend object type 'Missing' because it is not defined"
+        );
+    }
+
+    #[test]
+    fn test_configured_entity_key_fields() {
+        let arena = Arena::new();
+
+        let schema = r#"
+            directive @key(fields: String!) on OBJECT
+            type Query { issue: Issue, article: Article, hit: SearchHit }
+            type Issue @key(fields: "orgId number") { id: ID! orgId: ID! number: Int! title: String! }
+            type Article { id: ID! slug: String! }
+            type SearchHit { id: ID! score: Float! }
+        "#;
+
+        let document = r#"
+            query GetAll {
+                issue { title }
+                article { id }
+                hit { score }
+            }
+        "#;
+
+        let mut key_fields = rustc_hash::FxHashMap::default();
+        key_fields.insert("Article".to_string(), vec!["slug".to_string()]);
+        key_fields.insert("SearchHit".to_string(), vec![]);
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral(schema))
+            .with_document(Source::ephemeral(document))
+            .with_config(PipelineConfig::new().with_key_fields(key_fields))
+            .build()
+            .process();
+
+        assert!(output.errors.is_empty(), "errors: {:?}", output.errors);
+
+        let runtime = output.sources.iter().find(|s| s.file_path == "graphql.js").unwrap();
+        assert!(runtime.code.contains(r#""Issue": { keyFields: ["orgId", "number"] }"#));
+        assert!(runtime.code.contains(r#""Article": { keyFields: ["slug"] }"#));
+        assert!(!runtime.code.contains(r#""SearchHit": { keyFields"#));
+        assert!(
+            runtime
+                .code
+                .contains(r#"issue {\n    __typename\n    orgId\n    number\n    title\n  }"#)
+        );
+        assert!(
+            runtime
+                .code
+                .contains(r#"article {\n    __typename\n    slug\n    id\n  }"#)
+        );
+        assert!(runtime.code.contains(r#"hit {\n    __typename\n    score\n  }"#));
+
+        let types = output.sources.iter().find(|s| s.file_path == "types.d.ts").unwrap();
+        assert!(types.code.contains(r#"orgId: $Scalars["ID"];"#));
+        assert!(types.code.contains(r#"number: $Scalars["Int"];"#));
+        assert!(types.code.contains(r#"slug: $Scalars["String"];"#));
+    }
+
+    #[test]
+    fn test_federation_key_directive_declaration() {
+        let arena = Arena::new();
+
+        let schema = r#"
+            scalar FieldSet
+            directive @key(fields: FieldSet!, resolvable: Boolean = true) repeatable on OBJECT | INTERFACE
+            type Query { user: User }
+            type User @key(fields: "id") @key(fields: "email") { id: ID! email: String! name: String }
+        "#;
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral(schema))
+            .with_document(Source::ephemeral("query GetUser { user { name } }"))
+            .build()
+            .process();
+
+        assert!(output.errors.is_empty(), "errors: {:?}", output.errors);
+
+        let runtime = output.sources.iter().find(|s| s.file_path == "graphql.js").unwrap();
+        assert!(runtime.code.contains(r#""User": { keyFields: ["id"] }"#));
+    }
+
+    #[test]
+    fn test_schema_validation_errors_are_reported() {
+        let arena = Arena::new();
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral(
+                "interface Node { id: ID! } type User implements Node { name: String } type Query { user: User }",
+            ))
+            .build()
+            .process();
+
+        assert_eq!(output.errors.len(), 1);
+        assert_eq!(
+            output.errors[0].message(),
+            "Interface field 'Node.id' expected but 'User' does not provide it"
+        );
+    }
+
+    #[test]
+    fn test_deprecated_field_usage_is_reported_as_warning() {
+        let arena = Arena::new();
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral(
+                r#"type Query { user: User } type User { id: ID! name: String @deprecated(reason: "Use fullName") }"#,
+            ))
+            .with_document(Source::ephemeral("query GetUser { user { id name } }"))
+            .build()
+            .process();
+
+        assert_eq!(output.errors.len(), 1);
+        assert!(!output.errors[0].is_error());
+        assert_eq!(
+            output.errors[0].message(),
+            "Field 'User.name' is deprecated: Use fullName"
+        );
+        assert!(!output.sources.is_empty());
+
+        let serialized = serde_json::to_value(&output.errors[0]).unwrap();
+        assert_eq!(serialized["severity"], "warning");
+        assert_eq!(serialized["stage"], "validation");
+    }
+
+    #[test]
+    fn test_duplicate_fragment_across_documents_relates_original_definition() {
+        let arena = Arena::new();
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral("type Query { user: User } type User { id: ID! }"))
+            .with_document(Source {
+                code: "fragment UserFields on User { id }",
+                file_path: "a.graphql",
+                start_line: 1,
+                start_column: 1,
+                offset: 0,
+                mappings: &[],
+            })
+            .with_document(Source {
+                code: "\nfragment UserFields on User { id }",
+                file_path: "b.graphql",
+                start_line: 1,
+                start_column: 1,
+                offset: 0,
+                mappings: &[],
+            })
+            .build()
+            .process();
+
+        assert_eq!(output.errors.len(), 1);
+
+        let serialized = serde_json::to_value(&output.errors[0]).unwrap();
+        assert_eq!(serialized["code"], "duplicate-fragment-name");
+        assert_eq!(serialized["location"]["file_path"], "b.graphql");
+        assert_eq!(serialized["location"]["line"], 2);
+        assert_eq!(
+            serialized["related"][0]["message"],
+            "Fragment 'UserFields' is first defined here"
+        );
+        assert_eq!(serialized["related"][0]["location"]["file_path"], "a.graphql");
+        assert_eq!(serialized["related"][0]["location"]["line"], 1);
+    }
+
+    #[test]
+    fn test_syntax_errors_do_not_stop_other_definitions() {
+        let arena = Arena::new();
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral(
+                "type Query { user: User } type User { id: ID! name: String }",
+            ))
+            .with_document(Source::ephemeral(
+                "query Broken($id: ) { user { id } }\nquery GetUser { user { id name } }",
+            ))
+            .build()
+            .process();
+
+        assert_eq!(output.errors.len(), 1);
+        assert_eq!(output.errors[0].code(), Some(crate::error::ErrorCode::SyntaxError));
+
+        let types = output.sources.iter().find(|s| s.file_path == "types.d.ts").unwrap();
+        assert!(types.code.contains("GetUser"));
+        assert!(!types.code.contains("Broken"));
+    }
+
+    #[test]
+    fn test_parallel_processing_matches_sequential_processing() {
+        let schema = "type Query { user(id: ID!): User } type User { id: ID! name: String posts: [Post!]! } type Post { id: ID! title: String }";
+        let documents: Vec<String> = (0..32)
+            .map(|index| match index % 4 {
+                0 => format!("fragment UserFields{index} on User {{ id name }}"),
+                1 => format!(
+                    "query GetUser{index}($id: ID!) {{ user(id: $id) {{ ...UserFields{} posts {{ id title }} }} }}",
+                    index - 1
+                ),
+                2 => format!("query Broken{index} {{ user(id: ) {{ id }} }}"),
+                _ => format!("query Unused{index}($unused: ID) {{ user(id: \"1\") {{ id }} }}"),
+            })
+            .collect();
+
+        let process = |num_threads| {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap();
+            pool.install(|| {
+                let arena = Arena::new();
+                let mut builder = Pipeline::builder(&arena).with_schema(Source::ephemeral(schema));
+                for document in &documents {
+                    builder = builder.with_document(Source::ephemeral(document));
+                }
+                let output = builder.build().process();
+                let sources: Vec<_> = output.sources.into_iter().map(|s| (s.file_path, s.code)).collect();
+                let errors: Vec<_> = output.errors.iter().map(|e| format!("{:?}", e)).collect();
+                (sources, errors)
+            })
+        };
+
+        let (sequential_sources, sequential_errors) = process(1);
+        let (parallel_sources, parallel_errors) = process(4);
+
+        assert_eq!(sequential_errors.len(), 16);
+        assert!(sequential_sources.iter().any(|(_, code)| code.contains("GetUser29")));
+        assert_eq!(parallel_errors, sequential_errors);
+        assert_eq!(parallel_sources, sequential_sources);
+    }
+
+    #[test]
+    fn test_unknown_field_suggestions_are_serialized() {
+        let arena = Arena::new();
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral(
+                "type Query { user: User } type User { id: ID! name: String }",
+            ))
+            .with_document(Source::ephemeral("query GetUser { user { id nmae } }"))
+            .build()
+            .process();
+
+        let serialized = serde_json::to_value(&output.errors[0]).unwrap();
+        assert_eq!(serialized["code"], "unknown-field");
+        assert_eq!(serialized["suggestions"], serde_json::json!(["name"]));
+        assert!(serialized.get("related").is_none());
+    }
+
+    #[test]
+    fn test_errors_in_extracted_documents_point_into_host_file() {
+        let arena = Arena::new();
+
+        let host = SourceBuf {
+            code: "import { graphql } from '$mearie';\n  const q = graphql(`query GetUser { user { nmae } }`);\n"
+                .to_string(),
+            file_path: "App.tsx".to_string(),
+            importable_file_path: None,
+            start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
+        };
+        let extracted = crate::extraction::extract_graphql_sources(host);
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral(
+                "type Query { user: User } type User { id: ID! name: String }",
+            ))
+            .with_document(Source::from(&extracted.sources[0]))
+            .build()
+            .process();
+
+        let location = output.errors[0].location().unwrap();
+        assert_eq!(location.file_path, "App.tsx");
+        assert_eq!(location.line, 2);
+        assert_eq!(location.column, Some(45));
+        assert_eq!(location.end_column, Some(49));
+    }
+
+    #[test]
+    fn test_graphql_comment_templates_are_validated() {
+        let arena = Arena::new();
+
+        let host = SourceBuf {
+            code: "const q = /* GraphQL */ `query GetUser { user { nmae } }`;\n".to_string(),
+            file_path: "legacy.ts".to_string(),
+            importable_file_path: None,
+            start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
+        };
+        let extracted = crate::extraction::extract_graphql_sources(host);
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral(
+                "type Query { user: User } type User { id: ID! name: String }",
+            ))
+            .with_document(Source::from(&extracted.sources[0]))
+            .build()
+            .process();
+
+        let location = output
+            .errors
+            .iter()
+            .find(|error| error.code().map(|code| code.as_str()) == Some("unknown-field"))
+            .and_then(|error| error.location())
+            .unwrap();
+        assert_eq!(location.file_path, "legacy.ts");
+        assert_eq!((location.line, location.column), (1, Some(49)));
+    }
+
+    #[test]
+    fn test_graphql_comment_templates_are_registered_like_graphql_calls() {
+        let arena = Arena::new();
+
+        let host = SourceBuf {
+            code: "const q = /* GraphQL */ `query GetUser { user { name } }`;\n".to_string(),
+            file_path: "legacy.ts".to_string(),
+            importable_file_path: None,
+            start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
+        };
+        let extracted = crate::extraction::extract_graphql_sources(host);
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral(
+                "type Query { user: User } type User { id: ID! name: String }",
+            ))
+            .with_document(Source::from(&extracted.sources[0]))
+            .build()
+            .process();
+
+        assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+        let runtime = output.sources.iter().find(|s| s.file_path == "graphql.js").unwrap();
+        assert!(runtime.code.contains(r#""query GetUser { user { name } }": $GetUser"#));
+        let module = output.sources.iter().find(|s| s.file_path == "graphql.d.ts").unwrap();
+        assert!(
+            module
+                .code
+                .contains(r#"export function graphql(artifact: "query GetUser { user { name } }"): GetUser;"#)
+        );
+    }
+
+    #[test]
+    fn test_errors_in_vue_graphql_blocks_point_into_component() {
+        let arena = Arena::new();
+
+        let component = SourceBuf {
+            code: [
+                "<template>",
+                "  <p>{{ user.name }}</p>",
+                "</template>",
+                "",
+                "<graphql>",
+                "query GetUser {",
+                "  user { nmae }",
+                "}",
+                "</graphql>",
+            ]
+            .join("\n"),
+            file_path: "/project/UserPage.vue".to_string(),
+            importable_file_path: None,
+            start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
+        };
+        let extracted = crate::extraction::extract_graphql_sources(component);
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral(
+                "type Query { user: User } type User { id: ID! name: String }",
+            ))
+            .with_document(Source::from(&extracted.sources[0]))
+            .build()
+            .process();
+
+        let location = output
+            .errors
+            .iter()
+            .find(|error| error.code().map(|code| code.as_str()) == Some("unknown-field"))
+            .and_then(|error| error.location())
+            .unwrap();
+        assert_eq!(location.file_path, "/project/UserPage.vue");
+        assert_eq!((location.line, location.column), (7, Some(10)));
+        assert_eq!(location.end_column, Some(14));
+    }
+
+    #[test]
+    fn test_errors_in_typed_graphql_documents_point_at_builder_code() {
+        let arena = Arena::new();
+
+        let host = SourceBuf {
+            code: [
+                "import { graphql } from '$mearie';",
+                "const q = graphql.query('GetUser', {",
+                "  select: () => ({",
+                "    user: [{ args: { id: true } }, {",
+                "      nmae: true,",
+                "    }],",
+                "  }),",
+                "});",
+            ]
+            .join("\n"),
+            file_path: "App.ts".to_string(),
+            importable_file_path: None,
+            start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
+        };
+        let extracted = crate::extraction::extract_graphql_sources(host);
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral(
+                "type Query { user(id: ID!): User } type User { id: ID! name: String }",
+            ))
+            .with_document(Source::from(&extracted.sources[0]))
+            .build()
+            .process();
+
+        let location_of = |code: &str| {
+            output
+                .errors
+                .iter()
+                .find(|error| error.code().map(|code| code.as_str()) == Some(code))
+                .and_then(|error| error.location())
+                .unwrap()
+        };
+
+        let location = location_of("unknown-field");
+        assert_eq!(location.file_path, "App.ts");
+        assert_eq!((location.line, location.column), (5, Some(7)));
+        assert_eq!((location.end_line, location.end_column), (Some(5), Some(11)));
+
+        let location = location_of("invalid-value");
+        assert_eq!((location.line, location.column), (4, Some(26)));
+        assert_eq!(location.end_column, Some(30));
+    }
+
+    #[test]
+    fn test_introspection_schema_source() {
+        let arena = Arena::new();
+
+        let introspection = r#"{
+            "data": {
+                "__schema": {
+                    "queryType": { "name": "Query" },
+                    "types": [
+                        {
+                            "kind": "OBJECT",
+                            "name": "Query",
+                            "fields": [
+                                { "name": "user", "args": [], "type": { "kind": "OBJECT", "name": "User" } }
+                            ],
+                            "interfaces": []
+                        },
+                        {
+                            "kind": "OBJECT",
+                            "name": "User",
+                            "fields": [
+                                {
+                                    "name": "id",
+                                    "args": [],
+                                    "type": { "kind": "NON_NULL", "ofType": { "kind": "SCALAR", "name": "ID" } }
+                                },
+                                { "name": "name", "args": [], "type": { "kind": "SCALAR", "name": "String" } }
+                            ],
+                            "interfaces": []
+                        }
+                    ],
+                    "directives": []
+                }
+            }
+        }"#;
+
+        let operation_code = r#"
+            query GetUser {
+                user {
+                    id
+                    name
+                }
+            }
+        "#;
+
+        let output = Pipeline::builder(&arena)
+            .with_introspection(Source {
+                code: introspection,
+                file_path: "schema.json",
+                start_line: 1,
+                start_column: 1,
+                offset: 0,
+                mappings: &[],
+            })
+            .with_document(Source::ephemeral(operation_code))
+            .build()
+            .process();
+
+        assert!(output.errors.is_empty(), "Expected no errors, got: {:?}", output.errors);
+
+        let types_file = output.sources.iter().find(|s| s.file_path == "types.d.ts").unwrap();
+        assert!(
+            types_file.code.contains("export type GetUser$data"),
+            "Types should be generated from the introspection schema, but got:\n{}",
+            types_file.code
+        );
+    }
+
     macro_rules! assert_pipeline_snapshots {
         ($name:expr, $output:expr) => {{
             let types = $output.sources.iter().find(|s| s.file_path == "types.d.ts").unwrap();
@@ -507,6 +1097,40 @@ mod tests {
         }};
     }
 
+    #[test]
+    fn snapshot_template_literal_keys() {
+        let arena = Arena::new();
+
+        let host = SourceBuf {
+            code: [
+                "import { graphql } from '$mearie';",
+                "const UserFields = graphql(`fragment UserFields on User { name }`);",
+                r#"const GetGreeting = graphql(`query GetGreeting { greeting(format: "\\\\d+", locale: "caf\u00e9") }`);"#,
+                "const GetUser = graphql`query GetUser { user { ...UserFields } } ${UserFields}`;",
+            ]
+            .join("\n"),
+            file_path: "App.ts".to_string(),
+            importable_file_path: None,
+            start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: None,
+        };
+        let extracted = crate::extraction::extract_graphql_sources(host);
+        assert!(extracted.errors.is_empty(), "{:?}", extracted.errors);
+
+        let output = Pipeline::builder(&arena)
+            .with_schema(Source::ephemeral(
+                "type Query { user: User greeting(format: String, locale: String): String } type User { id: ID! name: String }",
+            ))
+            .with_documents(extracted.sources.iter().map(Source::from).collect())
+            .build()
+            .process();
+
+        assert!(output.errors.is_empty(), "{:?}", output.errors);
+        assert_pipeline_snapshots!("template_literal_keys", output);
+    }
+
     #[test]
     fn snapshot_simple_query() {
         let arena = Arena::new();
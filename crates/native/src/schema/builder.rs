@@ -1,8 +1,13 @@
-use crate::error::{MearieError, Result};
+use crate::arena::Arena;
+use crate::error::{ErrorCode, MearieError, Result};
 use crate::graphql::ast::*;
 use crate::schema::{SchemaIndex, TypeInfo};
+use crate::source::Source;
 use rustc_hash::{FxHashMap, FxHashSet};
 
+/// The directive that declares the entity key fields of an object type.
+const KEY_DIRECTIVE: &str = "key";
+
 /// Builder for constructing an immutable [`SchemaIndex`].
 ///
 /// `SchemaBuilder` processes GraphQL schema documents and builds optimized
@@ -21,6 +26,10 @@ use rustc_hash::{FxHashMap, FxHashSet};
 /// // Add schema documents
 /// // builder.add_document(schema_doc).unwrap();
 ///
+/// // Merge `extend ...` definitions once every document is added
+/// let errors = builder.apply_extensions(&arena);
+/// assert!(errors.is_empty());
+///
 /// // Build immutable index
 /// let index = builder.build();
 /// ```
@@ -30,10 +39,14 @@ pub struct SchemaBuilder<'a> {
     interface_implementors: FxHashMap<&'a str, FxHashSet<&'a str>>,
     union_members: FxHashMap<&'a str, FxHashSet<&'a str>>,
     directives: FxHashMap<&'a str, &'a DirectiveDefinition<'a>>,
+    type_sources: FxHashMap<&'a str, &'a Source<'a>>,
+    directive_sources: FxHashMap<&'a str, &'a Source<'a>>,
     custom_scalars: Vec<&'a str>,
     query_type: Option<&'a str>,
     mutation_type: Option<&'a str>,
     subscription_type: Option<&'a str>,
+    extensions: Vec<&'a TypeSystemExtension<'a>>,
+    key_field_overrides: FxHashMap<&'a str, Vec<&'a str>>,
 }
 
 impl<'a> SchemaBuilder<'a> {
@@ -47,10 +60,14 @@ impl<'a> SchemaBuilder<'a> {
             interface_implementors: FxHashMap::default(),
             union_members: FxHashMap::default(),
             directives: FxHashMap::default(),
+            type_sources: FxHashMap::default(),
+            directive_sources: FxHashMap::default(),
             custom_scalars: Vec::new(),
             query_type: None,
             mutation_type: None,
             subscription_type: None,
+            extensions: Vec::new(),
+            key_field_overrides: FxHashMap::default(),
         }
     }
 
@@ -58,6 +75,8 @@ impl<'a> SchemaBuilder<'a> {
     ///
     /// Processes all type definitions, schema definitions, and directives from the document.
     /// Operations and fragments are ignored (use [`DocumentIndex`](crate::schema::DocumentIndex) for those).
+    /// Type system extensions are deferred until [`apply_extensions`](Self::apply_extensions) is called,
+    /// so an extension may target a type defined in a later document.
     ///
     /// # Errors
     ///
@@ -76,19 +95,23 @@ impl<'a> SchemaBuilder<'a> {
     /// // builder.add_document(schema_doc)?;
     /// ```
     pub fn add_document(&mut self, doc: &'a Document<'a>) -> Result<()> {
-        for definition in &doc.definitions {
+        for definition in doc.definitions {
             match definition {
                 Definition::TypeSystem(TypeSystemDefinition::Type(type_def)) => {
                     self.register_type_definition(type_def)?;
+                    self.type_sources.insert(type_def.name().as_str(), doc.source);
                 }
                 Definition::TypeSystem(TypeSystemDefinition::Schema(schema_def)) => {
                     self.process_schema_definition(schema_def);
                 }
                 Definition::TypeSystem(TypeSystemDefinition::Directive(directive_def)) => {
                     self.register_directive(directive_def)?;
+                    self.directive_sources.insert(directive_def.name.as_str(), doc.source);
                 }
                 Definition::Executable(_) => {}
-                Definition::TypeSystemExtension(_) => {}
+                Definition::TypeSystemExtension(extension) => {
+                    self.extensions.push(extension);
+                }
             }
         }
         Ok(())
@@ -109,21 +132,21 @@ impl<'a> SchemaBuilder<'a> {
         let type_name = obj.name.as_str();
 
         if self.types.contains_key(type_name) {
-            return Err(MearieError::validation(format!(
-                "Duplicate type definition: {}",
-                type_name
-            )));
+            return Err(
+                MearieError::validation(format!("Duplicate type definition: {}", type_name))
+                    .with_code(ErrorCode::DuplicateTypeDefinition),
+            );
         }
 
         self.types.insert(type_name, TypeInfo::Object(obj));
 
         let mut field_map = FxHashMap::default();
-        for field in &obj.fields {
+        for field in obj.fields {
             field_map.insert(field.name.as_str(), field);
         }
         self.fields.insert(type_name, field_map);
 
-        for interface_name in &obj.implements {
+        for interface_name in obj.implements {
             self.interface_implementors
                 .entry(interface_name.as_str())
                 .or_default()
@@ -137,22 +160,29 @@ impl<'a> SchemaBuilder<'a> {
         let type_name = iface.name.as_str();
 
         if self.types.contains_key(type_name) {
-            return Err(MearieError::validation(format!(
-                "Duplicate type definition: {}",
-                type_name
-            )));
+            return Err(
+                MearieError::validation(format!("Duplicate type definition: {}", type_name))
+                    .with_code(ErrorCode::DuplicateTypeDefinition),
+            );
         }
 
         self.types.insert(type_name, TypeInfo::Interface(iface));
 
         let mut field_map = FxHashMap::default();
-        for field in &iface.fields {
+        for field in iface.fields {
             field_map.insert(field.name.as_str(), field);
         }
         self.fields.insert(type_name, field_map);
 
         self.interface_implementors.entry(type_name).or_default();
 
+        for interface_name in iface.implements {
+            self.interface_implementors
+                .entry(interface_name.as_str())
+                .or_default()
+                .insert(type_name);
+        }
+
         Ok(())
     }
 
@@ -160,10 +190,10 @@ impl<'a> SchemaBuilder<'a> {
         let type_name = union.name.as_str();
 
         if self.types.contains_key(type_name) {
-            return Err(MearieError::validation(format!(
-                "Duplicate type definition: {}",
-                type_name
-            )));
+            return Err(
+                MearieError::validation(format!("Duplicate type definition: {}", type_name))
+                    .with_code(ErrorCode::DuplicateTypeDefinition),
+            );
         }
 
         self.types.insert(type_name, TypeInfo::Union(union));
@@ -178,10 +208,10 @@ impl<'a> SchemaBuilder<'a> {
         let type_name = enum_type.name.as_str();
 
         if self.types.contains_key(type_name) {
-            return Err(MearieError::validation(format!(
-                "Duplicate type definition: {}",
-                type_name
-            )));
+            return Err(
+                MearieError::validation(format!("Duplicate type definition: {}", type_name))
+                    .with_code(ErrorCode::DuplicateTypeDefinition),
+            );
         }
 
         self.types.insert(type_name, TypeInfo::Enum(enum_type));
@@ -193,10 +223,10 @@ impl<'a> SchemaBuilder<'a> {
         let type_name = scalar.name.as_str();
 
         if self.types.contains_key(type_name) {
-            return Err(MearieError::validation(format!(
-                "Duplicate type definition: {}",
-                type_name
-            )));
+            return Err(
+                MearieError::validation(format!("Duplicate type definition: {}", type_name))
+                    .with_code(ErrorCode::DuplicateTypeDefinition),
+            );
         }
 
         self.types.insert(type_name, TypeInfo::Scalar(scalar));
@@ -213,10 +243,10 @@ impl<'a> SchemaBuilder<'a> {
         let type_name = input.name.as_str();
 
         if self.types.contains_key(type_name) {
-            return Err(MearieError::validation(format!(
-                "Duplicate type definition: {}",
-                type_name
-            )));
+            return Err(
+                MearieError::validation(format!("Duplicate type definition: {}", type_name))
+                    .with_code(ErrorCode::DuplicateTypeDefinition),
+            );
         }
 
         self.types.insert(type_name, TypeInfo::InputObject(input));
@@ -228,10 +258,10 @@ impl<'a> SchemaBuilder<'a> {
         let directive_name = directive.name.as_str();
 
         if self.directives.contains_key(directive_name) {
-            return Err(MearieError::validation(format!(
-                "Duplicate directive definition: @{}",
-                directive_name
-            )));
+            return Err(
+                MearieError::validation(format!("Duplicate directive definition: @{}", directive_name))
+                    .with_code(ErrorCode::DuplicateDirectiveDefinition),
+            );
         }
 
         self.directives.insert(directive_name, directive);
@@ -251,6 +281,417 @@ impl<'a> SchemaBuilder<'a> {
         }
     }
 
+    /// Merges all deferred type system extensions into their base definitions.
+    ///
+    /// Must be called after every schema document has been added. Each extended
+    /// definition is re-allocated in the arena with the extension's fields,
+    /// interfaces, union members, enum values, input fields and directives appended,
+    /// and the field and relationship indices are updated accordingly.
+    ///
+    /// Extensions that cannot be applied are skipped and reported; the remaining
+    /// extensions are still merged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for each extension that:
+    /// - Targets a type that does not exist or is of a different kind
+    /// - Redefines an existing field, input field, enum value, union member or interface
+    /// - Redefines an already defined root operation type
+    pub fn apply_extensions(&mut self, arena: &'a Arena) -> Vec<MearieError> {
+        let mut errors = Vec::new();
+
+        for extension in std::mem::take(&mut self.extensions) {
+            let result = match extension {
+                TypeSystemExtension::Schema(schema_ext) => self.extend_schema(schema_ext),
+                TypeSystemExtension::Type(TypeExtension::Scalar(ext)) => self.extend_scalar_type(arena, ext),
+                TypeSystemExtension::Type(TypeExtension::Object(ext)) => self.extend_object_type(arena, ext),
+                TypeSystemExtension::Type(TypeExtension::Interface(ext)) => self.extend_interface_type(arena, ext),
+                TypeSystemExtension::Type(TypeExtension::Union(ext)) => self.extend_union_type(arena, ext),
+                TypeSystemExtension::Type(TypeExtension::Enum(ext)) => self.extend_enum_type(arena, ext),
+                TypeSystemExtension::Type(TypeExtension::InputObject(ext)) => self.extend_input_object_type(arena, ext),
+            };
+
+            if let Err(e) = result {
+                errors.push(e);
+            }
+        }
+
+        errors
+    }
+
+    fn extension_target(&self, type_name: &str, expected_kind: &str) -> Result<TypeInfo<'a>> {
+        self.types.get(type_name).copied().ok_or_else(|| {
+            MearieError::validation(format!(
+                "Cannot extend {} '{}' because it is not defined",
+                expected_kind, type_name
+            ))
+            .with_code(ErrorCode::InvalidExtension)
+        })
+    }
+
+    fn extension_kind_mismatch(type_name: &str, expected_kind: &str) -> MearieError {
+        MearieError::validation(format!(
+            "Cannot extend '{}' as {}: it is defined as a different kind of type",
+            type_name, expected_kind
+        ))
+        .with_code(ErrorCode::InvalidExtension)
+    }
+
+    fn extend_fields(
+        type_name: &str,
+        fields: &[FieldDefinition<'a>],
+        new_fields: &[FieldDefinition<'a>],
+    ) -> Result<()> {
+        for field in new_fields {
+            if fields.iter().any(|existing| existing.name == field.name) {
+                return Err(MearieError::validation(format!(
+                    "Field '{}.{}' already exists and cannot be redefined by an extension",
+                    type_name, field.name
+                ))
+                .with_code(ErrorCode::InvalidExtension));
+            }
+        }
+        Ok(())
+    }
+
+    fn extend_implements(type_name: &str, implements: &[TypeName<'a>], new_interfaces: &[TypeName<'a>]) -> Result<()> {
+        for interface_name in new_interfaces {
+            if implements.contains(interface_name) {
+                return Err(MearieError::validation(format!(
+                    "Type '{}' already implements interface '{}'",
+                    type_name, interface_name
+                ))
+                .with_code(ErrorCode::InvalidExtension));
+            }
+        }
+        Ok(())
+    }
+
+    fn index_fields(&mut self, type_name: &'a str, fields: &'a [FieldDefinition<'a>]) {
+        let field_map = fields.iter().map(|field| (field.name.as_str(), field)).collect();
+        self.fields.insert(type_name, field_map);
+    }
+
+    fn extend_scalar_type(&mut self, arena: &'a Arena, ext: &'a ScalarTypeExtension<'a>) -> Result<()> {
+        let type_name = ext.name.as_str();
+        let TypeInfo::Scalar(scalar) = self.extension_target(type_name, "scalar")? else {
+            return Err(Self::extension_kind_mismatch(type_name, "scalar"));
+        };
+
+        let mut merged = scalar.clone();
+        merged.directives = arena.alloc_slice(merged.directives.iter().chain(ext.directives).cloned());
+        self.types.insert(type_name, TypeInfo::Scalar(arena.alloc(merged)));
+
+        Ok(())
+    }
+
+    fn extend_object_type(&mut self, arena: &'a Arena, ext: &'a ObjectTypeExtension<'a>) -> Result<()> {
+        let type_name = ext.name.as_str();
+        let TypeInfo::Object(obj) = self.extension_target(type_name, "object type")? else {
+            return Err(Self::extension_kind_mismatch(type_name, "object type"));
+        };
+
+        let mut merged = obj.clone();
+        Self::extend_fields(type_name, merged.fields, ext.fields)?;
+        Self::extend_implements(type_name, merged.implements, ext.implements)?;
+        merged.fields = arena.alloc_slice(merged.fields.iter().chain(ext.fields).cloned());
+        merged.implements = arena.alloc_slice(merged.implements.iter().chain(ext.implements).copied());
+        merged.directives = arena.alloc_slice(merged.directives.iter().chain(ext.directives).cloned());
+
+        let merged = arena.alloc(merged);
+        self.types.insert(type_name, TypeInfo::Object(merged));
+        self.index_fields(type_name, merged.fields);
+
+        for interface_name in ext.implements {
+            self.interface_implementors
+                .entry(interface_name.as_str())
+                .or_default()
+                .insert(type_name);
+        }
+
+        Ok(())
+    }
+
+    fn extend_interface_type(&mut self, arena: &'a Arena, ext: &'a InterfaceTypeExtension<'a>) -> Result<()> {
+        let type_name = ext.name.as_str();
+        let TypeInfo::Interface(iface) = self.extension_target(type_name, "interface")? else {
+            return Err(Self::extension_kind_mismatch(type_name, "interface"));
+        };
+
+        let mut merged = iface.clone();
+        Self::extend_fields(type_name, merged.fields, ext.fields)?;
+        Self::extend_implements(type_name, merged.implements, ext.implements)?;
+        merged.fields = arena.alloc_slice(merged.fields.iter().chain(ext.fields).cloned());
+        merged.implements = arena.alloc_slice(merged.implements.iter().chain(ext.implements).copied());
+        merged.directives = arena.alloc_slice(merged.directives.iter().chain(ext.directives).cloned());
+
+        let merged = arena.alloc(merged);
+        self.types.insert(type_name, TypeInfo::Interface(merged));
+        self.index_fields(type_name, merged.fields);
+
+        for interface_name in ext.implements {
+            self.interface_implementors
+                .entry(interface_name.as_str())
+                .or_default()
+                .insert(type_name);
+        }
+
+        Ok(())
+    }
+
+    fn extend_union_type(&mut self, arena: &'a Arena, ext: &'a UnionTypeExtension<'a>) -> Result<()> {
+        let type_name = ext.name.as_str();
+        let TypeInfo::Union(union) = self.extension_target(type_name, "union")? else {
+            return Err(Self::extension_kind_mismatch(type_name, "union"));
+        };
+
+        let mut merged = union.clone();
+        for member in ext.members {
+            if merged.members.contains(member) {
+                return Err(MearieError::validation(format!(
+                    "Union '{}' already includes member '{}'",
+                    type_name, member
+                ))
+                .with_code(ErrorCode::InvalidExtension));
+            }
+        }
+        merged.members = arena.alloc_slice(merged.members.iter().chain(ext.members).copied());
+        merged.directives = arena.alloc_slice(merged.directives.iter().chain(ext.directives).cloned());
+
+        self.types.insert(type_name, TypeInfo::Union(arena.alloc(merged)));
+        self.union_members
+            .entry(type_name)
+            .or_default()
+            .extend(ext.members.iter().map(|t| t.as_str()));
+
+        Ok(())
+    }
+
+    fn extend_enum_type(&mut self, arena: &'a Arena, ext: &'a EnumTypeExtension<'a>) -> Result<()> {
+        let type_name = ext.name.as_str();
+        let TypeInfo::Enum(enum_type) = self.extension_target(type_name, "enum")? else {
+            return Err(Self::extension_kind_mismatch(type_name, "enum"));
+        };
+
+        let mut merged = enum_type.clone();
+        for value in ext.values {
+            if merged.values.iter().any(|existing| existing.value == value.value) {
+                return Err(MearieError::validation(format!(
+                    "Enum value '{}.{}' already exists and cannot be redefined by an extension",
+                    type_name, value.value
+                ))
+                .with_code(ErrorCode::InvalidExtension));
+            }
+        }
+        merged.values = arena.alloc_slice(merged.values.iter().chain(ext.values).cloned());
+        merged.directives = arena.alloc_slice(merged.directives.iter().chain(ext.directives).cloned());
+
+        self.types.insert(type_name, TypeInfo::Enum(arena.alloc(merged)));
+
+        Ok(())
+    }
+
+    fn extend_input_object_type(&mut self, arena: &'a Arena, ext: &'a InputObjectTypeExtension<'a>) -> Result<()> {
+        let type_name = ext.name.as_str();
+        let TypeInfo::InputObject(input) = self.extension_target(type_name, "input object")? else {
+            return Err(Self::extension_kind_mismatch(type_name, "input object"));
+        };
+
+        let mut merged = input.clone();
+        for field in ext.fields {
+            if merged.fields.iter().any(|existing| existing.name == field.name) {
+                return Err(MearieError::validation(format!(
+                    "Input field '{}.{}' already exists and cannot be redefined by an extension",
+                    type_name, field.name
+                ))
+                .with_code(ErrorCode::InvalidExtension));
+            }
+        }
+        merged.fields = arena.alloc_slice(merged.fields.iter().chain(ext.fields).cloned());
+        merged.directives = arena.alloc_slice(merged.directives.iter().chain(ext.directives).cloned());
+
+        self.types.insert(type_name, TypeInfo::InputObject(arena.alloc(merged)));
+
+        Ok(())
+    }
+
+    fn extend_schema(&mut self, schema_ext: &'a SchemaExtension<'a>) -> Result<()> {
+        let roots = [
+            ("query", schema_ext.query, &mut self.query_type),
+            ("mutation", schema_ext.mutation, &mut self.mutation_type),
+            ("subscription", schema_ext.subscription, &mut self.subscription_type),
+        ];
+
+        for (operation, type_name, slot) in roots {
+            let Some(type_name) = type_name else {
+                continue;
+            };
+            if let Some(existing) = slot {
+                return Err(MearieError::validation(format!(
+                    "Root {} type is already defined as '{}' and cannot be redefined by a schema extension",
+                    operation, existing
+                ))
+                .with_code(ErrorCode::InvalidExtension));
+            }
+            *slot = Some(type_name.as_str());
+        }
+
+        Ok(())
+    }
+
+    /// Validates `@key` directives and applies per-type entity key overrides.
+    ///
+    /// Must be called after every schema document has been added and extensions have
+    /// been applied. Each entry maps an object type name to the fields that identify
+    /// it in the normalized cache; an empty list opts the type out of normalization.
+    /// Overrides take precedence over `@key(fields: "...")` directives, which in turn
+    /// take precedence over the default `id`, `_id`, `uuid` heuristic. `@key` is not a
+    /// built-in directive, so it is only read when the schema declares it, as Apollo
+    /// Federation schemas do. Of repeated `@key` directives, the first one is used.
+    ///
+    /// Invalid overrides are skipped and reported; the remaining ones are still applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for each override or `@key` directive that:
+    /// - Targets a type that is not defined or is not an object type
+    /// - References a field that is not defined on the type
+    /// - References a field that is not a scalar or enum, or is a list
+    pub fn apply_key_fields(&mut self, key_fields: &FxHashMap<String, Vec<String>>) -> Vec<MearieError> {
+        let mut errors = Vec::new();
+
+        let mut objects: Vec<_> = self
+            .types
+            .values()
+            .filter_map(|type_info| match type_info {
+                TypeInfo::Object(obj) => Some(*obj),
+                _ => None,
+            })
+            .collect();
+        objects.sort_unstable_by_key(|obj| obj.name.as_str());
+
+        for obj in objects {
+            if let Some(directive) = self.key_directive(obj)
+                && let Err(e) = self.resolve_key_directive(obj.name.as_str(), directive)
+            {
+                errors.push(e);
+            }
+        }
+
+        let mut overrides: Vec<_> = key_fields.iter().collect();
+        overrides.sort_unstable_by_key(|(type_name, _)| type_name.as_str());
+
+        for (type_name, field_names) in overrides {
+            let Some(TypeInfo::Object(obj)) = self.types.get(type_name.as_str()) else {
+                errors.push(
+                    MearieError::validation(format!(
+                        "Cannot configure key fields for '{}' because it is not an object type",
+                        type_name
+                    ))
+                    .with_code(ErrorCode::InvalidKeyFields),
+                );
+                continue;
+            };
+
+            match self.resolve_key_fields(obj.name.as_str(), field_names.iter().map(String::as_str)) {
+                Ok(fields) => {
+                    self.key_field_overrides.insert(obj.name.as_str(), fields);
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+
+        errors
+    }
+
+    /// Returns the first `@key` directive of `obj`, if the schema declares `@key`.
+    fn key_directive(&self, obj: &'a ObjectTypeDefinition<'a>) -> Option<&'a Directive<'a>> {
+        if !self.directives.contains_key(KEY_DIRECTIVE) {
+            return None;
+        }
+
+        obj.directives.iter().find(|d| d.name == KEY_DIRECTIVE)
+    }
+
+    fn resolve_key_directive(&self, type_name: &str, directive: &Directive<'a>) -> Result<Vec<&'a str>> {
+        let Some(Value {
+            kind: ValueKind::String(fields),
+            ..
+        }) = directive.get_argument("fields")
+        else {
+            return Err(MearieError::validation(format!(
+                "Directive '@key' on '{}' requires a string 'fields' argument",
+                type_name
+            ))
+            .with_code(ErrorCode::InvalidKeyFields));
+        };
+
+        self.resolve_key_fields(type_name, fields.split_whitespace())
+    }
+
+    fn resolve_key_fields<'s>(
+        &self,
+        type_name: &str,
+        field_names: impl Iterator<Item = &'s str>,
+    ) -> Result<Vec<&'a str>> {
+        let fields = self.fields.get(type_name);
+
+        field_names
+            .map(|field_name| {
+                let Some((&name, field_def)) = fields.and_then(|fields| fields.get_key_value(field_name)) else {
+                    return Err(MearieError::validation(format!(
+                        "Key field '{}' is not defined on type '{}'",
+                        field_name, type_name
+                    ))
+                    .with_code(ErrorCode::InvalidKeyFields));
+                };
+
+                let is_leaf = matches!(
+                    self.types.get(field_def.typ.innermost_type().as_str()),
+                    Some(TypeInfo::Scalar(_) | TypeInfo::Enum(_))
+                );
+                if !is_leaf || field_def.typ.is_list() {
+                    return Err(MearieError::validation(format!(
+                        "Key field '{}.{}' must be a scalar or enum field",
+                        type_name, field_name
+                    ))
+                    .with_code(ErrorCode::InvalidKeyFields));
+                }
+
+                Ok(name)
+            })
+            .collect()
+    }
+
+    /// Determines the key fields of an object type: configured overrides first, then a
+    /// valid `@key` directive, then the first non-null scalar `id`, `_id` or `uuid` field.
+    fn entity_key_fields(&self, obj: &'a ObjectTypeDefinition<'a>) -> Option<Vec<&'a str>> {
+        const KEY_FIELD_NAMES: [&str; 3] = ["id", "_id", "uuid"];
+
+        let type_name = obj.name.as_str();
+
+        if let Some(fields) = self.key_field_overrides.get(type_name) {
+            return (!fields.is_empty()).then(|| fields.clone());
+        }
+
+        if let Some(directive) = self.key_directive(obj) {
+            return self
+                .resolve_key_directive(type_name, directive)
+                .ok()
+                .filter(|fields| !fields.is_empty());
+        }
+
+        let fields = self.fields.get(type_name)?;
+        KEY_FIELD_NAMES.iter().find_map(|&key_name| {
+            let (&name, field_def) = fields.get_key_value(key_name)?;
+            let is_scalar = matches!(
+                self.types.get(field_def.typ.innermost_type().as_str()),
+                Some(TypeInfo::Scalar(_))
+            );
+            (!field_def.typ.is_nullable() && !field_def.typ.is_list() && is_scalar).then(|| vec![name])
+        })
+    }
+
     /// Consumes the builder and returns an immutable [`SchemaIndex`].
     ///
     /// After calling this method, the builder cannot be used again.
@@ -265,18 +706,58 @@ impl<'a> SchemaBuilder<'a> {
     /// let index = builder.build();
     /// ```
     pub fn build(self) -> SchemaIndex<'a> {
+        let interface_implementors = Self::resolve_transitive_implementors(&self.interface_implementors);
+
+        let root_types = [self.query_type, self.mutation_type, self.subscription_type];
+        let entity_keys = self
+            .types
+            .iter()
+            .filter(|(type_name, _)| !root_types.contains(&Some(**type_name)))
+            .filter_map(|(&type_name, type_info)| match type_info {
+                TypeInfo::Object(obj) => Some((type_name, self.entity_key_fields(obj)?)),
+                _ => None,
+            })
+            .collect();
+
         SchemaIndex::new(
             self.types,
             self.fields,
-            self.interface_implementors,
+            interface_implementors,
             self.union_members,
             self.directives,
+            self.type_sources,
+            self.directive_sources,
+            entity_keys,
             self.custom_scalars,
             self.query_type,
             self.mutation_type,
             self.subscription_type,
         )
     }
+
+    /// Expands direct `implements` relationships so that every interface maps to all
+    /// object and interface types implementing it, directly or through another interface.
+    fn resolve_transitive_implementors(
+        direct: &FxHashMap<&'a str, FxHashSet<&'a str>>,
+    ) -> FxHashMap<&'a str, FxHashSet<&'a str>> {
+        direct
+            .keys()
+            .map(|&interface_name| {
+                let mut implementors = FxHashSet::default();
+                let mut stack = vec![interface_name];
+
+                while let Some(current) = stack.pop() {
+                    for &implementor in direct.get(current).into_iter().flatten() {
+                        if implementor != interface_name && implementors.insert(implementor) {
+                            stack.push(implementor);
+                        }
+                    }
+                }
+
+                (interface_name, implementors)
+            })
+            .collect()
+    }
 }
 
 impl<'a> Default for SchemaBuilder<'a> {
@@ -290,9 +771,31 @@ mod tests {
     use super::*;
     use crate::arena::Arena;
     use crate::graphql::ast::{FieldName, Name, TypeName};
+    use crate::graphql::parser::Parser;
     use crate::source::Source;
     use assertables::*;
 
+    fn build_from_sdl<'a>(arena: &'a Arena, sdl: &'a str) -> (SchemaIndex<'a>, Vec<MearieError>) {
+        let source = arena.alloc(Source::ephemeral(sdl));
+        let doc = Parser::new(arena).with_source(source).parse().unwrap();
+
+        let mut builder = SchemaBuilder::new();
+        builder.add_document(doc).unwrap();
+        let errors = builder.apply_extensions(arena);
+
+        (builder.build(), errors)
+    }
+
+    fn builder_from_sdl<'a>(arena: &'a Arena, sdl: &'a str) -> SchemaBuilder<'a> {
+        let mut builder = SchemaBuilder::new();
+        for code in [crate::schema::BUILTIN_SCHEMA, sdl] {
+            let source = arena.alloc(Source::ephemeral(code));
+            let doc = Parser::new(arena).with_source(source).parse().unwrap();
+            builder.add_document(doc).unwrap();
+        }
+        builder
+    }
+
     #[test]
     fn test_add_object_type() {
         let arena = Arena::new();
@@ -301,18 +804,18 @@ mod tests {
         let user_name = TypeName::new(Name::new(arena.intern("User")));
         let obj = ObjectTypeDefinition {
             name: user_name,
-            implements: arena.alloc_vec(),
-            directives: arena.alloc_vec(),
-            fields: arena.alloc_vec(),
+            implements: &[],
+            directives: &[],
+            fields: &[],
             description: None,
         };
 
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(obj)))
-            ],
+            definitions: arena.alloc_slice([Definition::TypeSystem(TypeSystemDefinition::Type(
+                TypeDefinition::Object(obj),
+            ))]),
         });
 
         builder.add_document(doc).unwrap();
@@ -333,22 +836,26 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Interface(InterfaceTypeDefinition {
-                    name: node_name,
-                    implements: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    fields: arena.alloc_vec(),
-                    description: None,
-                }))),
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(ObjectTypeDefinition {
-                    name: user_name,
-                    implements: bumpalo::vec![in arena.allocator(); node_name],
-                    directives: arena.alloc_vec(),
-                    fields: arena.alloc_vec(),
-                    description: None,
-                }))),
-            ],
+            definitions: arena.alloc_slice([
+                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Interface(
+                    InterfaceTypeDefinition {
+                        name: node_name,
+                        implements: &[],
+                        directives: &[],
+                        fields: &[],
+                        description: None,
+                    },
+                ))),
+                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(
+                    ObjectTypeDefinition {
+                        name: user_name,
+                        implements: arena.alloc_slice([node_name]),
+                        directives: &[],
+                        fields: &[],
+                        description: None,
+                    },
+                ))),
+            ]),
         });
 
         builder.add_document(doc).unwrap();
@@ -371,21 +878,23 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(ObjectTypeDefinition {
-                    name: user_name,
-                    implements: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    fields: arena.alloc_vec(),
-                    description: None,
-                }))),
+            definitions: arena.alloc_slice([
+                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(
+                    ObjectTypeDefinition {
+                        name: user_name,
+                        implements: &[],
+                        directives: &[],
+                        fields: &[],
+                        description: None,
+                    },
+                ))),
                 Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Union(UnionTypeDefinition {
                     name: union_name,
-                    directives: arena.alloc_vec(),
-                    members: bumpalo::vec![in arena.allocator(); user_name],
+                    directives: &[],
+                    members: arena.alloc_slice([user_name]),
                     description: None,
                 }))),
-            ],
+            ]),
         });
 
         builder.add_document(doc).unwrap();
@@ -407,19 +916,19 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(ObjectTypeDefinition {
+            definitions: arena.alloc_slice([Definition::TypeSystem(TypeSystemDefinition::Type(
+                TypeDefinition::Object(ObjectTypeDefinition {
                     name: user_name,
-                    implements: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    fields: bumpalo::vec![in arena.allocator();
+                    implements: &[],
+                    directives: &[],
+                    fields: arena.alloc_slice([
                         FieldDefinition {
                             name: FieldName::new(Name::new(arena.intern("id"))),
                             typ: Type::Named(NamedType {
                                 name: TypeName::new(Name::new(arena.intern("ID"))),
                             }),
-                            arguments: arena.alloc_vec(),
-                            directives: arena.alloc_vec(),
+                            arguments: &[],
+                            directives: &[],
                             description: None,
                         },
                         FieldDefinition {
@@ -427,14 +936,14 @@ mod tests {
                             typ: Type::Named(NamedType {
                                 name: TypeName::new(Name::new(arena.intern("String"))),
                             }),
-                            arguments: arena.alloc_vec(),
-                            directives: arena.alloc_vec(),
+                            arguments: &[],
+                            directives: &[],
                             description: None,
-                        }
-                    ],
+                        },
+                    ]),
                     description: None,
-                })))
-            ],
+                }),
+            ))]),
         });
 
         builder.add_document(doc).unwrap();
@@ -457,25 +966,23 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Interface(InterfaceTypeDefinition {
+            definitions: arena.alloc_slice([Definition::TypeSystem(TypeSystemDefinition::Type(
+                TypeDefinition::Interface(InterfaceTypeDefinition {
                     name: node_name,
-                    implements: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    fields: bumpalo::vec![in arena.allocator();
-                        FieldDefinition {
-                            name: FieldName::new(Name::new(arena.intern("id"))),
-                            typ: Type::Named(NamedType {
-                                name: TypeName::new(Name::new(arena.intern("ID"))),
-                            }),
-                            arguments: arena.alloc_vec(),
-                            directives: arena.alloc_vec(),
-                            description: None,
-                        }
-                    ],
+                    implements: &[],
+                    directives: &[],
+                    fields: arena.alloc_slice([FieldDefinition {
+                        name: FieldName::new(Name::new(arena.intern("id"))),
+                        typ: Type::Named(NamedType {
+                            name: TypeName::new(Name::new(arena.intern("ID"))),
+                        }),
+                        arguments: &[],
+                        directives: &[],
+                        description: None,
+                    }]),
                     description: None,
-                })))
-            ],
+                }),
+            ))]),
         });
 
         builder.add_document(doc).unwrap();
@@ -495,13 +1002,13 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Scalar(ScalarTypeDefinition {
+            definitions: arena.alloc_slice([Definition::TypeSystem(TypeSystemDefinition::Type(
+                TypeDefinition::Scalar(ScalarTypeDefinition {
                     name: datetime_name,
-                    directives: arena.alloc_vec(),
+                    directives: &[],
                     description: None,
-                })))
-            ],
+                }),
+            ))]),
         });
 
         builder.add_document(doc).unwrap();
@@ -522,15 +1029,13 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::TypeSystem(TypeSystemDefinition::Schema(SchemaDefinition {
-                    description: None,
-                    query: Some(TypeName::new(Name::new(arena.intern("Query")))),
-                    mutation: Some(TypeName::new(Name::new(arena.intern("Mutation")))),
-                    subscription: Some(TypeName::new(Name::new(arena.intern("Subscription")))),
-                    directives: arena.alloc_vec(),
-                }))
-            ],
+            definitions: arena.alloc_slice([Definition::TypeSystem(TypeSystemDefinition::Schema(SchemaDefinition {
+                description: None,
+                query: Some(TypeName::new(Name::new(arena.intern("Query")))),
+                mutation: Some(TypeName::new(Name::new(arena.intern("Mutation")))),
+                subscription: Some(TypeName::new(Name::new(arena.intern("Subscription")))),
+                directives: &[],
+            }))]),
         });
 
         builder.add_document(doc).unwrap();
@@ -551,22 +1056,26 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(ObjectTypeDefinition {
-                    name: user_name,
-                    implements: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    fields: arena.alloc_vec(),
-                    description: None,
-                }))),
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(ObjectTypeDefinition {
-                    name: user_name,
-                    implements: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    fields: arena.alloc_vec(),
-                    description: None,
-                }))),
-            ],
+            definitions: arena.alloc_slice([
+                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(
+                    ObjectTypeDefinition {
+                        name: user_name,
+                        implements: &[],
+                        directives: &[],
+                        fields: &[],
+                        description: None,
+                    },
+                ))),
+                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(
+                    ObjectTypeDefinition {
+                        name: user_name,
+                        implements: &[],
+                        directives: &[],
+                        fields: &[],
+                        description: None,
+                    },
+                ))),
+            ]),
         });
 
         assert_err!(builder.add_document(doc));
@@ -582,15 +1091,15 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::TypeSystem(TypeSystemDefinition::Directive(DirectiveDefinition {
+            definitions: arena.alloc_slice([Definition::TypeSystem(TypeSystemDefinition::Directive(
+                DirectiveDefinition {
                     name: directive_name,
-                    arguments: arena.alloc_vec(),
-                    locations: bumpalo::vec![in arena.allocator(); ],
+                    arguments: &[],
+                    locations: &[],
                     repeatable: false,
                     description: None,
-                }))
-            ],
+                },
+            ))]),
         });
 
         builder.add_document(doc).unwrap();
@@ -598,4 +1107,378 @@ mod tests {
 
         assert_some!(index.get_directive("custom"));
     }
+
+    #[test]
+    fn test_extend_object_type_adds_fields_and_interfaces() {
+        let arena = Arena::new();
+        let (index, errors) = build_from_sdl(
+            &arena,
+            r#"
+            extend type Query { viewer: User }
+            interface Node { id: ID! }
+            type Query { user: User }
+            type User { id: ID! }
+            extend type User implements Node @deprecated { name: String }
+            "#,
+        );
+
+        assert_is_empty!(errors);
+        assert_some!(index.get_field("Query", "user"));
+        assert_some!(index.get_field("Query", "viewer"));
+        assert_some!(index.get_field("User", "name"));
+        assert!(index.implements("User", "Node"));
+
+        let user = index.get_object_type("User").unwrap();
+        assert_len_eq_x!(&user.fields, 2);
+        assert_len_eq_x!(&user.implements, 1);
+        assert_len_eq_x!(&user.directives, 1);
+    }
+
+    #[test]
+    fn test_extend_interface_type() {
+        let arena = Arena::new();
+        let (index, errors) = build_from_sdl(
+            &arena,
+            r#"
+            interface Node { id: ID! }
+            extend interface Node { createdAt: String }
+            "#,
+        );
+
+        assert_is_empty!(errors);
+        assert_some!(index.get_field("Node", "createdAt"));
+        assert_len_eq_x!(&index.get_interface_type("Node").unwrap().fields, 2);
+    }
+
+    #[test]
+    fn test_extend_union_enum_input_and_scalar() {
+        let arena = Arena::new();
+        let (index, errors) = build_from_sdl(
+            &arena,
+            r#"
+            type User { id: ID! }
+            type Post { id: ID! }
+            union SearchResult = User
+            extend union SearchResult = Post
+            enum Status { ACTIVE }
+            extend enum Status { INACTIVE }
+            input UserInput { name: String }
+            extend input UserInput { email: String }
+            scalar DateTime
+            extend scalar DateTime @specifiedBy(url: "https://example.com")
+            "#,
+        );
+
+        assert_is_empty!(errors);
+
+        let members: Vec<_> = index.get_possible_types("SearchResult").collect();
+        assert_len_eq_x!(&members, 2);
+        assert_contains!(&members, &"Post");
+        assert_len_eq_x!(&index.get_union_type("SearchResult").unwrap().members, 2);
+
+        let status = index.get_enum_type("Status").unwrap();
+        assert!(status.values.iter().any(|v| v.value == "INACTIVE"));
+
+        let input = index.get_input_object_type("UserInput").unwrap();
+        assert!(input.fields.iter().any(|f| f.name == "email"));
+
+        assert_len_eq_x!(&index.get_scalar_type("DateTime").unwrap().directives, 1);
+        assert_eq!(index.custom_scalars(), &["DateTime"]);
+    }
+
+    #[test]
+    fn test_multiple_extensions_of_same_type() {
+        let arena = Arena::new();
+        let (index, errors) = build_from_sdl(
+            &arena,
+            r#"
+            type Query { a: String }
+            extend type Query { b: String }
+            extend type Query { c: String }
+            "#,
+        );
+
+        assert_is_empty!(errors);
+        assert_len_eq_x!(index.get_object_fields("Query").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_extend_schema_root_types() {
+        let arena = Arena::new();
+        let (index, errors) = build_from_sdl(
+            &arena,
+            r#"
+            schema { query: Query }
+            extend schema { mutation: Mutation }
+            type Query { a: String }
+            type Mutation { b: String }
+            "#,
+        );
+
+        assert_is_empty!(errors);
+        assert_some_eq_x!(index.query_type(), "Query");
+        assert_some_eq_x!(index.mutation_type(), "Mutation");
+    }
+
+    #[test]
+    fn test_extend_schema_redefining_root_type() {
+        let arena = Arena::new();
+        let (_, errors) = build_from_sdl(
+            &arena,
+            r#"
+            schema { query: Query }
+            extend schema { query: OtherQuery }
+            "#,
+        );
+
+        assert_len_eq_x!(&errors, 1);
+        assert_contains!(errors[0].message(), "Root query type is already defined");
+    }
+
+    #[test]
+    fn test_extend_unknown_type() {
+        let arena = Arena::new();
+        let (_, errors) = build_from_sdl(&arena, "extend type Missing { a: String }");
+
+        assert_len_eq_x!(&errors, 1);
+        assert_eq!(
+            errors[0].message(),
+            "Cannot extend object type 'Missing' because it is not defined"
+        );
+    }
+
+    #[test]
+    fn test_extend_type_of_different_kind() {
+        let arena = Arena::new();
+        let (_, errors) = build_from_sdl(
+            &arena,
+            r#"
+            enum Status { ACTIVE }
+            extend type Status { a: String }
+            "#,
+        );
+
+        assert_len_eq_x!(&errors, 1);
+        assert_contains!(errors[0].message(), "defined as a different kind of type");
+    }
+
+    #[test]
+    fn test_extend_redefining_existing_field() {
+        let arena = Arena::new();
+        let (index, errors) = build_from_sdl(
+            &arena,
+            r#"
+            type User { id: ID! }
+            extend type User { id: String }
+            extend type User { name: String }
+            "#,
+        );
+
+        assert_len_eq_x!(&errors, 1);
+        assert_eq!(
+            errors[0].message(),
+            "Field 'User.id' already exists and cannot be redefined by an extension"
+        );
+        assert_some!(index.get_field("User", "name"));
+    }
+
+    #[test]
+    fn test_extend_redefining_existing_enum_value() {
+        let arena = Arena::new();
+        let (_, errors) = build_from_sdl(
+            &arena,
+            r#"
+            enum Status { ACTIVE }
+            extend enum Status { ACTIVE }
+            "#,
+        );
+
+        assert_len_eq_x!(&errors, 1);
+        assert_contains!(errors[0].message(), "Enum value 'Status.ACTIVE' already exists");
+    }
+
+    #[test]
+    fn test_interface_implementing_interface() {
+        let arena = Arena::new();
+        let (index, errors) = build_from_sdl(
+            &arena,
+            r#"
+            interface Node { id: ID! }
+            interface Resource implements Node { id: ID! url: String! }
+            type File implements Resource & Node { id: ID! url: String! }
+            type Folder implements Resource { id: ID! url: String! }
+            "#,
+        );
+
+        assert_is_empty!(errors);
+
+        assert!(index.implements("Resource", "Node"));
+        assert!(index.implements("File", "Resource"));
+        assert!(index.implements("File", "Node"));
+        assert!(index.implements("Folder", "Node"));
+        assert!(!index.implements("Node", "Resource"));
+
+        let mut node_types: Vec<_> = index.get_possible_types("Node").collect();
+        node_types.sort_unstable();
+        assert_eq!(node_types, ["File", "Folder"]);
+
+        let mut resource_types: Vec<_> = index.get_possible_types("Resource").collect();
+        resource_types.sort_unstable();
+        assert_eq!(resource_types, ["File", "Folder"]);
+    }
+
+    #[test]
+    fn test_extend_interface_with_implements() {
+        let arena = Arena::new();
+        let (index, errors) = build_from_sdl(
+            &arena,
+            r#"
+            interface Node { id: ID! }
+            interface Resource { id: ID! }
+            type File implements Resource { id: ID! }
+            extend interface Resource implements Node
+            "#,
+        );
+
+        assert_is_empty!(errors);
+        assert!(index.implements("Resource", "Node"));
+        assert!(index.implements("File", "Node"));
+        assert_eq!(index.get_possible_types("Node").collect::<Vec<_>>(), ["File"]);
+    }
+
+    #[test]
+    fn test_entity_key_fields_default_heuristic() {
+        let arena = Arena::new();
+        let index = builder_from_sdl(
+            &arena,
+            r#"
+            schema { query: Query }
+            type Query { user: User }
+            type User { id: ID! }
+            type Post { _id: String!, id: ID }
+            type Tag { uuid: String }
+            "#,
+        )
+        .build();
+
+        assert_eq!(index.entity_key_fields("User"), Some(&["id"][..]));
+        assert_eq!(index.entity_key_fields("Post"), Some(&["_id"][..]));
+        assert_none!(index.entity_key_fields("Tag"));
+        assert_none!(index.entity_key_fields("Query"));
+    }
+
+    #[test]
+    fn test_entity_key_fields_from_key_directive() {
+        let arena = Arena::new();
+        let mut builder = builder_from_sdl(
+            &arena,
+            r#"
+            directive @key(fields: String!) on OBJECT
+            type Issue @key(fields: "orgId number") { id: ID! orgId: ID! number: Int! }
+            "#,
+        );
+        assert_is_empty!(builder.apply_key_fields(&FxHashMap::default()));
+        let index = builder.build();
+
+        assert_eq!(index.entity_key_fields("Issue"), Some(&["orgId", "number"][..]));
+    }
+
+    #[test]
+    fn test_entity_key_fields_from_federation_key_directive() {
+        let arena = Arena::new();
+        let mut builder = builder_from_sdl(
+            &arena,
+            r#"
+            scalar FieldSet
+            directive @key(fields: FieldSet!, resolvable: Boolean = true) repeatable on OBJECT | INTERFACE
+            type Query { issue: Issue }
+            type Issue @key(fields: "orgId number") @key(fields: "id") { id: ID! orgId: ID! number: Int! }
+            interface Node @key(fields: "id") { id: ID! }
+            "#,
+        );
+        assert_is_empty!(builder.apply_key_fields(&FxHashMap::default()));
+        let index = builder.build();
+
+        assert_eq!(index.entity_key_fields("Issue"), Some(&["orgId", "number"][..]));
+        assert_some!(index.get_field("Query", "issue"));
+    }
+
+    #[test]
+    fn test_undeclared_key_directive_is_not_read() {
+        let arena = Arena::new();
+        let mut builder = builder_from_sdl(
+            &arena,
+            r#"
+            type Issue @key(fields: "orgId number") { id: ID! orgId: ID! number: Int! }
+            "#,
+        );
+        assert_is_empty!(builder.apply_key_fields(&FxHashMap::default()));
+        let index = builder.build();
+
+        assert_eq!(index.entity_key_fields("Issue"), Some(&["id"][..]));
+    }
+
+    #[test]
+    fn test_entity_key_fields_from_config() {
+        let arena = Arena::new();
+        let mut builder = builder_from_sdl(
+            &arena,
+            r#"
+            type Article { id: ID! slug: String! }
+            directive @key(fields: String!) on OBJECT
+            type Issue @key(fields: "orgId number") { id: ID! orgId: ID! number: Int! }
+            type SearchHit { id: ID! }
+            "#,
+        );
+
+        let mut key_fields = FxHashMap::default();
+        key_fields.insert("Article".to_string(), vec!["slug".to_string()]);
+        key_fields.insert("Issue".to_string(), vec!["id".to_string()]);
+        key_fields.insert("SearchHit".to_string(), vec![]);
+        assert_is_empty!(builder.apply_key_fields(&key_fields));
+        let index = builder.build();
+
+        assert_eq!(index.entity_key_fields("Article"), Some(&["slug"][..]));
+        assert_eq!(index.entity_key_fields("Issue"), Some(&["id"][..]));
+        assert_none!(index.entity_key_fields("SearchHit"));
+    }
+
+    #[test]
+    fn test_entity_key_fields_errors() {
+        let arena = Arena::new();
+        let mut builder = builder_from_sdl(
+            &arena,
+            r#"
+            directive @key(fields: String!) on OBJECT
+            type Issue @key(fields: "orgId missing") { id: ID! orgId: ID! }
+            type User { id: ID! tags: [String!]! profile: Profile }
+            type Profile { bio: String }
+            enum Status { ACTIVE }
+            "#,
+        );
+
+        let mut key_fields = FxHashMap::default();
+        key_fields.insert("Status".to_string(), vec!["id".to_string()]);
+        key_fields.insert("User".to_string(), vec!["tags".to_string()]);
+        key_fields.insert("Profile".to_string(), vec!["bio".to_string(), "profile".to_string()]);
+        let errors: Vec<_> = builder
+            .apply_key_fields(&key_fields)
+            .iter()
+            .map(|e| e.message().to_string())
+            .collect();
+
+        assert_eq!(
+            errors,
+            [
+                "Key field 'missing' is not defined on type 'Issue'",
+                "Key field 'profile' is not defined on type 'Profile'",
+                "Cannot configure key fields for 'Status' because it is not an object type",
+                "Key field 'User.tags' must be a scalar or enum field",
+            ]
+        );
+
+        let index = builder.build();
+        assert_none!(index.entity_key_fields("Issue"));
+        assert_eq!(index.entity_key_fields("User"), Some(&["id"][..]));
+    }
 }
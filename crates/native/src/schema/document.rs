@@ -1,4 +1,4 @@
-use crate::error::{MearieError, Result};
+use crate::error::{ErrorCode, Location, MearieError, Result};
 use crate::graphql::ast::*;
 use rustc_hash::FxHashMap;
 
@@ -41,9 +41,15 @@ pub struct DocumentIndex<'a> {
     operations: Vec<&'a OperationDefinition<'a>>,
     operations_by_name: FxHashMap<Option<&'a str>, &'a OperationDefinition<'a>>,
     fragments: FxHashMap<&'a str, &'a FragmentDefinition<'a>>,
-    operation_to_document: FxHashMap<*const OperationDefinition<'a>, *const Document<'a>>,
-    fragment_to_document: FxHashMap<*const FragmentDefinition<'a>, *const Document<'a>>,
-    document_transforms: FxHashMap<*const Document<'a>, &'a Document<'a>>,
+    operation_to_document: FxHashMap<usize, &'a Document<'a>>,
+    fragment_to_document: FxHashMap<usize, &'a Document<'a>>,
+    document_transforms: FxHashMap<usize, &'a Document<'a>>,
+}
+
+/// Identifies a node by its address, which is stable for the lifetime of the arena.
+#[inline]
+fn address<T>(node: &T) -> usize {
+    std::ptr::from_ref(node).addr()
 }
 
 impl<'a> DocumentIndex<'a> {
@@ -63,16 +69,15 @@ impl<'a> DocumentIndex<'a> {
     pub fn add_document(&mut self, doc: &'a Document<'a>) -> Result<()> {
         self.documents.push(doc);
 
-        for definition in &doc.definitions {
+        for definition in doc.definitions {
             match definition {
                 Definition::Executable(ExecutableDefinition::Fragment(fragment)) => {
-                    self.register_fragment(fragment)?;
-                    self.fragment_to_document.insert(fragment as *const _, doc as *const _);
+                    self.register_fragment(doc, fragment)?;
+                    self.fragment_to_document.insert(address(fragment), doc);
                 }
                 Definition::Executable(ExecutableDefinition::Operation(operation)) => {
                     self.register_operation(operation);
-                    self.operation_to_document
-                        .insert(operation as *const _, doc as *const _);
+                    self.operation_to_document.insert(address(operation), doc);
                 }
                 Definition::TypeSystem(_) | Definition::TypeSystemExtension(_) => {}
             }
@@ -81,14 +86,20 @@ impl<'a> DocumentIndex<'a> {
         Ok(())
     }
 
-    fn register_fragment(&mut self, fragment: &'a FragmentDefinition<'a>) -> Result<()> {
+    fn register_fragment(&mut self, doc: &'a Document<'a>, fragment: &'a FragmentDefinition<'a>) -> Result<()> {
         let fragment_name = fragment.name.as_str();
 
-        if self.fragments.contains_key(fragment_name) {
-            return Err(MearieError::validation(format!(
-                "Duplicate fragment definition: {}",
-                fragment_name
-            )));
+        if let Some(&existing) = self.fragments.get(fragment_name) {
+            let mut error = MearieError::validation(format!("Duplicate fragment definition: {}", fragment_name))
+                .with_code(ErrorCode::DuplicateFragmentName)
+                .at(Location::from_span(doc.source, fragment.name.span()));
+            if let Some(existing_doc) = self.get_fragment_document(existing) {
+                error = error.with_related(
+                    format!("Fragment '{}' is first defined here", fragment_name),
+                    Location::from_span(existing_doc.source, existing.name.span()),
+                );
+            }
+            return Err(error);
         }
 
         self.fragments.insert(fragment_name, fragment);
@@ -208,7 +219,7 @@ impl<'a> DocumentIndex<'a> {
     /// When source code is requested for operations or fragments in the original document,
     /// the transformed document's source will be returned instead.
     pub fn set_transformed_document(&mut self, original: &'a Document<'a>, transformed: &'a Document<'a>) {
-        self.document_transforms.insert(original as *const _, transformed);
+        self.document_transforms.insert(address(original), transformed);
     }
 
     /// Gets the original source code for an operation definition.
@@ -228,8 +239,7 @@ impl<'a> DocumentIndex<'a> {
     /// }
     /// ```
     pub fn get_operation_source(&self, operation: &OperationDefinition<'a>) -> Option<&'a str> {
-        let doc_ptr = self.operation_to_document.get(&(operation as *const _))?;
-        let original_doc = unsafe { &**doc_ptr };
+        let original_doc = self.operation_to_document.get(&address(operation))?;
         Some(original_doc.source.code)
     }
 
@@ -242,14 +252,12 @@ impl<'a> DocumentIndex<'a> {
     ///
     /// O(1) - uses hash map lookups with pointer equality
     pub fn get_transformed_operation_source(&self, operation: &OperationDefinition<'a>) -> Option<&'a str> {
-        let doc_ptr = self.operation_to_document.get(&(operation as *const _))?;
-
-        if let Some(&transformed_doc) = self.document_transforms.get(doc_ptr) {
-            return Some(transformed_doc.source.code);
-        }
-
-        let original_doc = unsafe { &**doc_ptr };
-        Some(original_doc.source.code)
+        let original_doc = self.operation_to_document.get(&address(operation))?;
+        let document = self
+            .document_transforms
+            .get(&address(*original_doc))
+            .unwrap_or(original_doc);
+        Some(document.source.code)
     }
 
     /// Gets the original source code for a fragment definition.
@@ -269,11 +277,15 @@ impl<'a> DocumentIndex<'a> {
     /// }
     /// ```
     pub fn get_fragment_source(&self, fragment: &FragmentDefinition<'a>) -> Option<&'a str> {
-        let doc_ptr = self.fragment_to_document.get(&(fragment as *const _))?;
-        let original_doc = unsafe { &**doc_ptr };
+        let original_doc = self.fragment_to_document.get(&address(fragment))?;
         Some(original_doc.source.code)
     }
 
+    /// Gets the document a fragment definition was parsed from.
+    pub fn get_fragment_document(&self, fragment: &FragmentDefinition<'a>) -> Option<&'a Document<'a>> {
+        self.fragment_to_document.get(&address(fragment)).copied()
+    }
+
     /// Gets the transformed source code for a fragment definition.
     ///
     /// Returns the transformed GraphQL source string if the document has been transformed,
@@ -283,14 +295,12 @@ impl<'a> DocumentIndex<'a> {
     ///
     /// O(1) - uses hash map lookups with pointer equality
     pub fn get_transformed_fragment_source(&self, fragment: &FragmentDefinition<'a>) -> Option<&'a str> {
-        let doc_ptr = self.fragment_to_document.get(&(fragment as *const _))?;
-
-        if let Some(&transformed_doc) = self.document_transforms.get(doc_ptr) {
-            return Some(transformed_doc.source.code);
-        }
-
-        let original_doc = unsafe { &**doc_ptr };
-        Some(original_doc.source.code)
+        let original_doc = self.fragment_to_document.get(&address(fragment))?;
+        let document = self
+            .document_transforms
+            .get(&address(*original_doc))
+            .unwrap_or(original_doc);
+        Some(document.source.code)
     }
 }
 
@@ -328,18 +338,18 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::Executable(ExecutableDefinition::Fragment(FragmentDefinition {
+            definitions: arena.alloc_slice([Definition::Executable(ExecutableDefinition::Fragment(
+                FragmentDefinition {
                     span: Span::empty(),
                     name: fragment_name,
 
-                    variable_definitions: arena.alloc_vec(),
+                    variable_definitions: &[],
 
                     type_condition: TypeName::new(Name::new(arena.intern("User"))),
-                    directives: arena.alloc_vec(),
-                    selection_set: SelectionSet { selections: bumpalo::vec![in arena.allocator(); ] },
-                }))
-            ],
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
+                },
+            ))]),
         });
 
         index.add_document(doc).unwrap();
@@ -359,18 +369,18 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::Executable(ExecutableDefinition::Fragment(FragmentDefinition {
+            definitions: arena.alloc_slice([Definition::Executable(ExecutableDefinition::Fragment(
+                FragmentDefinition {
                     span: Span::empty(),
                     name: fragment_name,
 
-                    variable_definitions: arena.alloc_vec(),
+                    variable_definitions: &[],
 
                     type_condition: TypeName::new(Name::new(arena.intern("User"))),
-                    directives: arena.alloc_vec(),
-                    selection_set: SelectionSet { selections: bumpalo::vec![in arena.allocator(); ] },
-                }))
-            ],
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
+                },
+            ))]),
         });
 
         index.add_document(doc).unwrap();
@@ -389,28 +399,28 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
+            definitions: arena.alloc_slice([
                 Definition::Executable(ExecutableDefinition::Fragment(FragmentDefinition {
                     span: Span::empty(),
                     name: fragment_name,
 
-                    variable_definitions: arena.alloc_vec(),
+                    variable_definitions: &[],
 
                     type_condition: TypeName::new(Name::new(arena.intern("User"))),
-                    directives: arena.alloc_vec(),
-                    selection_set: SelectionSet { selections: bumpalo::vec![in arena.allocator(); ] },
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
                 })),
                 Definition::Executable(ExecutableDefinition::Fragment(FragmentDefinition {
                     span: Span::empty(),
                     name: fragment_name,
 
-                    variable_definitions: arena.alloc_vec(),
+                    variable_definitions: &[],
 
                     type_condition: TypeName::new(Name::new(arena.intern("User"))),
-                    directives: arena.alloc_vec(),
-                    selection_set: SelectionSet { selections: bumpalo::vec![in arena.allocator(); ] },
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
                 })),
-            ],
+            ]),
         });
 
         let result = index.add_document(doc);
@@ -427,16 +437,16 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::Executable(ExecutableDefinition::Operation(OperationDefinition {
+            definitions: arena.alloc_slice([Definition::Executable(ExecutableDefinition::Operation(
+                OperationDefinition {
                     span: Span::empty(),
                     operation_type: OperationType::Query,
                     name: Some(operation_name),
-                    variable_definitions: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    selection_set: SelectionSet { selections: bumpalo::vec![in arena.allocator(); ] },
-                }))
-            ],
+                    variable_definitions: &[],
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
+                },
+            ))]),
         });
 
         index.add_document(doc).unwrap();
@@ -453,16 +463,16 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::Executable(ExecutableDefinition::Operation(OperationDefinition {
+            definitions: arena.alloc_slice([Definition::Executable(ExecutableDefinition::Operation(
+                OperationDefinition {
                     span: Span::empty(),
                     operation_type: OperationType::Query,
                     name: None,
-                    variable_definitions: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    selection_set: SelectionSet { selections: bumpalo::vec![in arena.allocator(); ] },
-                }))
-            ],
+                    variable_definitions: &[],
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
+                },
+            ))]),
         });
 
         index.add_document(doc).unwrap();
@@ -481,16 +491,16 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::Executable(ExecutableDefinition::Operation(OperationDefinition {
+            definitions: arena.alloc_slice([Definition::Executable(ExecutableDefinition::Operation(
+                OperationDefinition {
                     span: Span::empty(),
                     operation_type: OperationType::Query,
                     name: Some(operation_name),
-                    variable_definitions: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    selection_set: SelectionSet { selections: bumpalo::vec![in arena.allocator(); ] },
-                }))
-            ],
+                    variable_definitions: &[],
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
+                },
+            ))]),
         });
 
         index.add_document(doc).unwrap();
@@ -512,16 +522,18 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator(); Definition::Executable(ExecutableDefinition::Fragment(FragmentDefinition {
-                span: Span::empty(),
-                name: FragmentName::new(Name::new(arena.intern("UserFragment"))),
+            definitions: arena.alloc_slice([Definition::Executable(ExecutableDefinition::Fragment(
+                FragmentDefinition {
+                    span: Span::empty(),
+                    name: FragmentName::new(Name::new(arena.intern("UserFragment"))),
 
-                variable_definitions: arena.alloc_vec(),
+                    variable_definitions: &[],
 
-                type_condition: TypeName::new(Name::new(arena.intern("User"))),
-                directives: arena.alloc_vec(),
-                selection_set: SelectionSet { selections: bumpalo::vec![in arena.allocator(); ] },
-            }))],
+                    type_condition: TypeName::new(Name::new(arena.intern("User"))),
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
+                },
+            ))]),
         });
 
         index.add_document(doc).unwrap();
@@ -538,28 +550,28 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
+            definitions: arena.alloc_slice([
                 Definition::Executable(ExecutableDefinition::Fragment(FragmentDefinition {
                     span: Span::empty(),
                     name: FragmentName::new(Name::new(arena.intern("Fragment1"))),
 
-                    variable_definitions: arena.alloc_vec(),
+                    variable_definitions: &[],
 
                     type_condition: TypeName::new(Name::new(arena.intern("User"))),
-                    directives: arena.alloc_vec(),
-                    selection_set: SelectionSet { selections: bumpalo::vec![in arena.allocator(); ] },
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
                 })),
                 Definition::Executable(ExecutableDefinition::Fragment(FragmentDefinition {
                     span: Span::empty(),
                     name: FragmentName::new(Name::new(arena.intern("Fragment2"))),
 
-                    variable_definitions: arena.alloc_vec(),
+                    variable_definitions: &[],
 
                     type_condition: TypeName::new(Name::new(arena.intern("Post"))),
-                    directives: arena.alloc_vec(),
-                    selection_set: SelectionSet { selections: bumpalo::vec![in arena.allocator(); ] },
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
                 })),
-            ],
+            ]),
         });
 
         index.add_document(doc).unwrap();
@@ -575,24 +587,24 @@ mod tests {
         let source = Source::ephemeral("");
         let doc = arena.alloc(Document {
             source: &source,
-            definitions: bumpalo::vec![in arena.allocator();
+            definitions: arena.alloc_slice([
                 Definition::Executable(ExecutableDefinition::Operation(OperationDefinition {
                     span: Span::empty(),
                     operation_type: OperationType::Query,
                     name: Some(Name::new(arena.intern("GetUser"))),
-                    variable_definitions: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    selection_set: SelectionSet { selections: bumpalo::vec![in arena.allocator(); ] },
+                    variable_definitions: &[],
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
                 })),
                 Definition::Executable(ExecutableDefinition::Operation(OperationDefinition {
                     span: Span::empty(),
                     operation_type: OperationType::Mutation,
                     name: Some(Name::new(arena.intern("CreateUser"))),
-                    variable_definitions: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    selection_set: SelectionSet { selections: bumpalo::vec![in arena.allocator(); ] },
+                    variable_definitions: &[],
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
                 })),
-            ],
+            ]),
         });
 
         index.add_document(doc).unwrap();
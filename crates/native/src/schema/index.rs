@@ -1,4 +1,5 @@
 use crate::graphql::ast::*;
+use crate::source::Source;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 /// Fast O(1) schema index for GraphQL type lookups.
@@ -47,6 +48,9 @@ pub struct SchemaIndex<'a> {
     interface_implementors: FxHashMap<&'a str, FxHashSet<&'a str>>,
     union_members: FxHashMap<&'a str, FxHashSet<&'a str>>,
     directives: FxHashMap<&'a str, &'a DirectiveDefinition<'a>>,
+    type_sources: FxHashMap<&'a str, &'a Source<'a>>,
+    directive_sources: FxHashMap<&'a str, &'a Source<'a>>,
+    entity_keys: FxHashMap<&'a str, Vec<&'a str>>,
     custom_scalars: Vec<&'a str>,
     query_type: Option<&'a str>,
     mutation_type: Option<&'a str>,
@@ -73,6 +77,20 @@ pub enum TypeInfo<'a> {
     InputObject(&'a InputObjectTypeDefinition<'a>),
 }
 
+impl<'a> TypeInfo<'a> {
+    /// Returns the name of the type.
+    pub fn name(self) -> TypeName<'a> {
+        match self {
+            Self::Object(obj) => obj.name,
+            Self::Interface(iface) => iface.name,
+            Self::Union(union) => union.name,
+            Self::Enum(enum_type) => enum_type.name,
+            Self::Scalar(scalar) => scalar.name,
+            Self::InputObject(input) => input.name,
+        }
+    }
+}
+
 impl<'a> SchemaIndex<'a> {
     #[allow(clippy::too_many_arguments)]
     pub(super) fn new(
@@ -81,6 +99,9 @@ impl<'a> SchemaIndex<'a> {
         interface_implementors: FxHashMap<&'a str, FxHashSet<&'a str>>,
         union_members: FxHashMap<&'a str, FxHashSet<&'a str>>,
         directives: FxHashMap<&'a str, &'a DirectiveDefinition<'a>>,
+        type_sources: FxHashMap<&'a str, &'a Source<'a>>,
+        directive_sources: FxHashMap<&'a str, &'a Source<'a>>,
+        entity_keys: FxHashMap<&'a str, Vec<&'a str>>,
         custom_scalars: Vec<&'a str>,
         query_type: Option<&'a str>,
         mutation_type: Option<&'a str>,
@@ -92,6 +113,9 @@ impl<'a> SchemaIndex<'a> {
             interface_implementors,
             union_members,
             directives,
+            type_sources,
+            directive_sources,
+            entity_keys,
             custom_scalars,
             query_type,
             mutation_type,
@@ -230,13 +254,16 @@ impl<'a> SchemaIndex<'a> {
 
     /// Gets possible concrete types for an interface or union.
     ///
-    /// Returns a pre-computed set of type names that either:
-    /// - Implement the given interface
+    /// Returns the object type names that either:
+    /// - Implement the given interface, directly or through an intermediate interface
     /// - Are members of the given union
     ///
+    /// Interfaces implementing the given interface are not possible types themselves
+    /// and are excluded.
+    ///
     /// # Time Complexity
     ///
-    /// O(1) - returns pre-computed set
+    /// O(n) where n is the number of implementors - iterates a pre-computed set
     ///
     /// # Example
     ///
@@ -256,10 +283,32 @@ impl<'a> SchemaIndex<'a> {
             .or_else(|| self.union_members.get(type_name))
             .into_iter()
             .flat_map(|set| set.iter().copied())
+            .filter(|name| !self.is_interface(name))
+    }
+
+    /// Checks if `type_name` is a possible type of `abstract_type`.
+    ///
+    /// A type is always a possible type of itself; otherwise this checks
+    /// interface implementations (transitively) and union membership.
+    ///
+    /// # Time Complexity
+    ///
+    /// O(1) - uses hash map and hash set lookups
+    pub fn is_possible_type(&self, abstract_type: &str, type_name: &str) -> bool {
+        type_name == abstract_type
+            || self.implements(type_name, abstract_type)
+            || self
+                .union_members
+                .get(abstract_type)
+                .is_some_and(|members| members.contains(type_name))
     }
 
     /// Checks if a type implements an interface.
     ///
+    /// Both object and interface types are considered, including interfaces
+    /// implemented transitively (e.g. `File implements Resource` where
+    /// `Resource implements Node` makes `File` implement `Node`).
+    ///
     /// # Time Complexity
     ///
     /// O(1) - uses hash map and hash set lookups
@@ -290,6 +339,28 @@ impl<'a> SchemaIndex<'a> {
         self.directives.values().copied()
     }
 
+    /// Gets the source of the document that defines a type.
+    pub fn type_source(&self, name: &str) -> Option<&'a Source<'a>> {
+        self.type_sources.get(name).copied()
+    }
+
+    /// Gets the source of the document that defines a directive.
+    pub fn directive_source(&self, name: &str) -> Option<&'a Source<'a>> {
+        self.directive_sources.get(name).copied()
+    }
+
+    /// Gets the fields identifying an entity type in the normalized cache.
+    ///
+    /// Returns `None` for types that are not normalized: non-object types, root
+    /// operation types, types without a key and types opted out via configuration.
+    ///
+    /// # Time Complexity
+    ///
+    /// O(1)
+    pub fn entity_key_fields(&self, type_name: &str) -> Option<&[&'a str]> {
+        self.entity_keys.get(type_name).map(Vec::as_slice)
+    }
+
     pub fn custom_scalars(&self) -> &[&'a str] {
         &self.custom_scalars
     }
@@ -358,9 +429,9 @@ mod tests {
         let obj_name = TypeName::new(Name::new(arena.intern("User")));
         let obj_def = arena.alloc(ObjectTypeDefinition {
             name: obj_name,
-            implements: arena.alloc_vec(),
-            directives: arena.alloc_vec(),
-            fields: arena.alloc_vec(),
+            implements: &[],
+            directives: &[],
+            fields: &[],
             description: None,
         });
         types.insert(obj_name.as_str(), TypeInfo::Object(obj_def));
@@ -368,9 +439,9 @@ mod tests {
         let iface_name = TypeName::new(Name::new(arena.intern("Node")));
         let iface_def = arena.alloc(InterfaceTypeDefinition {
             name: iface_name,
-            implements: arena.alloc_vec(),
-            directives: arena.alloc_vec(),
-            fields: arena.alloc_vec(),
+            implements: &[],
+            directives: &[],
+            fields: &[],
             description: None,
         });
         types.insert(iface_name.as_str(), TypeInfo::Interface(iface_def));
@@ -382,8 +453,8 @@ mod tests {
         let union_name = TypeName::new(Name::new(arena.intern("SearchResult")));
         let union_def = arena.alloc(UnionTypeDefinition {
             name: union_name,
-            directives: arena.alloc_vec(),
-            members: bumpalo::vec![in arena.allocator(); obj_name],
+            directives: &[],
+            members: arena.alloc_slice([obj_name]),
             description: None,
         });
         types.insert(union_name.as_str(), TypeInfo::Union(union_def));
@@ -394,8 +465,8 @@ mod tests {
         let enum_name = TypeName::new(Name::new(arena.intern("Status")));
         let enum_def = arena.alloc(EnumTypeDefinition {
             name: enum_name,
-            directives: arena.alloc_vec(),
-            values: arena.alloc_vec(),
+            directives: &[],
+            values: &[],
             description: None,
         });
         types.insert(enum_name.as_str(), TypeInfo::Enum(enum_def));
@@ -403,7 +474,7 @@ mod tests {
         let scalar_name = TypeName::new(Name::new(arena.intern("DateTime")));
         let scalar_def = arena.alloc(ScalarTypeDefinition {
             name: scalar_name,
-            directives: arena.alloc_vec(),
+            directives: &[],
             description: None,
         });
         types.insert(scalar_name.as_str(), TypeInfo::Scalar(scalar_def));
@@ -411,8 +482,8 @@ mod tests {
         let input_name = TypeName::new(Name::new(arena.intern("UserInput")));
         let input_def = arena.alloc(InputObjectTypeDefinition {
             name: input_name,
-            directives: arena.alloc_vec(),
-            fields: arena.alloc_vec(),
+            directives: &[],
+            fields: &[],
             description: None,
         });
         types.insert(input_name.as_str(), TypeInfo::InputObject(input_def));
@@ -422,8 +493,8 @@ mod tests {
             typ: Type::Named(NamedType {
                 name: TypeName::new(Name::new(arena.intern("ID"))),
             }),
-            arguments: arena.alloc_vec(),
-            directives: arena.alloc_vec(),
+            arguments: &[],
+            directives: &[],
             description: None,
         });
         let mut obj_fields = FxHashMap::default();
@@ -441,6 +512,9 @@ mod tests {
             FxHashMap::default(),
             FxHashMap::default(),
             FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
             Vec::new(),
             None,
             None,
@@ -462,6 +536,9 @@ mod tests {
             interface_implementors,
             union_members,
             FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
             Vec::new(),
             None,
             None,
@@ -487,6 +564,9 @@ mod tests {
             interface_implementors,
             union_members,
             FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
             Vec::new(),
             None,
             None,
@@ -522,6 +602,9 @@ mod tests {
             interface_implementors,
             union_members,
             FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
             Vec::new(),
             None,
             None,
@@ -546,6 +629,9 @@ mod tests {
             interface_implementors,
             union_members,
             FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
             Vec::new(),
             None,
             None,
@@ -569,6 +655,9 @@ mod tests {
             interface_implementors,
             union_members,
             FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
             Vec::new(),
             None,
             None,
@@ -593,6 +682,9 @@ mod tests {
             interface_implementors,
             union_members,
             FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
             Vec::new(),
             None,
             None,
@@ -614,6 +706,9 @@ mod tests {
             interface_implementors,
             union_members,
             FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
             Vec::new(),
             None,
             None,
@@ -636,6 +731,9 @@ mod tests {
             interface_implementors,
             union_members,
             FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
             custom_scalars,
             None,
             None,
@@ -653,8 +751,8 @@ mod tests {
         let directive_name = DirectiveName::new(Name::new(arena.intern("deprecated")));
         let directive_def = arena.alloc(DirectiveDefinition {
             name: directive_name,
-            arguments: arena.alloc_vec(),
-            locations: arena.alloc_vec(),
+            arguments: &[],
+            locations: &[],
             repeatable: false,
             description: None,
         });
@@ -668,6 +766,9 @@ mod tests {
             FxHashMap::default(),
             FxHashMap::default(),
             directives,
+            FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
             Vec::new(),
             None,
             None,
@@ -686,6 +787,9 @@ mod tests {
             FxHashMap::default(),
             FxHashMap::default(),
             FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
             Vec::new(),
             Some("Query"),
             Some("Mutation"),
@@ -707,6 +811,9 @@ mod tests {
             interface_implementors,
             union_members,
             FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
             Vec::new(),
             None,
             None,
@@ -728,6 +835,9 @@ mod tests {
             interface_implementors,
             union_members,
             FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
             Vec::new(),
             None,
             None,
@@ -0,0 +1,791 @@
+use crate::arena::Arena;
+use crate::error::location::Span;
+use crate::error::{ErrorCode, Location, MearieError, Result};
+use crate::graphql::ast::*;
+use crate::graphql::parser::Parser;
+use crate::source::Source;
+use bumpalo::collections::Vec;
+use serde::Deserialize;
+
+const BUILTIN_SCALARS: &[&str] = &["Int", "Float", "String", "Boolean", "ID"];
+const BUILTIN_DIRECTIVES: &[&str] = &["skip", "include", "deprecated", "specifiedBy"];
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IntrospectionResult {
+    Response { data: IntrospectionData },
+    Data(IntrospectionData),
+}
+
+#[derive(Deserialize)]
+struct IntrospectionData {
+    #[serde(rename = "__schema")]
+    schema: IntrospectionSchema,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionSchema {
+    #[serde(default)]
+    description: Option<String>,
+    query_type: Option<NamedTypeRef>,
+    #[serde(default)]
+    mutation_type: Option<NamedTypeRef>,
+    #[serde(default)]
+    subscription_type: Option<NamedTypeRef>,
+    types: std::vec::Vec<IntrospectionType>,
+    #[serde(default)]
+    directives: std::vec::Vec<IntrospectionDirective>,
+}
+
+#[derive(Deserialize)]
+struct NamedTypeRef {
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionType {
+    kind: String,
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default, rename = "specifiedByURL", alias = "specifiedByUrl")]
+    specified_by_url: Option<String>,
+    #[serde(default)]
+    fields: Option<std::vec::Vec<IntrospectionField>>,
+    #[serde(default)]
+    input_fields: Option<std::vec::Vec<IntrospectionInputValue>>,
+    #[serde(default)]
+    interfaces: Option<std::vec::Vec<NamedTypeRef>>,
+    #[serde(default)]
+    enum_values: Option<std::vec::Vec<IntrospectionEnumValue>>,
+    #[serde(default)]
+    possible_types: Option<std::vec::Vec<NamedTypeRef>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionField {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    args: std::vec::Vec<IntrospectionInputValue>,
+    #[serde(rename = "type")]
+    typ: IntrospectionTypeRef,
+    #[serde(default)]
+    is_deprecated: bool,
+    #[serde(default)]
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionInputValue {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(rename = "type")]
+    typ: IntrospectionTypeRef,
+    #[serde(default)]
+    default_value: Option<String>,
+    #[serde(default)]
+    is_deprecated: bool,
+    #[serde(default)]
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionEnumValue {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    is_deprecated: bool,
+    #[serde(default)]
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionTypeRef {
+    kind: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    of_type: Option<Box<IntrospectionTypeRef>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionDirective {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    locations: std::vec::Vec<String>,
+    #[serde(default)]
+    args: std::vec::Vec<IntrospectionInputValue>,
+    #[serde(default)]
+    is_repeatable: bool,
+}
+
+/// Converts a GraphQL introspection result into a type system [`Document`].
+///
+/// Accepts both a full response (`{"data": {"__schema": ...}}`) and a bare
+/// `{"__schema": ...}` object. The resulting document contains only type system
+/// definitions and can be passed to [`SchemaBuilder::add_document`](crate::schema::SchemaBuilder::add_document)
+/// like a parsed SDL document.
+///
+/// Introspection types (`__Type`, ...), built-in scalars and built-in directives
+/// are skipped since they are provided by [`BUILTIN_SCHEMA`](crate::schema::BUILTIN_SCHEMA).
+/// Deprecations become `@deprecated` directives and `specifiedByURL` becomes `@specifiedBy`.
+///
+/// # Errors
+///
+/// Returns an error if the source is not a valid introspection result, references
+/// an unknown type kind or directive location, or contains an unparsable default value.
+pub fn parse_introspection<'a>(arena: &'a Arena, source: &'a Source<'a>) -> Result<&'a Document<'a>> {
+    let result: IntrospectionResult = serde_json::from_str(source.code).map_err(|e| {
+        let line = e.line().max(1) as u32;
+        let column = if line == 1 {
+            (source.start_column + e.column() as u32).saturating_sub(1)
+        } else {
+            e.column() as u32
+        };
+
+        MearieError::parse(format!("Invalid introspection result: {}", e))
+            .with_code(ErrorCode::InvalidIntrospection)
+            .at(Location {
+                file_path: source.file_path.to_string(),
+                line: source.start_line + line - 1,
+                column: Some(column),
+                end_line: None,
+                end_column: None,
+            })
+    })?;
+
+    let schema = match result {
+        IntrospectionResult::Response { data } => data.schema,
+        IntrospectionResult::Data(data) => data.schema,
+    };
+
+    let converter = IntrospectionConverter { arena, source };
+    let mut definitions = arena.alloc_vec();
+
+    for typ in &schema.types {
+        if typ.name.starts_with("__") || BUILTIN_SCALARS.contains(&typ.name.as_str()) {
+            continue;
+        }
+        definitions.push(Definition::TypeSystem(TypeSystemDefinition::Type(
+            converter.convert_type(typ)?,
+        )));
+    }
+
+    for directive in &schema.directives {
+        if BUILTIN_DIRECTIVES.contains(&directive.name.as_str()) {
+            continue;
+        }
+        definitions.push(Definition::TypeSystem(TypeSystemDefinition::Directive(
+            converter.convert_directive(directive)?,
+        )));
+    }
+
+    definitions.push(Definition::TypeSystem(TypeSystemDefinition::Schema(SchemaDefinition {
+        description: converter.description(&schema.description),
+        directives: &[],
+        query: schema.query_type.as_ref().map(|t| converter.type_name(&t.name)),
+        mutation: schema.mutation_type.as_ref().map(|t| converter.type_name(&t.name)),
+        subscription: schema.subscription_type.as_ref().map(|t| converter.type_name(&t.name)),
+    })));
+
+    Ok(arena.alloc(Document {
+        source,
+        definitions: definitions.into_bump_slice(),
+    }))
+}
+
+struct IntrospectionConverter<'a> {
+    arena: &'a Arena,
+    source: &'a Source<'a>,
+}
+
+impl<'a> IntrospectionConverter<'a> {
+    fn error(&self, message: String) -> MearieError {
+        MearieError::parse(message)
+            .with_code(ErrorCode::InvalidIntrospection)
+            .at(Location {
+                file_path: self.source.file_path.to_string(),
+                line: self.source.start_line,
+                column: None,
+                end_line: None,
+                end_column: None,
+            })
+    }
+
+    fn name(&self, name: &str) -> Name<'a> {
+        Name::new(self.arena.intern(name))
+    }
+
+    fn type_name(&self, name: &str) -> TypeName<'a> {
+        TypeName::new(self.name(name))
+    }
+
+    fn description(&self, description: &Option<String>) -> Option<Description<'a>> {
+        description.as_deref().map(|value| Description {
+            value: self.arena.allocator().alloc_str(value),
+        })
+    }
+
+    fn convert_type(&self, typ: &IntrospectionType) -> Result<TypeDefinition<'a>> {
+        let name = self.type_name(&typ.name);
+        let description = self.description(&typ.description);
+
+        let definition = match typ.kind.as_str() {
+            "SCALAR" => {
+                let mut directives = self.arena.alloc_vec();
+                if let Some(url) = &typ.specified_by_url {
+                    directives.push(self.directive("specifiedBy", "url", url));
+                }
+                TypeDefinition::Scalar(ScalarTypeDefinition {
+                    description,
+                    name,
+                    directives: directives.into_bump_slice(),
+                })
+            }
+            "OBJECT" => TypeDefinition::Object(ObjectTypeDefinition {
+                description,
+                name,
+                implements: self.type_names(&typ.interfaces).into_bump_slice(),
+                directives: &[],
+                fields: self.convert_fields(&typ.fields)?.into_bump_slice(),
+            }),
+            "INTERFACE" => TypeDefinition::Interface(InterfaceTypeDefinition {
+                description,
+                name,
+                implements: self.type_names(&typ.interfaces).into_bump_slice(),
+                directives: &[],
+                fields: self.convert_fields(&typ.fields)?.into_bump_slice(),
+            }),
+            "UNION" => TypeDefinition::Union(UnionTypeDefinition {
+                description,
+                name,
+                directives: &[],
+                members: self.type_names(&typ.possible_types).into_bump_slice(),
+            }),
+            "ENUM" => {
+                let mut values = self.arena.alloc_vec();
+                for value in typ.enum_values.iter().flatten() {
+                    values.push(EnumValueDefinition {
+                        description: self.description(&value.description),
+                        value: self.name(&value.name),
+                        directives: self
+                            .deprecation(value.is_deprecated, &value.deprecation_reason)
+                            .into_bump_slice(),
+                    });
+                }
+                TypeDefinition::Enum(EnumTypeDefinition {
+                    description,
+                    name,
+                    directives: &[],
+                    values: values.into_bump_slice(),
+                })
+            }
+            "INPUT_OBJECT" => {
+                let mut fields = self.arena.alloc_vec();
+                for field in typ.input_fields.iter().flatten() {
+                    fields.push(self.convert_input_value(field)?);
+                }
+                TypeDefinition::InputObject(InputObjectTypeDefinition {
+                    description,
+                    name,
+                    directives: &[],
+                    fields: fields.into_bump_slice(),
+                })
+            }
+            kind => {
+                return Err(self.error(format!(
+                    "Unknown type kind '{}' for type '{}' in introspection result",
+                    kind, typ.name
+                )));
+            }
+        };
+
+        Ok(definition)
+    }
+
+    fn type_names(&self, types: &Option<std::vec::Vec<NamedTypeRef>>) -> Vec<'a, TypeName<'a>> {
+        let mut names = self.arena.alloc_vec();
+        for typ in types.iter().flatten() {
+            names.push(self.type_name(&typ.name));
+        }
+        names
+    }
+
+    fn convert_fields(
+        &self,
+        fields: &Option<std::vec::Vec<IntrospectionField>>,
+    ) -> Result<Vec<'a, FieldDefinition<'a>>> {
+        let mut definitions = self.arena.alloc_vec();
+        for field in fields.iter().flatten() {
+            let mut arguments = self.arena.alloc_vec();
+            for arg in &field.args {
+                arguments.push(self.convert_input_value(arg)?);
+            }
+            definitions.push(FieldDefinition {
+                description: self.description(&field.description),
+                name: FieldName::new(self.name(&field.name)),
+                arguments: arguments.into_bump_slice(),
+                typ: self.convert_type_ref(&field.typ)?,
+                directives: self
+                    .deprecation(field.is_deprecated, &field.deprecation_reason)
+                    .into_bump_slice(),
+            });
+        }
+        Ok(definitions)
+    }
+
+    fn convert_input_value(&self, value: &IntrospectionInputValue) -> Result<InputValueDefinition<'a>> {
+        let default_value = match &value.default_value {
+            Some(literal) => Some(self.parse_default_value(&value.name, literal)?),
+            None => None,
+        };
+
+        Ok(InputValueDefinition {
+            description: self.description(&value.description),
+            name: ArgumentName::new(self.name(&value.name)),
+            typ: self.convert_type_ref(&value.typ)?,
+            default_value,
+            directives: self
+                .deprecation(value.is_deprecated, &value.deprecation_reason)
+                .into_bump_slice(),
+        })
+    }
+
+    fn parse_default_value(&self, name: &str, literal: &str) -> Result<Value<'a>> {
+        let source = self.arena.alloc(Source {
+            code: self.arena.allocator().alloc_str(literal),
+            file_path: self.source.file_path,
+            start_line: self.source.start_line,
+            start_column: self.source.start_column,
+            offset: self.source.offset,
+            mappings: &[],
+        });
+
+        Parser::new(self.arena)
+            .with_source(source)
+            .parse_const_value()
+            .map_err(|e| {
+                self.error(format!(
+                    "Invalid default value for '{}' in introspection result: {}",
+                    name,
+                    e.message()
+                ))
+            })
+    }
+
+    fn convert_type_ref(&self, type_ref: &IntrospectionTypeRef) -> Result<Type<'a>> {
+        let inner = || {
+            type_ref
+                .of_type
+                .as_deref()
+                .ok_or_else(|| self.error(format!("Missing ofType for {} type reference", type_ref.kind)))
+        };
+
+        match type_ref.kind.as_str() {
+            "NON_NULL" => match self.convert_type_ref(inner()?)? {
+                Type::Named(named) => Ok(Type::NonNull(self.arena.alloc(NonNullType::Named(named)))),
+                Type::List(list) => Ok(Type::NonNull(self.arena.alloc(NonNullType::List(list)))),
+                Type::NonNull(_) => Err(self.error("Non-null type cannot wrap another non-null type".to_string())),
+            },
+            "LIST" => Ok(Type::List(self.arena.alloc(self.convert_type_ref(inner()?)?))),
+            _ => {
+                let name = type_ref
+                    .name
+                    .as_deref()
+                    .ok_or_else(|| self.error(format!("Missing name for {} type reference", type_ref.kind)))?;
+                Ok(Type::Named(NamedType::new(self.type_name(name))))
+            }
+        }
+    }
+
+    fn convert_directive(&self, directive: &IntrospectionDirective) -> Result<DirectiveDefinition<'a>> {
+        let mut arguments = self.arena.alloc_vec();
+        for arg in &directive.args {
+            arguments.push(self.convert_input_value(arg)?);
+        }
+
+        let mut locations = self.arena.alloc_vec();
+        for location in &directive.locations {
+            let location = DirectiveLocation::from_name(location).ok_or_else(|| {
+                self.error(format!(
+                    "Unknown directive location '{}' for @{} in introspection result",
+                    location, directive.name
+                ))
+            })?;
+            locations.push(location);
+        }
+
+        Ok(DirectiveDefinition {
+            description: self.description(&directive.description),
+            name: DirectiveName::new(self.name(&directive.name)),
+            arguments: arguments.into_bump_slice(),
+            repeatable: directive.is_repeatable,
+            locations: locations.into_bump_slice(),
+        })
+    }
+
+    fn deprecation(&self, is_deprecated: bool, reason: &Option<String>) -> Vec<'a, Directive<'a>> {
+        let mut directives = self.arena.alloc_vec();
+        if is_deprecated {
+            match reason {
+                Some(reason) => directives.push(self.directive("deprecated", "reason", reason)),
+                None => directives.push(Directive {
+                    span: Span::empty(),
+                    name: DirectiveName::new(self.name("deprecated")),
+                    arguments: &[],
+                }),
+            }
+        }
+        directives
+    }
+
+    fn directive(&self, name: &str, argument: &str, value: &str) -> Directive<'a> {
+        let mut arguments = self.arena.alloc_vec();
+        arguments.push(Argument {
+            span: Span::empty(),
+            name: ArgumentName::new(self.name(argument)),
+            value: Value::from(ValueKind::String(self.arena.allocator().alloc_str(value))),
+        });
+
+        Directive {
+            span: Span::empty(),
+            name: DirectiveName::new(self.name(name)),
+            arguments: arguments.into_bump_slice(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{BUILTIN_SCHEMA, SchemaBuilder};
+    use assertables::*;
+
+    const INTROSPECTION: &str = r#"{
+        "data": {
+            "__schema": {
+                "description": null,
+                "queryType": { "name": "Query" },
+                "mutationType": { "name": "Mutation" },
+                "subscriptionType": null,
+                "types": [
+                    {
+                        "kind": "OBJECT",
+                        "name": "Query",
+                        "description": "The root query",
+                        "fields": [
+                            {
+                                "name": "users",
+                                "description": null,
+                                "args": [
+                                    {
+                                        "name": "first",
+                                        "description": null,
+                                        "type": { "kind": "SCALAR", "name": "Int", "ofType": null },
+                                        "defaultValue": "10"
+                                    },
+                                    {
+                                        "name": "filter",
+                                        "description": null,
+                                        "type": { "kind": "INPUT_OBJECT", "name": "UserFilter", "ofType": null },
+                                        "defaultValue": "{status: ACTIVE, tags: [\"a\"]}"
+                                    }
+                                ],
+                                "type": {
+                                    "kind": "NON_NULL",
+                                    "name": null,
+                                    "ofType": {
+                                        "kind": "LIST",
+                                        "name": null,
+                                        "ofType": {
+                                            "kind": "NON_NULL",
+                                            "name": null,
+                                            "ofType": { "kind": "OBJECT", "name": "User", "ofType": null }
+                                        }
+                                    }
+                                },
+                                "isDeprecated": false,
+                                "deprecationReason": null
+                            },
+                            {
+                                "name": "search",
+                                "args": [],
+                                "type": { "kind": "UNION", "name": "SearchResult", "ofType": null },
+                                "isDeprecated": true,
+                                "deprecationReason": "Use users"
+                            }
+                        ],
+                        "inputFields": null,
+                        "interfaces": [],
+                        "enumValues": null,
+                        "possibleTypes": null
+                    },
+                    {
+                        "kind": "OBJECT",
+                        "name": "Mutation",
+                        "fields": [
+                            {
+                                "name": "noop",
+                                "args": [],
+                                "type": { "kind": "SCALAR", "name": "Boolean", "ofType": null }
+                            }
+                        ],
+                        "interfaces": []
+                    },
+                    {
+                        "kind": "INTERFACE",
+                        "name": "Node",
+                        "fields": [
+                            {
+                                "name": "id",
+                                "args": [],
+                                "type": { "kind": "NON_NULL", "name": null, "ofType": { "kind": "SCALAR", "name": "ID", "ofType": null } }
+                            }
+                        ],
+                        "interfaces": [],
+                        "possibleTypes": [{ "kind": "OBJECT", "name": "User" }]
+                    },
+                    {
+                        "kind": "OBJECT",
+                        "name": "User",
+                        "fields": [
+                            {
+                                "name": "id",
+                                "args": [],
+                                "type": { "kind": "NON_NULL", "name": null, "ofType": { "kind": "SCALAR", "name": "ID", "ofType": null } }
+                            },
+                            {
+                                "name": "createdAt",
+                                "args": [],
+                                "type": { "kind": "SCALAR", "name": "DateTime", "ofType": null }
+                            }
+                        ],
+                        "interfaces": [{ "kind": "INTERFACE", "name": "Node", "ofType": null }]
+                    },
+                    {
+                        "kind": "UNION",
+                        "name": "SearchResult",
+                        "possibleTypes": [{ "kind": "OBJECT", "name": "User", "ofType": null }]
+                    },
+                    {
+                        "kind": "ENUM",
+                        "name": "Status",
+                        "enumValues": [
+                            { "name": "ACTIVE", "description": "Active users", "isDeprecated": false, "deprecationReason": null },
+                            { "name": "LEGACY", "description": null, "isDeprecated": true, "deprecationReason": null }
+                        ]
+                    },
+                    {
+                        "kind": "INPUT_OBJECT",
+                        "name": "UserFilter",
+                        "inputFields": [
+                            {
+                                "name": "status",
+                                "type": { "kind": "ENUM", "name": "Status", "ofType": null },
+                                "defaultValue": null
+                            },
+                            {
+                                "name": "tags",
+                                "type": { "kind": "LIST", "name": null, "ofType": { "kind": "SCALAR", "name": "String", "ofType": null } },
+                                "defaultValue": null
+                            }
+                        ]
+                    },
+                    {
+                        "kind": "SCALAR",
+                        "name": "DateTime",
+                        "description": "ISO-8601 date time",
+                        "specifiedByURL": "https://tools.ietf.org/html/rfc3339"
+                    },
+                    { "kind": "SCALAR", "name": "String" },
+                    { "kind": "SCALAR", "name": "Boolean" },
+                    { "kind": "OBJECT", "name": "__Schema", "fields": [], "interfaces": [] }
+                ],
+                "directives": [
+                    {
+                        "name": "cache",
+                        "description": "Cache hint",
+                        "locations": ["FIELD", "QUERY"],
+                        "args": [
+                            {
+                                "name": "ttl",
+                                "type": { "kind": "NON_NULL", "name": null, "ofType": { "kind": "SCALAR", "name": "Int", "ofType": null } },
+                                "defaultValue": null
+                            }
+                        ],
+                        "isRepeatable": true
+                    },
+                    {
+                        "name": "skip",
+                        "locations": ["FIELD"],
+                        "args": []
+                    }
+                ]
+            }
+        }
+    }"#;
+
+    fn build<'a>(arena: &'a Arena, code: &'a str) -> Result<crate::schema::SchemaIndex<'a>> {
+        let mut builder = SchemaBuilder::new();
+        let built_in_source = arena.alloc(Source::ephemeral(BUILTIN_SCHEMA));
+        builder.add_document(Parser::new(arena).with_source(built_in_source).parse()?)?;
+
+        let source = arena.alloc(Source::ephemeral(code));
+        builder.add_document(parse_introspection(arena, source)?)?;
+        Ok(builder.build())
+    }
+
+    #[test]
+    fn test_introspection_types_and_root_operations() {
+        let arena = Arena::new();
+        let index = build(&arena, INTROSPECTION).unwrap();
+
+        assert_some_eq_x!(index.query_type(), "Query");
+        assert_some_eq_x!(index.mutation_type(), "Mutation");
+        assert_none!(index.subscription_type());
+
+        assert!(index.is_object("User"));
+        assert!(index.is_interface("Node"));
+        assert!(index.is_union("SearchResult"));
+        assert!(index.is_enum("Status"));
+        assert!(index.is_input_object("UserFilter"));
+        assert!(index.is_scalar("DateTime"));
+        assert!(!index.has_type("__Schema"));
+
+        assert!(index.implements("User", "Node"));
+        assert_eq!(
+            index.get_possible_types("SearchResult").collect::<std::vec::Vec<_>>(),
+            ["User"]
+        );
+        assert_eq!(index.custom_scalars(), &["DateTime"]);
+    }
+
+    #[test]
+    fn test_introspection_field_types_and_arguments() {
+        let arena = Arena::new();
+        let index = build(&arena, INTROSPECTION).unwrap();
+
+        let users = index.get_field("Query", "users").unwrap();
+        assert!(!users.typ.is_nullable());
+        assert!(users.typ.is_list());
+        assert_eq!(users.typ.innermost_type(), "User");
+
+        let first = &users.arguments[0];
+        assert_eq!(first.default_value, Some(ValueKind::Int("10").into()));
+
+        let Some(ValueKind::Object(filter)) = users.arguments[1].default_value.as_ref().map(|v| &v.kind) else {
+            panic!("Expected object default value");
+        };
+        assert_eq!(filter[0].name, "status");
+        assert_eq!(filter[0].value.kind, ValueKind::Enum(Name::new("ACTIVE")));
+    }
+
+    #[test]
+    fn test_introspection_descriptions_and_deprecations() {
+        let arena = Arena::new();
+        let index = build(&arena, INTROSPECTION).unwrap();
+
+        let query = index.get_object_type("Query").unwrap();
+        assert_eq!(query.description.as_ref().unwrap().value, "The root query");
+
+        let search = index.get_field("Query", "search").unwrap();
+        assert_eq!(search.directives[0].name, "deprecated");
+        assert_eq!(
+            search.directives[0].get_argument("reason"),
+            Some(&ValueKind::String("Use users").into())
+        );
+
+        let status = index.get_enum_type("Status").unwrap();
+        assert_eq!(status.values[0].description.as_ref().unwrap().value, "Active users");
+        assert_eq!(status.values[1].directives[0].name, "deprecated");
+        assert_is_empty!(status.values[1].directives[0].arguments);
+
+        let date_time = index.get_scalar_type("DateTime").unwrap();
+        assert_eq!(date_time.directives[0].name, "specifiedBy");
+    }
+
+    #[test]
+    fn test_introspection_directives() {
+        let arena = Arena::new();
+        let index = build(&arena, INTROSPECTION).unwrap();
+
+        let cache = index.get_directive("cache").unwrap();
+        assert!(cache.repeatable);
+        assert_eq!(cache.locations, &[DirectiveLocation::Field, DirectiveLocation::Query]);
+        assert_eq!(cache.arguments[0].name, "ttl");
+    }
+
+    #[test]
+    fn test_introspection_keeps_user_defined_key_directive() {
+        let arena = Arena::new();
+        let code = r#"{ "__schema": { "queryType": { "name": "Query" }, "types": [
+            { "kind": "OBJECT", "name": "Query", "fields": [
+                { "name": "hello", "args": [], "type": { "kind": "SCALAR", "name": "String" } }
+            ], "interfaces": [] }
+        ], "directives": [
+            { "name": "key", "isRepeatable": true, "locations": ["OBJECT", "INTERFACE"], "args": [
+                { "name": "fields", "type": { "kind": "NON_NULL", "ofType": { "kind": "SCALAR", "name": "String" } } }
+            ] }
+        ] } }"#;
+        let index = build(&arena, code).unwrap();
+
+        let key = index.get_directive("key").unwrap();
+        assert!(key.repeatable);
+        assert_eq!(
+            key.locations,
+            &[DirectiveLocation::Object, DirectiveLocation::Interface]
+        );
+    }
+
+    #[test]
+    fn test_introspection_without_data_wrapper() {
+        let arena = Arena::new();
+        let code = r#"{ "__schema": { "queryType": { "name": "Query" }, "types": [
+            { "kind": "OBJECT", "name": "Query", "fields": [
+                { "name": "hello", "args": [], "type": { "kind": "SCALAR", "name": "String" } }
+            ], "interfaces": [] }
+        ] } }"#;
+        let index = build(&arena, code).unwrap();
+
+        assert_some!(index.get_field("Query", "hello"));
+    }
+
+    #[test]
+    fn test_invalid_introspection_json() {
+        let arena = Arena::new();
+        let source = Source::ephemeral("{ \"data\": { \"__schema\": ");
+        let err = parse_introspection(&arena, &source).unwrap_err();
+
+        assert_starts_with!(err.message(), "Invalid introspection result");
+        assert_eq!(err.location().unwrap().file_path, "test.graphql");
+    }
+
+    #[test]
+    fn test_invalid_introspection_default_value() {
+        let arena = Arena::new();
+        let code = r#"{ "__schema": { "queryType": { "name": "Query" }, "types": [
+            { "kind": "OBJECT", "name": "Query", "fields": [
+                { "name": "hello", "args": [
+                    { "name": "a", "type": { "kind": "SCALAR", "name": "Int" }, "defaultValue": ")" }
+                ], "type": { "kind": "SCALAR", "name": "String" } }
+            ], "interfaces": [] }
+        ] } }"#;
+        let source = Source::ephemeral(code);
+        let err = parse_introspection(&arena, &source).unwrap_err();
+
+        assert_starts_with!(err.message(), "Invalid default value for 'a'");
+    }
+}
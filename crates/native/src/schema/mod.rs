@@ -2,11 +2,15 @@ mod builder;
 mod builtin;
 mod document;
 mod index;
+mod introspection;
+mod validate;
 
 pub use builder::SchemaBuilder;
 pub use builtin::BUILTIN_SCHEMA;
 pub use document::DocumentIndex;
 pub use index::{SchemaIndex, TypeInfo};
+pub use introspection::parse_introspection;
+pub use validate::validate;
 
 #[cfg(test)]
 mod integration_tests {
@@ -22,6 +26,9 @@ mod integration_tests {
             code: "",
             file_path: "test.graphql",
             start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: &[],
         }
     }
 
@@ -40,95 +47,119 @@ mod integration_tests {
         let source_binding = test_source();
         let schema_doc = arena.alloc(Document {
             source: &source_binding,
-            definitions: bumpalo::vec![in arena.allocator();
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Interface(InterfaceTypeDefinition {
-                    name: TypeName::new(Name::new(arena.intern("Node"))),
-                    implements: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    fields: bumpalo::vec![in arena.allocator(); FieldDefinition {
-                        name: FieldName::new(Name::new(arena.intern("id"))),
-                        typ: Type::NonNull(arena.alloc(NonNullType::Named(NamedType { name: TypeName::new(Name::new(arena.intern("ID"))),
-                        }))),
-                        arguments: arena.alloc_vec(),
-                        directives: arena.alloc_vec(),
-                        description: None,
-                    }],
-                    description: Some(Description { value: "Node interface for entities" }),
-                }))),
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(ObjectTypeDefinition {
-                    name: TypeName::new(Name::new(arena.intern("User"))),
-                    implements: bumpalo::vec![in arena.allocator(); TypeName::new(Name::new(arena.intern("Node")))],
-                    directives: arena.alloc_vec(),
-                    fields: bumpalo::vec![in arena.allocator();
-                        FieldDefinition {
-                            name: FieldName::new(Name::new(arena.intern("id"))),
-                            typ: Type::NonNull(arena.alloc(NonNullType::Named(NamedType { name: TypeName::new(Name::new(arena.intern("ID"))),
-                            }))),
-                            arguments: arena.alloc_vec(),
-                            directives: arena.alloc_vec(),
-                            description: None,
-                        },
-                        FieldDefinition {
-                            name: FieldName::new(Name::new(arena.intern("name"))),
-                            typ: Type::NonNull(arena.alloc(NonNullType::Named(NamedType { name: TypeName::new(Name::new(arena.intern("String"))),
-                            }))),
-                            arguments: arena.alloc_vec(),
-                            directives: arena.alloc_vec(),
-                            description: None,
-                        }
-                    ],
-                    description: Some(Description { value: "User type" }),
-                }))),
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(ObjectTypeDefinition {
-                    name: TypeName::new(Name::new(arena.intern("Post"))),
-                    implements: bumpalo::vec![in arena.allocator(); TypeName::new(Name::new(arena.intern("Node")))],
-                    directives: arena.alloc_vec(),
-                    fields: bumpalo::vec![in arena.allocator();
-                        FieldDefinition {
+            definitions: arena.alloc_slice([
+                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Interface(
+                    InterfaceTypeDefinition {
+                        name: TypeName::new(Name::new(arena.intern("Node"))),
+                        implements: &[],
+                        directives: &[],
+                        fields: arena.alloc_slice([FieldDefinition {
                             name: FieldName::new(Name::new(arena.intern("id"))),
-                            typ: Type::NonNull(arena.alloc(NonNullType::Named(NamedType { name: TypeName::new(Name::new(arena.intern("ID"))),
-                            }))),
-                            arguments: arena.alloc_vec(),
-                            directives: arena.alloc_vec(),
-                            description: None,
-                        },
-                        FieldDefinition {
-                            name: FieldName::new(Name::new(arena.intern("title"))),
-                            typ: Type::NonNull(arena.alloc(NonNullType::Named(NamedType { name: TypeName::new(Name::new(arena.intern("String"))),
+                            typ: Type::NonNull(arena.alloc(NonNullType::Named(NamedType {
+                                name: TypeName::new(Name::new(arena.intern("ID"))),
                             }))),
-                            arguments: arena.alloc_vec(),
-                            directives: arena.alloc_vec(),
+                            arguments: &[],
+                            directives: &[],
                             description: None,
-                        }
-                    ],
-                    description: Some(Description { value: "Post type" }),
-                }))),
+                        }]),
+                        description: Some(Description {
+                            value: "Node interface for entities",
+                        }),
+                    },
+                ))),
+                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(
+                    ObjectTypeDefinition {
+                        name: TypeName::new(Name::new(arena.intern("User"))),
+                        implements: arena.alloc_slice([TypeName::new(Name::new(arena.intern("Node")))]),
+                        directives: &[],
+                        fields: arena.alloc_slice([
+                            FieldDefinition {
+                                name: FieldName::new(Name::new(arena.intern("id"))),
+                                typ: Type::NonNull(arena.alloc(NonNullType::Named(NamedType {
+                                    name: TypeName::new(Name::new(arena.intern("ID"))),
+                                }))),
+                                arguments: &[],
+                                directives: &[],
+                                description: None,
+                            },
+                            FieldDefinition {
+                                name: FieldName::new(Name::new(arena.intern("name"))),
+                                typ: Type::NonNull(arena.alloc(NonNullType::Named(NamedType {
+                                    name: TypeName::new(Name::new(arena.intern("String"))),
+                                }))),
+                                arguments: &[],
+                                directives: &[],
+                                description: None,
+                            },
+                        ]),
+                        description: Some(Description { value: "User type" }),
+                    },
+                ))),
+                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(
+                    ObjectTypeDefinition {
+                        name: TypeName::new(Name::new(arena.intern("Post"))),
+                        implements: arena.alloc_slice([TypeName::new(Name::new(arena.intern("Node")))]),
+                        directives: &[],
+                        fields: arena.alloc_slice([
+                            FieldDefinition {
+                                name: FieldName::new(Name::new(arena.intern("id"))),
+                                typ: Type::NonNull(arena.alloc(NonNullType::Named(NamedType {
+                                    name: TypeName::new(Name::new(arena.intern("ID"))),
+                                }))),
+                                arguments: &[],
+                                directives: &[],
+                                description: None,
+                            },
+                            FieldDefinition {
+                                name: FieldName::new(Name::new(arena.intern("title"))),
+                                typ: Type::NonNull(arena.alloc(NonNullType::Named(NamedType {
+                                    name: TypeName::new(Name::new(arena.intern("String"))),
+                                }))),
+                                arguments: &[],
+                                directives: &[],
+                                description: None,
+                            },
+                        ]),
+                        description: Some(Description { value: "Post type" }),
+                    },
+                ))),
                 Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Union(UnionTypeDefinition {
                     name: TypeName::new(Name::new(arena.intern("SearchResult"))),
-                    directives: arena.alloc_vec(),
-                    members: bumpalo::vec![in arena.allocator(); TypeName::new(Name::new(arena.intern("User"))), TypeName::new(Name::new(arena.intern("Post")))],
-                    description: Some(Description { value: "Search result union" }),
-                }))),
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Scalar(ScalarTypeDefinition {
-                    name: TypeName::new(Name::new(arena.intern("DateTime"))),
-                    directives: arena.alloc_vec(),
-                    description: Some(Description { value: "DateTime scalar" }),
-                }))),
-                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(ObjectTypeDefinition {
-                    name: TypeName::new(Name::new(arena.intern("Query"))),
-                    implements: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    fields: arena.alloc_vec(),
-                    description: Some(Description { value: "Query root" }),
+                    directives: &[],
+                    members: arena.alloc_slice([
+                        TypeName::new(Name::new(arena.intern("User"))),
+                        TypeName::new(Name::new(arena.intern("Post"))),
+                    ]),
+                    description: Some(Description {
+                        value: "Search result union",
+                    }),
                 }))),
+                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Scalar(
+                    ScalarTypeDefinition {
+                        name: TypeName::new(Name::new(arena.intern("DateTime"))),
+                        directives: &[],
+                        description: Some(Description {
+                            value: "DateTime scalar",
+                        }),
+                    },
+                ))),
+                Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(
+                    ObjectTypeDefinition {
+                        name: TypeName::new(Name::new(arena.intern("Query"))),
+                        implements: &[],
+                        directives: &[],
+                        fields: &[],
+                        description: Some(Description { value: "Query root" }),
+                    },
+                ))),
                 Definition::TypeSystem(TypeSystemDefinition::Schema(SchemaDefinition {
                     description: None,
                     query: Some(TypeName::new(Name::new(arena.intern("Query")))),
                     mutation: None,
                     subscription: None,
-                    directives: arena.alloc_vec(),
+                    directives: &[],
                 })),
-            ],
+            ]),
         });
 
         builder.add_document(schema_doc).unwrap();
@@ -177,62 +208,60 @@ mod integration_tests {
         let source_binding = test_source();
         let doc = arena.alloc(Document {
             source: &source_binding,
-            definitions: bumpalo::vec![in arena.allocator();
+            definitions: arena.alloc_slice([
                 Definition::Executable(ExecutableDefinition::Fragment(FragmentDefinition {
                     span: Span::empty(),
                     name: FragmentName::new(Name::new(arena.intern("UserFields"))),
 
-                    variable_definitions: arena.alloc_vec(),
+                    variable_definitions: &[],
 
                     type_condition: TypeName::new(Name::new(arena.intern("User"))),
-                    directives: arena.alloc_vec(),
+                    directives: &[],
                     selection_set: SelectionSet {
-                        selections: bumpalo::vec![in arena.allocator(); Selection::Field(Field {
+                        selections: arena.alloc_slice([Selection::Field(Field {
                             span: Span::empty(),
                             alias: None,
                             name: FieldName::new(Name::new(arena.intern("id"))),
-                            arguments: arena.alloc_vec(),
-                            directives: arena.alloc_vec(),
-                            selection_set: SelectionSet {
-                                selections: arena.alloc_vec(),
-                            },
-                        })],
+                            arguments: &[],
+                            directives: &[],
+                            selection_set: SelectionSet { selections: &[] },
+                        })]),
                     },
                 })),
                 Definition::Executable(ExecutableDefinition::Operation(OperationDefinition {
                     span: Span::empty(),
                     operation_type: OperationType::Query,
                     name: Some(Name::new(arena.intern("GetUser"))),
-                    variable_definitions: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
+                    variable_definitions: &[],
+                    directives: &[],
                     selection_set: SelectionSet {
-                        selections: bumpalo::vec![in arena.allocator(); Selection::FragmentSpread(FragmentSpread {
+                        selections: arena.alloc_slice([Selection::FragmentSpread(FragmentSpread {
                             span: Span::empty(),
                             fragment_name: FragmentName::new(Name::new(arena.intern("UserFields"))),
 
-                            arguments: arena.alloc_vec(),
+                            arguments: &[],
 
-                            directives: arena.alloc_vec(),
-                        })],
+                            directives: &[],
+                        })]),
                     },
                 })),
                 Definition::Executable(ExecutableDefinition::Operation(OperationDefinition {
                     span: Span::empty(),
                     operation_type: OperationType::Mutation,
                     name: Some(Name::new(arena.intern("CreateUser"))),
-                    variable_definitions: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    selection_set: SelectionSet { selections: arena.alloc_vec() },
+                    variable_definitions: &[],
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
                 })),
                 Definition::Executable(ExecutableDefinition::Operation(OperationDefinition {
                     span: Span::empty(),
                     operation_type: OperationType::Query,
                     name: None,
-                    variable_definitions: arena.alloc_vec(),
-                    directives: arena.alloc_vec(),
-                    selection_set: SelectionSet { selections: arena.alloc_vec() },
+                    variable_definitions: &[],
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
                 })),
-            ],
+            ]),
         });
 
         doc_index.add_document(doc).unwrap();
@@ -277,28 +306,32 @@ mod integration_tests {
         let source_binding1 = test_source();
         let schema_doc = arena.alloc(Document {
             source: &source_binding1,
-            definitions: bumpalo::vec![in arena.allocator(); Definition::TypeSystem(TypeSystemDefinition::Type(TypeDefinition::Object(ObjectTypeDefinition {
-                name: TypeName::new(Name::new(arena.intern("User"))),
-                implements: arena.alloc_vec(),
-                directives: arena.alloc_vec(),
-                fields: arena.alloc_vec(),
-                description: None,
-            })))],
+            definitions: arena.alloc_slice([Definition::TypeSystem(TypeSystemDefinition::Type(
+                TypeDefinition::Object(ObjectTypeDefinition {
+                    name: TypeName::new(Name::new(arena.intern("User"))),
+                    implements: &[],
+                    directives: &[],
+                    fields: &[],
+                    description: None,
+                }),
+            ))]),
         });
 
         let source_binding2 = test_source();
         let query_doc = arena.alloc(Document {
             source: &source_binding2,
-            definitions: bumpalo::vec![in arena.allocator(); Definition::Executable(ExecutableDefinition::Fragment(FragmentDefinition {
-                span: Span::empty(),
-                name: FragmentName::new(Name::new(arena.intern("UserFields"))),
+            definitions: arena.alloc_slice([Definition::Executable(ExecutableDefinition::Fragment(
+                FragmentDefinition {
+                    span: Span::empty(),
+                    name: FragmentName::new(Name::new(arena.intern("UserFields"))),
 
-                variable_definitions: arena.alloc_vec(),
+                    variable_definitions: &[],
 
-                type_condition: TypeName::new(Name::new(arena.intern("User"))),
-                directives: arena.alloc_vec(),
-                selection_set: SelectionSet { selections: arena.alloc_vec() },
-            }))],
+                    type_condition: TypeName::new(Name::new(arena.intern("User"))),
+                    directives: &[],
+                    selection_set: SelectionSet { selections: &[] },
+                },
+            ))]),
         });
 
         schema_builder.add_document(schema_doc).unwrap();
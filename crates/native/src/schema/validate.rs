@@ -0,0 +1,736 @@
+use crate::error::location::{Location, Span};
+use crate::error::{ErrorCode, MearieError};
+use crate::graphql::ast::*;
+use crate::schema::{SchemaIndex, TypeInfo};
+use crate::source::Source;
+use rustc_hash::FxHashSet;
+
+/// Validates a built [`SchemaIndex`] against the type system rules of the GraphQL specification.
+///
+/// The following rules are checked:
+/// - Referenced types exist, fields return output types and arguments/input fields accept input types
+/// - Objects and interfaces correctly implement their interfaces (field presence, covariant
+///   return types, argument compatibility and transitively implemented interfaces)
+/// - Union members are object types
+/// - Input objects do not reference themselves through an unbroken chain of non-null fields
+/// - Directives used in type definitions exist, are allowed at their location, are not
+///   repeated unless repeatable, and are given known and all required arguments
+///
+/// Errors are located at the name of the offending type or directive definition.
+/// Returns all errors found; an empty vector means the schema is valid.
+pub fn validate(schema: &SchemaIndex) -> Vec<MearieError> {
+    let mut validator = SchemaValidator {
+        schema,
+        errors: Vec::new(),
+    };
+    validator.validate();
+    validator.errors
+}
+
+struct SchemaValidator<'s, 'a> {
+    schema: &'s SchemaIndex<'a>,
+    errors: Vec<MearieError>,
+}
+
+/// The definition an error is reported at: the span of its name in the source defining it.
+#[derive(Clone, Copy)]
+struct Origin<'a> {
+    source: Option<&'a Source<'a>>,
+    span: Span,
+}
+
+impl<'s, 'a> SchemaValidator<'s, 'a> {
+    fn error(&mut self, origin: Origin<'a>, code: ErrorCode, message: String) {
+        let location = origin.source.map(|source| Location::from_span(source, origin.span));
+        self.errors
+            .push(MearieError::validation(message).with_code(code).at_opt(location));
+    }
+
+    fn validate(&mut self) {
+        let mut types: Vec<_> = self.schema.types().collect();
+        types.sort_unstable_by_key(|(name, _)| *name);
+
+        for (name, type_info) in &types {
+            let origin = Origin {
+                source: self.schema.type_source(name),
+                span: type_info.name().span(),
+            };
+
+            match *type_info {
+                TypeInfo::Scalar(scalar) => {
+                    self.validate_directives(origin, scalar.directives, DirectiveLocation::Scalar, &scalar.name);
+                }
+                TypeInfo::Object(obj) => {
+                    self.validate_directives(origin, obj.directives, DirectiveLocation::Object, &obj.name);
+                    self.validate_fields(origin, obj.name.as_str(), obj.fields);
+                    self.validate_implementations(origin, obj.name.as_str(), obj.implements, obj.fields);
+                }
+                TypeInfo::Interface(iface) => {
+                    self.validate_directives(origin, iface.directives, DirectiveLocation::Interface, &iface.name);
+                    self.validate_fields(origin, iface.name.as_str(), iface.fields);
+                    self.validate_implementations(origin, iface.name.as_str(), iface.implements, iface.fields);
+                }
+                TypeInfo::Union(union) => {
+                    self.validate_directives(origin, union.directives, DirectiveLocation::Union, &union.name);
+                    self.validate_union_members(origin, union);
+                }
+                TypeInfo::Enum(enum_type) => {
+                    self.validate_directives(origin, enum_type.directives, DirectiveLocation::Enum, &enum_type.name);
+                    for value in enum_type.values {
+                        let coordinate = format!("{}.{}", enum_type.name, value.value);
+                        self.validate_directives(origin, value.directives, DirectiveLocation::EnumValue, &coordinate);
+                    }
+                }
+                TypeInfo::InputObject(input) => {
+                    self.validate_directives(origin, input.directives, DirectiveLocation::InputObject, &input.name);
+                    for field in input.fields {
+                        let coordinate = format!("{}.{}", input.name, field.name);
+                        self.validate_input_type(origin, &field.typ, &coordinate);
+                        self.validate_directives(
+                            origin,
+                            field.directives,
+                            DirectiveLocation::InputFieldDefinition,
+                            &coordinate,
+                        );
+                    }
+                    self.validate_input_object_cycles(origin, input);
+                }
+            }
+        }
+
+        let mut directives: Vec<_> = self.schema.directives().collect();
+        directives.sort_unstable_by_key(|directive| directive.name.as_str());
+
+        for directive in directives {
+            let origin = Origin {
+                source: self.schema.directive_source(directive.name.as_str()),
+                span: directive.name.span(),
+            };
+
+            for arg in directive.arguments {
+                let coordinate = format!("@{}({}:)", directive.name, arg.name);
+                self.validate_input_type(origin, &arg.typ, &coordinate);
+            }
+        }
+    }
+
+    fn validate_fields(&mut self, origin: Origin<'a>, type_name: &str, fields: &[FieldDefinition<'a>]) {
+        for field in fields {
+            let coordinate = format!("{}.{}", type_name, field.name);
+            self.validate_output_type(origin, &field.typ, &coordinate);
+            self.validate_directives(
+                origin,
+                field.directives,
+                DirectiveLocation::FieldDefinition,
+                &coordinate,
+            );
+
+            for arg in field.arguments {
+                let coordinate = format!("{}.{}({}:)", type_name, field.name, arg.name);
+                self.validate_input_type(origin, &arg.typ, &coordinate);
+                self.validate_directives(
+                    origin,
+                    arg.directives,
+                    DirectiveLocation::ArgumentDefinition,
+                    &coordinate,
+                );
+            }
+        }
+    }
+
+    fn validate_output_type(&mut self, origin: Origin<'a>, typ: &Type<'a>, coordinate: &str) {
+        let type_name = typ.innermost_type();
+        match self.schema.get_type(type_name.as_str()) {
+            None => self.error(
+                origin,
+                ErrorCode::UnknownType,
+                format!("Unknown type '{}' referenced by '{}'", type_name, coordinate),
+            ),
+            Some(TypeInfo::InputObject(_)) => self.error(
+                origin,
+                ErrorCode::InvalidFieldType,
+                format!(
+                    "The type of '{}' must be an output type, but '{}' is an input object type",
+                    coordinate, type_name
+                ),
+            ),
+            Some(_) => {}
+        }
+    }
+
+    fn validate_input_type(&mut self, origin: Origin<'a>, typ: &Type<'a>, coordinate: &str) {
+        let type_name = typ.innermost_type();
+        match self.schema.get_type(type_name.as_str()) {
+            None => self.error(
+                origin,
+                ErrorCode::UnknownType,
+                format!("Unknown type '{}' referenced by '{}'", type_name, coordinate),
+            ),
+            Some(TypeInfo::Object(_) | TypeInfo::Interface(_) | TypeInfo::Union(_)) => self.error(
+                origin,
+                ErrorCode::InvalidFieldType,
+                format!(
+                    "The type of '{}' must be an input type, but '{}' is an output type",
+                    coordinate, type_name
+                ),
+            ),
+            Some(_) => {}
+        }
+    }
+
+    fn validate_implementations(
+        &mut self,
+        origin: Origin<'a>,
+        type_name: &str,
+        implements: &[TypeName<'a>],
+        fields: &[FieldDefinition<'a>],
+    ) {
+        for interface_name in implements {
+            if interface_name == type_name {
+                self.error(
+                    origin,
+                    ErrorCode::InvalidInterfaceImplementation,
+                    format!("Type '{}' cannot implement itself", type_name),
+                );
+                continue;
+            }
+
+            let Some(iface) = self.schema.get_interface_type(interface_name.as_str()) else {
+                self.error(
+                    origin,
+                    ErrorCode::InvalidInterfaceImplementation,
+                    format!(
+                        "Type '{}' can only implement interfaces, but '{}' is not an interface type",
+                        type_name, interface_name
+                    ),
+                );
+                continue;
+            };
+
+            for transitive in iface.implements {
+                if transitive != type_name && !implements.contains(transitive) {
+                    self.error(
+                        origin,
+                        ErrorCode::InvalidInterfaceImplementation,
+                        format!(
+                            "Type '{}' must implement '{}' because it is implemented by '{}'",
+                            type_name, transitive, interface_name
+                        ),
+                    );
+                }
+            }
+
+            for iface_field in iface.fields {
+                let Some(field) = fields.iter().find(|f| f.name == iface_field.name) else {
+                    self.error(
+                        origin,
+                        ErrorCode::InvalidInterfaceImplementation,
+                        format!(
+                            "Interface field '{}.{}' expected but '{}' does not provide it",
+                            interface_name, iface_field.name, type_name
+                        ),
+                    );
+                    continue;
+                };
+
+                if !self.is_subtype(&field.typ, &iface_field.typ) {
+                    self.error(
+                        origin,
+                        ErrorCode::InvalidInterfaceImplementation,
+                        format!(
+                            "Interface field '{}.{}' expects type '{}' but '{}.{}' is type '{}'",
+                            interface_name, iface_field.name, &iface_field.typ, type_name, field.name, &field.typ
+                        ),
+                    );
+                }
+
+                for iface_arg in iface_field.arguments {
+                    let Some(arg) = field.arguments.iter().find(|a| a.name == iface_arg.name) else {
+                        self.error(
+                            origin,
+                            ErrorCode::InvalidInterfaceImplementation,
+                            format!(
+                                "Interface field argument '{}.{}({}:)' expected but '{}.{}' does not provide it",
+                                interface_name, iface_field.name, iface_arg.name, type_name, field.name
+                            ),
+                        );
+                        continue;
+                    };
+
+                    if arg.typ != iface_arg.typ {
+                        self.error(
+                            origin,
+                            ErrorCode::InvalidInterfaceImplementation,
+                            format!(
+                                "Interface field argument '{}.{}({}:)' expects type '{}' but '{}.{}({}:)' is type '{}'",
+                                interface_name,
+                                iface_field.name,
+                                iface_arg.name,
+                                &iface_arg.typ,
+                                type_name,
+                                field.name,
+                                arg.name,
+                                &arg.typ
+                            ),
+                        );
+                    }
+                }
+
+                for arg in field.arguments {
+                    let is_interface_arg = iface_field.arguments.iter().any(|a| a.name == arg.name);
+                    if !is_interface_arg && !arg.typ.is_nullable() && arg.default_value.is_none() {
+                        self.error(origin, ErrorCode::InvalidInterfaceImplementation, format!(
+                            "Argument '{}.{}({}:)' must be optional because it is not defined by interface field '{}.{}'",
+                            type_name, field.name, arg.name, interface_name, iface_field.name
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns true if `sub` is a valid covariant replacement for `sup`.
+    fn is_subtype(&self, sub: &Type<'a>, sup: &Type<'a>) -> bool {
+        match (sub, sup) {
+            (Type::NonNull(sub_inner), Type::NonNull(sup_inner)) => {
+                self.is_subtype(&sub_inner.to_nullable(), &sup_inner.to_nullable())
+            }
+            (_, Type::NonNull(_)) => false,
+            (Type::NonNull(sub_inner), _) => self.is_subtype(&sub_inner.to_nullable(), sup),
+            (Type::List(sub_inner), Type::List(sup_inner)) => self.is_subtype(sub_inner, sup_inner),
+            (_, Type::List(_)) | (Type::List(_), _) => false,
+            (Type::Named(sub_named), Type::Named(sup_named)) => self
+                .schema
+                .is_possible_type(sup_named.name.as_str(), sub_named.name.as_str()),
+        }
+    }
+
+    fn validate_union_members(&mut self, origin: Origin<'a>, union: &UnionTypeDefinition<'a>) {
+        let mut seen = FxHashSet::default();
+        for member in union.members {
+            if !seen.insert(member.as_str()) {
+                self.error(
+                    origin,
+                    ErrorCode::InvalidUnionMember,
+                    format!("Union '{}' can only include type '{}' once", union.name, member),
+                );
+                continue;
+            }
+            match self.schema.get_type(member.as_str()) {
+                None => self.error(
+                    origin,
+                    ErrorCode::InvalidUnionMember,
+                    format!("Unknown type '{}' referenced by union '{}'", member, union.name),
+                ),
+                Some(TypeInfo::Object(_)) => {}
+                Some(_) => self.error(
+                    origin,
+                    ErrorCode::InvalidUnionMember,
+                    format!(
+                        "Union '{}' can only include object types, but '{}' is not an object type",
+                        union.name, member
+                    ),
+                ),
+            }
+        }
+    }
+
+    /// Reports a cycle only from the input object that sorts first in it, so each
+    /// cycle is reported exactly once.
+    fn validate_input_object_cycles(&mut self, origin: Origin<'a>, input: &InputObjectTypeDefinition<'a>) {
+        let mut path = vec![input.name.as_str()];
+        let mut visited = FxHashSet::default();
+        if let Some(cycle) = self.find_non_null_cycle(input, &mut path, &mut visited)
+            && cycle.iter().all(|name| input.name.as_str() <= *name)
+        {
+            self.error(
+                origin,
+                ErrorCode::InputObjectCycle,
+                format!(
+                    "Input object '{}' cannot reference itself through non-null fields: {}",
+                    input.name,
+                    cycle.join(" -> ")
+                ),
+            );
+        }
+    }
+
+    fn find_non_null_cycle(
+        &self,
+        input: &InputObjectTypeDefinition<'a>,
+        path: &mut Vec<&'a str>,
+        visited: &mut FxHashSet<&'a str>,
+    ) -> Option<Vec<&'a str>> {
+        for field in input.fields {
+            let Type::NonNull(NonNullType::Named(named)) = &field.typ else {
+                continue;
+            };
+            let Some(field_input) = self.schema.get_input_object_type(named.name.as_str()) else {
+                continue;
+            };
+
+            let field_type_name = field_input.name.as_str();
+            if field_type_name == path[0] {
+                let mut cycle = path.clone();
+                cycle.push(field_type_name);
+                return Some(cycle);
+            }
+            if !visited.insert(field_type_name) {
+                continue;
+            }
+
+            path.push(field_type_name);
+            if let Some(cycle) = self.find_non_null_cycle(field_input, path, visited) {
+                return Some(cycle);
+            }
+            path.pop();
+        }
+        None
+    }
+
+    fn validate_directives(
+        &mut self,
+        origin: Origin<'a>,
+        directives: &[Directive<'a>],
+        location: DirectiveLocation,
+        coordinate: &str,
+    ) {
+        let mut seen = FxHashSet::default();
+
+        for directive in directives {
+            let name = directive.name.as_str();
+            let Some(definition) = self.schema.get_directive(name) else {
+                self.error(
+                    origin,
+                    ErrorCode::UnknownDirective,
+                    format!("Unknown directive '@{}' on '{}'", name, coordinate),
+                );
+                continue;
+            };
+
+            if !definition.locations.contains(&location) {
+                self.error(
+                    origin,
+                    ErrorCode::MisplacedDirective,
+                    format!("Directive '@{}' may not be used on '{}'", name, coordinate),
+                );
+            }
+
+            if !seen.insert(name) && !definition.repeatable {
+                self.error(
+                    origin,
+                    ErrorCode::DuplicateDirective,
+                    format!("Directive '@{}' can only be used once on '{}'", name, coordinate),
+                );
+            }
+
+            for arg in directive.arguments {
+                if !definition.arguments.iter().any(|a| a.name == arg.name) {
+                    self.error(
+                        origin,
+                        ErrorCode::UnknownArgument,
+                        format!(
+                            "Unknown argument '{}' on directive '@{}' used on '{}'",
+                            arg.name, name, coordinate
+                        ),
+                    );
+                }
+            }
+
+            for arg_def in definition.arguments {
+                let is_required = !arg_def.typ.is_nullable() && arg_def.default_value.is_none();
+                if is_required && !directive.has_argument(arg_def.name.as_str()) {
+                    self.error(
+                        origin,
+                        ErrorCode::MissingRequiredArgument,
+                        format!(
+                            "Directive '@{}' used on '{}' is missing required argument '{}'",
+                            name, coordinate, arg_def.name
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arena::Arena;
+    use crate::graphql::parser::Parser;
+    use crate::schema::{BUILTIN_SCHEMA, SchemaBuilder};
+    use crate::source::Source;
+    use assertables::*;
+
+    fn validate_sdl(sdl: &str) -> Vec<String> {
+        let arena = Arena::new();
+        let mut builder = SchemaBuilder::new();
+
+        let built_in_source = Source::ephemeral(BUILTIN_SCHEMA);
+        let built_in_doc = Parser::new(&arena).with_source(&built_in_source).parse().unwrap();
+        builder.add_document(built_in_doc).unwrap();
+
+        let source = Source::ephemeral(sdl);
+        let doc = Parser::new(&arena).with_source(&source).parse().unwrap();
+        builder.add_document(doc).unwrap();
+        assert_is_empty!(builder.apply_extensions(&arena));
+
+        let index = builder.build();
+        validate(&index).iter().map(|e| e.message().to_string()).collect()
+    }
+
+    #[test]
+    fn test_valid_schema() {
+        let errors = validate_sdl(
+            r#"
+            directive @cache(ttl: Int!) repeatable on FIELD_DEFINITION
+            interface Node { id: ID! }
+            interface Entity implements Node { id: ID! createdAt: String }
+            type User implements Entity & Node {
+                id: ID!
+                createdAt: String
+                friends(first: Int, after: String): [User!]! @cache(ttl: 10) @cache(ttl: 20)
+                old: String @deprecated(reason: "no")
+            }
+            union SearchResult = User
+            input UserFilter { self: UserFilter, list: [UserFilter!]! }
+            type Query { node(id: ID!): Node, search(filter: UserFilter): [SearchResult] }
+            "#,
+        );
+
+        assert_is_empty!(errors);
+    }
+
+    #[test]
+    fn test_missing_interface_field() {
+        let errors = validate_sdl(
+            r#"
+            interface Node { id: ID! }
+            type User implements Node { name: String }
+            "#,
+        );
+
+        assert_eq!(
+            errors,
+            ["Interface field 'Node.id' expected but 'User' does not provide it"]
+        );
+    }
+
+    #[test]
+    fn test_covariant_return_types() {
+        let errors = validate_sdl(
+            r#"
+            interface Node { id: ID! }
+            type User implements Node { id: ID! }
+            union Result = User
+            interface HasOwner { owner: Node, results: [Result] }
+            type Post implements HasOwner { owner: User!, results: [User!]! }
+            type Comment implements HasOwner { owner: String, results: Result }
+            "#,
+        );
+
+        assert_eq!(
+            errors,
+            [
+                "Interface field 'HasOwner.owner' expects type 'Node' but 'Comment.owner' is type 'String'",
+                "Interface field 'HasOwner.results' expects type '[Result]' but 'Comment.results' is type 'Result'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nullable_field_cannot_implement_non_null() {
+        let errors = validate_sdl(
+            r#"
+            interface Node { id: ID! }
+            type User implements Node { id: ID }
+            "#,
+        );
+
+        assert_eq!(
+            errors,
+            ["Interface field 'Node.id' expects type 'ID!' but 'User.id' is type 'ID'"]
+        );
+    }
+
+    #[test]
+    fn test_interface_argument_compatibility() {
+        let errors = validate_sdl(
+            r#"
+            interface Connection { items(first: Int, after: String): [String] }
+            type A implements Connection { items(first: Int): [String] }
+            type B implements Connection { items(first: Int!, after: String): [String] }
+            type C implements Connection { items(first: Int, after: String, extra: Int!): [String] }
+            type D implements Connection { items(first: Int, after: String, extra: Int! = 1): [String] }
+            "#,
+        );
+
+        assert_eq!(
+            errors,
+            [
+                "Interface field argument 'Connection.items(after:)' expected but 'A.items' does not provide it",
+                "Interface field argument 'Connection.items(first:)' expects type 'Int' but 'B.items(first:)' is type 'Int!'",
+                "Argument 'C.items(extra:)' must be optional because it is not defined by interface field 'Connection.items'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_implementing_non_interface_and_transitive_interfaces() {
+        let errors = validate_sdl(
+            r#"
+            interface Node { id: ID! }
+            interface Entity implements Node { id: ID! }
+            type User implements Entity { id: ID! }
+            type Post implements User { id: ID! }
+            "#,
+        );
+
+        assert_eq!(
+            errors,
+            [
+                "Type 'Post' can only implement interfaces, but 'User' is not an interface type",
+                "Type 'User' must implement 'Node' because it is implemented by 'Entity'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_union_members_must_be_objects() {
+        let errors = validate_sdl(
+            r#"
+            interface Node { id: ID! }
+            type User { id: ID! }
+            union Result = User | Node | Missing | User
+            "#,
+        );
+
+        assert_eq!(
+            errors,
+            [
+                "Union 'Result' can only include object types, but 'Node' is not an object type",
+                "Unknown type 'Missing' referenced by union 'Result'",
+                "Union 'Result' can only include type 'User' once",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_input_object_non_null_cycle() {
+        let errors = validate_sdl(
+            r#"
+            input A { b: B! }
+            input B { c: C! }
+            input C { a: A!, optional: A }
+            input Selfish { me: Selfish! }
+            "#,
+        );
+
+        assert_eq!(
+            errors,
+            [
+                "Input object 'A' cannot reference itself through non-null fields: A -> B -> C -> A",
+                "Input object 'Selfish' cannot reference itself through non-null fields: Selfish -> Selfish",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_argument_and_field_type_kinds() {
+        let errors = validate_sdl(
+            r#"
+            type User { id: ID! }
+            input UserInput { user: User }
+            type Query { user(input: User): UserInput, missing: Unknown }
+            "#,
+        );
+
+        assert_eq!(
+            errors,
+            [
+                "The type of 'Query.user' must be an output type, but 'UserInput' is an input object type",
+                "The type of 'Query.user(input:)' must be an input type, but 'User' is an output type",
+                "Unknown type 'Unknown' referenced by 'Query.missing'",
+                "The type of 'UserInput.user' must be an input type, but 'User' is an output type",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_directive_usages() {
+        let errors = validate_sdl(
+            r#"
+            directive @cache(ttl: Int!) on FIELD_DEFINITION
+            type Query @cache(ttl: 1) {
+                a: String @cache
+                b: String @cache(ttl: 1, scope: "x")
+                c: String @cache(ttl: 1) @cache(ttl: 2)
+                d: String @unknown
+            }
+            "#,
+        );
+
+        assert_eq!(
+            errors,
+            [
+                "Directive '@cache' may not be used on 'Query'",
+                "Directive '@cache' used on 'Query.a' is missing required argument 'ttl'",
+                "Unknown argument 'scope' on directive '@cache' used on 'Query.b'",
+                "Directive '@cache' can only be used once on 'Query.c'",
+                "Unknown directive '@unknown' on 'Query.d'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors_are_located_at_the_offending_definition() {
+        let arena = Arena::new();
+        let mut builder = SchemaBuilder::new();
+
+        let built_in_source = Source::ephemeral(BUILTIN_SCHEMA);
+        let built_in_doc = Parser::new(&arena).with_source(&built_in_source).parse().unwrap();
+        builder.add_document(built_in_doc).unwrap();
+
+        let source = Source {
+            code: "type Query { user: User }\n\ndirective @cache(ttl: Duration) on FIELD_DEFINITION",
+            file_path: "schema.graphql",
+            start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: &[],
+        };
+        let doc = Parser::new(&arena).with_source(&source).parse().unwrap();
+        builder.add_document(doc).unwrap();
+
+        let index = builder.build();
+        let errors = validate(&index);
+
+        assert_len_eq_x!(&errors, 2);
+        assert_eq!(errors[0].message(), "Unknown type 'User' referenced by 'Query.user'");
+        assert_eq!(
+            errors[0].location(),
+            Some(&Location {
+                file_path: "schema.graphql".to_string(),
+                line: 1,
+                column: Some(6),
+                end_line: Some(1),
+                end_column: Some(11),
+            })
+        );
+        assert_eq!(
+            errors[1].message(),
+            "Unknown type 'Duration' referenced by '@cache(ttl:)'"
+        );
+        assert_eq!(
+            errors[1].location(),
+            Some(&Location {
+                file_path: "schema.graphql".to_string(),
+                line: 3,
+                column: Some(12),
+                end_line: Some(3),
+                end_column: Some(17),
+            })
+        );
+    }
+}
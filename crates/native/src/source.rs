@@ -1,5 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+/// An owned source file or a block of GraphQL embedded in a host file.
+///
+/// `start_line`, `start_column` and `offset` locate the first character of `code` in
+/// the host file, so that diagnostics can be reported at their position there. Lines
+/// and columns are 1-based and `offset` is a UTF-8 byte offset.
 #[cfg_attr(feature = "napi", napi_derive::napi(object))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SourceBuf {
@@ -8,6 +13,38 @@ pub struct SourceBuf {
     #[serde(default)]
     pub importable_file_path: Option<String>,
     pub start_line: u32,
+    pub start_column: u32,
+    pub offset: u32,
+    /// Mappings from generated code back to the host file, if `code` was not copied
+    /// verbatim from it.
+    #[serde(default)]
+    pub mappings: Option<Vec<SourceMapping>>,
+}
+
+/// Maps a byte range of generated code to the host file position it was generated from.
+///
+/// Positions are 1-based and the end position is exclusive.
+#[cfg_attr(feature = "napi", napi_derive::napi(object))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourceMapping {
+    pub generated_start: u32,
+    pub generated_end: u32,
+    pub line: u32,
+    pub column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+}
+
+impl SourceMapping {
+    #[inline]
+    fn contains(&self, pos: usize) -> bool {
+        (self.generated_start as usize..self.generated_end as usize).contains(&pos)
+    }
+
+    #[inline]
+    fn len(&self) -> u32 {
+        self.generated_end - self.generated_start
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -15,6 +52,10 @@ pub struct Source<'a> {
     pub code: &'a str,
     pub file_path: &'a str,
     pub start_line: u32,
+    pub start_column: u32,
+    pub offset: u32,
+    #[serde(skip)]
+    pub mappings: &'a [SourceMapping],
 }
 
 impl<'a> Source<'a> {
@@ -23,8 +64,27 @@ impl<'a> Source<'a> {
             code,
             file_path: "test.graphql",
             start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: &[],
         }
     }
+
+    /// Returns the innermost mapping of generated code containing the byte offset `pos`.
+    pub fn mapping_at(&self, pos: usize) -> Option<&'a SourceMapping> {
+        self.mappings
+            .iter()
+            .filter(|mapping| mapping.contains(pos))
+            .min_by_key(|mapping| mapping.len())
+    }
+}
+
+/// Returns whether `file_path` is a standalone GraphQL document, i.e. a `.graphql` or
+/// `.gql` file.
+pub(crate) fn is_graphql_document(file_path: &str) -> bool {
+    file_path
+        .rsplit_once('.')
+        .is_some_and(|(_, extension)| matches!(extension.to_ascii_lowercase().as_str(), "graphql" | "gql"))
 }
 
 impl<'a> From<&'a SourceBuf> for Source<'a> {
@@ -33,6 +93,21 @@ impl<'a> From<&'a SourceBuf> for Source<'a> {
             code: &owned.code,
             file_path: &owned.file_path,
             start_line: owned.start_line,
+            start_column: owned.start_column,
+            offset: owned.offset,
+            mappings: owned.mappings.as_deref().unwrap_or_default(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_graphql_document() {
+        assert!(is_graphql_document("/project/GetUser.graphql"));
+        assert!(is_graphql_document("/project/GetUser.GQL"));
+        assert!(!is_graphql_document("/project/graphql.ts"));
+    }
+}
@@ -13,7 +13,7 @@ pub fn clone_definition<'a>(_arena: &'a Arena, def: &Definition<'a>) -> Definiti
 
 pub fn clone_variable_definition<'a>(arena: &'a Arena, var: &VariableDefinition<'a>) -> VariableDefinition<'a> {
     let mut directives = bumpalo::vec![in arena.allocator();];
-    for d in &var.directives {
+    for d in var.directives {
         directives.push(clone_directive(arena, d));
     }
 
@@ -22,17 +22,19 @@ pub fn clone_variable_definition<'a>(arena: &'a Arena, var: &VariableDefinition<
         variable: var.variable,
         typ: clone_type(arena, &var.typ),
         default_value: var.default_value.as_ref().map(|v| clone_value(arena, v)),
-        directives,
+        directives: directives.into_bump_slice(),
     }
 }
 
 pub fn clone_selection_set<'a>(arena: &'a Arena, sel_set: &SelectionSet<'a>) -> SelectionSet<'a> {
     let mut selections = bumpalo::vec![in arena.allocator();];
-    for s in &sel_set.selections {
+    for s in sel_set.selections {
         selections.push(clone_selection(arena, s));
     }
 
-    SelectionSet { selections }
+    SelectionSet {
+        selections: selections.into_bump_slice(),
+    }
 }
 
 pub fn clone_selection<'a>(arena: &'a Arena, sel: &Selection<'a>) -> Selection<'a> {
@@ -45,12 +47,12 @@ pub fn clone_selection<'a>(arena: &'a Arena, sel: &Selection<'a>) -> Selection<'
 
 pub fn clone_field<'a>(arena: &'a Arena, field: &Field<'a>) -> Field<'a> {
     let mut arguments = bumpalo::vec![in arena.allocator();];
-    for a in &field.arguments {
+    for a in field.arguments {
         arguments.push(clone_argument(arena, a));
     }
 
     let mut directives = bumpalo::vec![in arena.allocator();];
-    for d in &field.directives {
+    for d in field.directives {
         directives.push(clone_directive(arena, d));
     }
 
@@ -58,55 +60,55 @@ pub fn clone_field<'a>(arena: &'a Arena, field: &Field<'a>) -> Field<'a> {
         span: field.span,
         alias: field.alias,
         name: field.name,
-        arguments,
-        directives,
+        arguments: arguments.into_bump_slice(),
+        directives: directives.into_bump_slice(),
         selection_set: clone_selection_set(arena, &field.selection_set),
     }
 }
 
 pub fn clone_fragment_spread<'a>(arena: &'a Arena, spread: &FragmentSpread<'a>) -> FragmentSpread<'a> {
     let mut arguments = bumpalo::vec![in arena.allocator();];
-    for a in &spread.arguments {
+    for a in spread.arguments {
         arguments.push(clone_argument(arena, a));
     }
 
     let mut directives = bumpalo::vec![in arena.allocator();];
-    for d in &spread.directives {
+    for d in spread.directives {
         directives.push(clone_directive(arena, d));
     }
 
     FragmentSpread {
         span: spread.span,
         fragment_name: spread.fragment_name,
-        arguments,
-        directives,
+        arguments: arguments.into_bump_slice(),
+        directives: directives.into_bump_slice(),
     }
 }
 
 pub fn clone_inline_fragment<'a>(arena: &'a Arena, inline: &InlineFragment<'a>) -> InlineFragment<'a> {
     let mut directives = bumpalo::vec![in arena.allocator();];
-    for d in &inline.directives {
+    for d in inline.directives {
         directives.push(clone_directive(arena, d));
     }
 
     InlineFragment {
         span: inline.span,
         type_condition: inline.type_condition,
-        directives,
+        directives: directives.into_bump_slice(),
         selection_set: clone_selection_set(arena, &inline.selection_set),
     }
 }
 
 pub fn clone_directive<'a>(arena: &'a Arena, dir: &Directive<'a>) -> Directive<'a> {
     let mut arguments = bumpalo::vec![in arena.allocator();];
-    for a in &dir.arguments {
+    for a in dir.arguments {
         arguments.push(clone_argument(arena, a));
     }
 
     Directive {
         span: dir.span,
         name: dir.name,
-        arguments,
+        arguments: arguments.into_bump_slice(),
     }
 }
 
@@ -119,33 +121,35 @@ pub fn clone_argument<'a>(arena: &'a Arena, arg: &Argument<'a>) -> Argument<'a>
 }
 
 pub fn clone_value<'a>(arena: &'a Arena, val: &Value<'a>) -> Value<'a> {
-    match val {
-        Value::Variable(name) => Value::Variable(*name),
-        Value::Int(s) => Value::Int(arena.intern(s)),
-        Value::Float(s) => Value::Float(arena.intern(s)),
-        Value::String(s) => Value::String(arena.intern(s)),
-        Value::Boolean(b) => Value::Boolean(*b),
-        Value::Null => Value::Null,
-        Value::Enum(name) => Value::Enum(*name),
-        Value::List(items) => {
+    let kind = match &val.kind {
+        ValueKind::Variable(name) => ValueKind::Variable(*name),
+        ValueKind::Int(s) => ValueKind::Int(arena.intern(s)),
+        ValueKind::Float(s) => ValueKind::Float(arena.intern(s)),
+        ValueKind::String(s) => ValueKind::String(arena.intern(s)),
+        ValueKind::Boolean(b) => ValueKind::Boolean(*b),
+        ValueKind::Null => ValueKind::Null,
+        ValueKind::Enum(name) => ValueKind::Enum(*name),
+        ValueKind::List(items) => {
             let mut new_items = bumpalo::vec![in arena.allocator();];
-            for v in items {
+            for v in items.iter() {
                 new_items.push(clone_value(arena, v));
             }
-            Value::List(new_items)
+            ValueKind::List(new_items.into_bump_slice())
         }
-        Value::Object(fields) => {
+        ValueKind::Object(fields) => {
             let mut new_fields = bumpalo::vec![in arena.allocator();];
-            for f in fields {
+            for f in fields.iter() {
                 new_fields.push(clone_object_field(arena, f));
             }
-            Value::Object(new_fields)
+            ValueKind::Object(new_fields.into_bump_slice())
         }
-    }
+    };
+    Value::new(kind, val.span)
 }
 
 pub fn clone_object_field<'a>(arena: &'a Arena, field: &ObjectField<'a>) -> ObjectField<'a> {
     ObjectField {
+        span: field.span,
         name: field.name,
         value: clone_value(arena, &field.value),
     }
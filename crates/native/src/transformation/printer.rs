@@ -40,7 +40,7 @@ impl Printer {
 
     fn print_document(&mut self, doc: &Document) {
         let mut first = true;
-        for def in &doc.definitions {
+        for def in doc.definitions {
             if !first {
                 self.output.push('\n');
             }
@@ -83,7 +83,7 @@ impl Printer {
             self.write(")");
         }
 
-        self.print_directives(&op.directives);
+        self.print_directives(op.directives);
 
         self.write(" ");
         self.print_selection_set(&op.selection_set);
@@ -101,7 +101,7 @@ impl Printer {
             self.print_value(default);
         }
 
-        self.print_directives(&var.directives);
+        self.print_directives(var.directives);
     }
 
     fn print_fragment_definition(&mut self, frag: &FragmentDefinition) {
@@ -121,7 +121,7 @@ impl Printer {
 
         self.write(" on ");
         self.write(frag.type_condition.as_str());
-        self.print_directives(&frag.directives);
+        self.print_directives(frag.directives);
         self.write(" ");
         self.print_selection_set(&frag.selection_set);
         self.output.push('\n');
@@ -135,7 +135,7 @@ impl Printer {
         self.write_line("{");
         self.indent();
 
-        for selection in &sel_set.selections {
+        for selection in sel_set.selections {
             self.write_indent();
             self.print_selection(selection);
             self.output.push('\n');
@@ -173,7 +173,7 @@ impl Printer {
             self.write(")");
         }
 
-        self.print_directives(&field.directives);
+        self.print_directives(field.directives);
 
         if !field.selection_set.is_empty() {
             self.write(" ");
@@ -196,7 +196,7 @@ impl Printer {
             self.write(")");
         }
 
-        self.print_directives(&spread.directives);
+        self.print_directives(spread.directives);
     }
 
     fn print_inline_fragment(&mut self, inline: &InlineFragment) {
@@ -207,7 +207,7 @@ impl Printer {
             self.write(type_condition.as_str());
         }
 
-        self.print_directives(&inline.directives);
+        self.print_directives(inline.directives);
         self.write(" ");
         self.print_selection_set(&inline.selection_set);
     }
@@ -259,22 +259,22 @@ impl Printer {
     }
 
     fn print_value(&mut self, value: &Value) {
-        match value {
-            Value::Variable(name) => {
+        match &value.kind {
+            ValueKind::Variable(name) => {
                 self.write("$");
                 self.write(name.as_str());
             }
-            Value::Int(val) => self.write(val),
-            Value::Float(val) => self.write(val),
-            Value::String(val) => {
+            ValueKind::Int(val) => self.write(val),
+            ValueKind::Float(val) => self.write(val),
+            ValueKind::String(val) => {
                 self.write("\"");
                 self.write(&escape_string(val));
                 self.write("\"");
             }
-            Value::Boolean(val) => self.write(if *val { "true" } else { "false" }),
-            Value::Null => self.write("null"),
-            Value::Enum(name) => self.write(name.as_str()),
-            Value::List(values) => {
+            ValueKind::Boolean(val) => self.write(if *val { "true" } else { "false" }),
+            ValueKind::Null => self.write("null"),
+            ValueKind::Enum(name) => self.write(name.as_str()),
+            ValueKind::List(values) => {
                 self.write("[");
                 for (i, val) in values.iter().enumerate() {
                     if i > 0 {
@@ -284,7 +284,7 @@ impl Printer {
                 }
                 self.write("]");
             }
-            Value::Object(fields) => {
+            ValueKind::Object(fields) => {
                 self.write("{");
                 for (i, field) in fields.iter().enumerate() {
                     if i > 0 {
@@ -313,7 +313,7 @@ impl Printer {
         }
 
         self.write("schema");
-        self.print_directives(&schema.directives);
+        self.print_directives(schema.directives);
         self.write_line(" {");
         self.indent();
 
@@ -358,7 +358,7 @@ impl Printer {
 
         self.write("scalar ");
         self.write(scalar.name.as_str());
-        self.print_directives(&scalar.directives);
+        self.print_directives(scalar.directives);
         self.output.push('\n');
         self.output.push('\n');
     }
@@ -381,13 +381,13 @@ impl Printer {
             }
         }
 
-        self.print_directives(&object.directives);
+        self.print_directives(object.directives);
 
         if !object.fields.is_empty() {
             self.write_line(" {");
             self.indent();
 
-            for field in &object.fields {
+            for field in object.fields {
                 self.print_field_definition(field);
             }
 
@@ -416,13 +416,13 @@ impl Printer {
             }
         }
 
-        self.print_directives(&interface.directives);
+        self.print_directives(interface.directives);
 
         if !interface.fields.is_empty() {
             self.write_line(" {");
             self.indent();
 
-            for field in &interface.fields {
+            for field in interface.fields {
                 self.print_field_definition(field);
             }
 
@@ -440,7 +440,7 @@ impl Printer {
 
         self.write("union ");
         self.write(union.name.as_str());
-        self.print_directives(&union.directives);
+        self.print_directives(union.directives);
 
         if !union.members.is_empty() {
             self.write(" = ");
@@ -463,19 +463,19 @@ impl Printer {
 
         self.write("enum ");
         self.write(enum_def.name.as_str());
-        self.print_directives(&enum_def.directives);
+        self.print_directives(enum_def.directives);
 
         if !enum_def.values.is_empty() {
             self.write_line(" {");
             self.indent();
 
-            for value in &enum_def.values {
+            for value in enum_def.values {
                 if let Some(desc) = &value.description {
                     self.print_description(desc);
                 }
                 self.write_indent();
                 self.write(value.value.as_str());
-                self.print_directives(&value.directives);
+                self.print_directives(value.directives);
                 self.output.push('\n');
             }
 
@@ -493,13 +493,13 @@ impl Printer {
 
         self.write("input ");
         self.write(input.name.as_str());
-        self.print_directives(&input.directives);
+        self.print_directives(input.directives);
 
         if !input.fields.is_empty() {
             self.write_line(" {");
             self.indent();
 
-            for field in &input.fields {
+            for field in input.fields {
                 self.print_input_value_definition(field);
             }
 
@@ -538,7 +538,7 @@ impl Printer {
 
         self.write(": ");
         self.print_type(&field.typ);
-        self.print_directives(&field.directives);
+        self.print_directives(field.directives);
         self.output.push('\n');
     }
 
@@ -557,7 +557,7 @@ impl Printer {
             self.print_value(default);
         }
 
-        self.print_directives(&input.directives);
+        self.print_directives(input.directives);
         self.output.push('\n');
     }
 
@@ -612,7 +612,7 @@ impl Printer {
 
     fn print_schema_extension(&mut self, schema: &SchemaExtension) {
         self.write("extend schema");
-        self.print_directives(&schema.directives);
+        self.print_directives(schema.directives);
         self.write_line(" {");
         self.indent();
 
@@ -653,7 +653,7 @@ impl Printer {
     fn print_scalar_type_extension(&mut self, scalar: &ScalarTypeExtension) {
         self.write("extend scalar ");
         self.write(scalar.name.as_str());
-        self.print_directives(&scalar.directives);
+        self.print_directives(scalar.directives);
         self.output.push('\n');
         self.output.push('\n');
     }
@@ -672,13 +672,13 @@ impl Printer {
             }
         }
 
-        self.print_directives(&object.directives);
+        self.print_directives(object.directives);
 
         if !object.fields.is_empty() {
             self.write_line(" {");
             self.indent();
 
-            for field in &object.fields {
+            for field in object.fields {
                 self.print_field_definition(field);
             }
 
@@ -703,13 +703,13 @@ impl Printer {
             }
         }
 
-        self.print_directives(&interface.directives);
+        self.print_directives(interface.directives);
 
         if !interface.fields.is_empty() {
             self.write_line(" {");
             self.indent();
 
-            for field in &interface.fields {
+            for field in interface.fields {
                 self.print_field_definition(field);
             }
 
@@ -723,7 +723,7 @@ impl Printer {
     fn print_union_type_extension(&mut self, union: &UnionTypeExtension) {
         self.write("extend union ");
         self.write(union.name.as_str());
-        self.print_directives(&union.directives);
+        self.print_directives(union.directives);
 
         if !union.members.is_empty() {
             self.write(" = ");
@@ -742,19 +742,19 @@ impl Printer {
     fn print_enum_type_extension(&mut self, enum_ext: &EnumTypeExtension) {
         self.write("extend enum ");
         self.write(enum_ext.name.as_str());
-        self.print_directives(&enum_ext.directives);
+        self.print_directives(enum_ext.directives);
 
         if !enum_ext.values.is_empty() {
             self.write_line(" {");
             self.indent();
 
-            for value in &enum_ext.values {
+            for value in enum_ext.values {
                 if let Some(desc) = &value.description {
                     self.print_description(desc);
                 }
                 self.write_indent();
                 self.write(value.value.as_str());
-                self.print_directives(&value.directives);
+                self.print_directives(value.directives);
                 self.output.push('\n');
             }
 
@@ -768,13 +768,13 @@ impl Printer {
     fn print_input_object_type_extension(&mut self, input: &InputObjectTypeExtension) {
         self.write("extend input ");
         self.write(input.name.as_str());
-        self.print_directives(&input.directives);
+        self.print_directives(input.directives);
 
         if !input.fields.is_empty() {
             self.write_line(" {");
             self.indent();
 
-            for field in &input.fields {
+            for field in input.fields {
                 self.print_input_value_definition(field);
             }
 
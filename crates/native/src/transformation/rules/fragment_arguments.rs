@@ -29,7 +29,7 @@ impl<'a> Transformer<'a> for FragmentArgumentRules {
         let arena = ctx.arena();
 
         let mut directives = bumpalo::vec![in arena.allocator();];
-        for d in &frag.directives {
+        for d in frag.directives {
             if let Some(transformed) = self.transform_directive(ctx, d) {
                 directives.push(transformed);
             }
@@ -38,9 +38,9 @@ impl<'a> Transformer<'a> for FragmentArgumentRules {
         Some(FragmentDefinition {
             span: frag.span,
             name: frag.name,
-            variable_definitions: bumpalo::vec![in arena.allocator();],
+            variable_definitions: &[],
             type_condition: frag.type_condition,
-            directives,
+            directives: directives.into_bump_slice(),
             selection_set: self.transform_selection_set(ctx, &frag.selection_set, frag.type_condition.as_str())?,
         })
     }
@@ -53,7 +53,7 @@ impl<'a> Transformer<'a> for FragmentArgumentRules {
         let arena = ctx.arena();
 
         let mut directives = bumpalo::vec![in arena.allocator();];
-        for d in &spread.directives {
+        for d in spread.directives {
             if let Some(transformed) = self.transform_directive(ctx, d) {
                 directives.push(transformed);
             }
@@ -62,8 +62,8 @@ impl<'a> Transformer<'a> for FragmentArgumentRules {
         Some(FragmentSpread {
             span: spread.span,
             fragment_name: spread.fragment_name,
-            arguments: bumpalo::vec![in arena.allocator();],
-            directives,
+            arguments: &[],
+            directives: directives.into_bump_slice(),
         })
     }
 }
@@ -8,7 +8,7 @@ use crate::transformation::transformer::Transformer;
 ///
 /// Always applies:
 /// - Adds `__typename` to all composite type selections (except root types)
-/// - Adds entity key field (`id`, `_id`, or `uuid`) where applicable
+/// - Adds entity key fields (see [`SchemaIndex::entity_key_fields`](crate::schema::SchemaIndex::entity_key_fields)) where applicable
 #[derive(Default)]
 pub struct SelectionRules;
 
@@ -17,27 +17,6 @@ impl SelectionRules {
         Self
     }
 
-    fn determine_key_field(&self, ctx: &TransformContext<'_>, type_name: &str) -> Option<&'static str> {
-        const KEY_FIELD_NAMES: [&str; 3] = ["id", "_id", "uuid"];
-
-        let fields = ctx.schema().get_object_fields(type_name)?;
-
-        for &key_name in &KEY_FIELD_NAMES {
-            if let Some(&field_def) = fields.get(key_name) {
-                let is_nullable = field_def.typ.is_nullable();
-                let is_list = field_def.typ.is_list();
-                let innermost_type = field_def.typ.innermost_type().as_str();
-                let is_scalar = ctx.schema().is_scalar(innermost_type);
-
-                if !is_nullable && !is_list && is_scalar {
-                    return Some(key_name);
-                }
-            }
-        }
-
-        None
-    }
-
     fn should_skip_type(&self, ctx: &TransformContext<'_>, type_name: &str) -> bool {
         // Check by name (for schemas without explicit schema declaration)
         type_name == "Query" || type_name == "Mutation" || type_name == "Subscription"
@@ -58,11 +37,9 @@ impl SelectionRules {
             span: Span::empty(),
             alias: None,
             name: FieldName::new(Name::new(arena.intern(field_name))),
-            arguments: bumpalo::vec![in arena.allocator();],
-            directives: bumpalo::vec![in arena.allocator();],
-            selection_set: SelectionSet {
-                selections: bumpalo::vec![in arena.allocator();],
-            },
+            arguments: &[],
+            directives: &[],
+            selection_set: SelectionSet { selections: &[] },
         })
     }
 }
@@ -78,27 +55,31 @@ impl<'a> Transformer<'a> for SelectionRules {
         let mut selections = bumpalo::vec![in arena.allocator();];
 
         if !self.should_skip_type(ctx, parent_type)
-            && !self.has_field(&sel_set.selections, "__typename")
+            && !self.has_field(sel_set.selections, "__typename")
             && ctx.schema().has_type(parent_type)
         {
             selections.push(self.create_field(arena, "__typename"));
         }
 
         if !self.should_skip_type(ctx, parent_type)
-            && ctx.schema().is_object(parent_type)
-            && let Some(key_field) = self.determine_key_field(ctx, parent_type)
-            && !self.has_field(&sel_set.selections, key_field)
+            && let Some(key_fields) = ctx.schema().entity_key_fields(parent_type)
         {
-            selections.push(self.create_field(arena, key_field));
+            for &key_field in key_fields {
+                if !self.has_field(sel_set.selections, key_field) {
+                    selections.push(self.create_field(arena, key_field));
+                }
+            }
         }
 
-        for sel in &sel_set.selections {
+        for sel in sel_set.selections {
             if let Some(transformed) = self.transform_selection(ctx, sel, parent_type) {
                 selections.push(transformed);
             }
         }
 
-        Some(SelectionSet { selections })
+        Some(SelectionSet {
+            selections: selections.into_bump_slice(),
+        })
     }
 }
 
@@ -21,6 +21,7 @@ macro_rules! transform_test {
         let schema_source = Source::ephemeral($schema);
         let schema_doc = Parser::new(&$arena).with_source(&schema_source).parse().unwrap();
         schema_builder.add_document(&schema_doc).unwrap();
+        assert!(schema_builder.apply_extensions(&$arena).is_empty());
         let $schema_index = schema_builder.build();
 
         let doc_source = Source::ephemeral($query);
@@ -19,7 +19,7 @@ pub trait Transformer<'a> {
     ) -> Option<&'a Document<'a>> {
         let arena = ctx.arena();
         let mut definitions = bumpalo::vec![in arena.allocator();];
-        for def in &doc.definitions {
+        for def in doc.definitions {
             if let Some(transformed) = self.transform_definition(ctx, def) {
                 definitions.push(transformed);
             }
@@ -32,11 +32,14 @@ pub trait Transformer<'a> {
             code,
             file_path: doc.source.file_path,
             start_line: 1,
+            start_column: 1,
+            offset: 0,
+            mappings: &[],
         });
 
         Some(arena.alloc(Document {
             source: new_source,
-            definitions,
+            definitions: definitions.into_bump_slice(),
         }))
     }
 
@@ -61,14 +64,14 @@ pub trait Transformer<'a> {
         let root_type = get_root_type(ctx, op.operation_type);
 
         let mut variable_definitions = bumpalo::vec![in arena.allocator();];
-        for v in &op.variable_definitions {
+        for v in op.variable_definitions {
             if let Some(transformed) = self.transform_variable_definition(ctx, v) {
                 variable_definitions.push(transformed);
             }
         }
 
         let mut directives = bumpalo::vec![in arena.allocator();];
-        for d in &op.directives {
+        for d in op.directives {
             if let Some(transformed) = self.transform_directive(ctx, d) {
                 directives.push(transformed);
             }
@@ -78,8 +81,8 @@ pub trait Transformer<'a> {
             span: op.span,
             operation_type: op.operation_type,
             name: op.name,
-            variable_definitions,
-            directives,
+            variable_definitions: variable_definitions.into_bump_slice(),
+            directives: directives.into_bump_slice(),
             selection_set: self.transform_selection_set(ctx, &op.selection_set, &root_type)?,
         })
     }
@@ -92,14 +95,14 @@ pub trait Transformer<'a> {
         let arena = ctx.arena();
 
         let mut variable_definitions = bumpalo::vec![in arena.allocator();];
-        for v in &frag.variable_definitions {
+        for v in frag.variable_definitions {
             if let Some(transformed) = self.transform_variable_definition(ctx, v) {
                 variable_definitions.push(transformed);
             }
         }
 
         let mut directives = bumpalo::vec![in arena.allocator();];
-        for d in &frag.directives {
+        for d in frag.directives {
             if let Some(transformed) = self.transform_directive(ctx, d) {
                 directives.push(transformed);
             }
@@ -108,9 +111,9 @@ pub trait Transformer<'a> {
         Some(FragmentDefinition {
             span: frag.span,
             name: frag.name,
-            variable_definitions,
+            variable_definitions: variable_definitions.into_bump_slice(),
             type_condition: frag.type_condition,
-            directives,
+            directives: directives.into_bump_slice(),
             selection_set: self.transform_selection_set(ctx, &frag.selection_set, frag.type_condition.as_str())?,
         })
     }
@@ -131,13 +134,15 @@ pub trait Transformer<'a> {
     ) -> Option<SelectionSet<'a>> {
         let arena = ctx.arena();
         let mut selections = bumpalo::vec![in arena.allocator();];
-        for sel in &sel_set.selections {
+        for sel in sel_set.selections {
             if let Some(transformed) = self.transform_selection(ctx, sel, parent_type) {
                 selections.push(transformed);
             }
         }
 
-        Some(SelectionSet { selections })
+        Some(SelectionSet {
+            selections: selections.into_bump_slice(),
+        })
     }
 
     fn transform_selection(
@@ -173,14 +178,14 @@ pub trait Transformer<'a> {
         };
 
         let mut arguments = bumpalo::vec![in arena.allocator();];
-        for a in &field.arguments {
+        for a in field.arguments {
             if let Some(transformed) = self.transform_argument(ctx, a) {
                 arguments.push(transformed);
             }
         }
 
         let mut directives = bumpalo::vec![in arena.allocator();];
-        for d in &field.directives {
+        for d in field.directives {
             if let Some(transformed) = self.transform_directive(ctx, d) {
                 directives.push(transformed);
             }
@@ -190,8 +195,8 @@ pub trait Transformer<'a> {
             span: field.span,
             alias: field.alias,
             name: field.name,
-            arguments,
-            directives,
+            arguments: arguments.into_bump_slice(),
+            directives: directives.into_bump_slice(),
             selection_set,
         })
     }
@@ -204,14 +209,14 @@ pub trait Transformer<'a> {
         let arena = ctx.arena();
 
         let mut arguments = bumpalo::vec![in arena.allocator();];
-        for a in &spread.arguments {
+        for a in spread.arguments {
             if let Some(transformed) = self.transform_argument(ctx, a) {
                 arguments.push(transformed);
             }
         }
 
         let mut directives = bumpalo::vec![in arena.allocator();];
-        for d in &spread.directives {
+        for d in spread.directives {
             if let Some(transformed) = self.transform_directive(ctx, d) {
                 directives.push(transformed);
             }
@@ -220,8 +225,8 @@ pub trait Transformer<'a> {
         Some(FragmentSpread {
             span: spread.span,
             fragment_name: spread.fragment_name,
-            arguments,
-            directives,
+            arguments: arguments.into_bump_slice(),
+            directives: directives.into_bump_slice(),
         })
     }
 
@@ -235,7 +240,7 @@ pub trait Transformer<'a> {
         let type_condition = inline.type_condition.map(|t| t.as_str()).unwrap_or(parent_type);
 
         let mut directives = bumpalo::vec![in arena.allocator();];
-        for d in &inline.directives {
+        for d in inline.directives {
             if let Some(transformed) = self.transform_directive(ctx, d) {
                 directives.push(transformed);
             }
@@ -244,7 +249,7 @@ pub trait Transformer<'a> {
         Some(InlineFragment {
             span: inline.span,
             type_condition: inline.type_condition,
-            directives,
+            directives: directives.into_bump_slice(),
             selection_set: self.transform_selection_set(ctx, &inline.selection_set, type_condition)?,
         })
     }
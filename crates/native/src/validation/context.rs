@@ -1,7 +1,8 @@
-use crate::error::MearieError;
 use crate::error::location::{Location, Span};
+use crate::error::{ErrorCode, MearieError, Severity};
 use crate::graphql::ast::Document;
 use crate::schema::{DocumentIndex, SchemaIndex};
+use crate::source::Source;
 
 pub struct ValidationContext<'a, 'b> {
     errors: Vec<MearieError>,
@@ -30,10 +31,40 @@ impl<'a, 'b> ValidationContext<'a, 'b> {
         self.document
     }
 
+    /// Returns the source of the document being validated.
     #[inline]
-    pub fn add_error(&mut self, message: impl Into<String>, span: Span) {
-        self.errors
-            .push(MearieError::validation(message).at(Location::from_span(self.target.source, span)));
+    pub fn source(&self) -> &'a Source<'a> {
+        self.target.source
+    }
+
+    /// Returns the location of a span in the document being validated.
+    #[inline]
+    pub fn locate(&self, span: Span) -> Location {
+        Location::from_span(self.target.source, span)
+    }
+
+    /// Creates a located diagnostic without reporting it, so that related locations
+    /// or suggestions can be attached before passing it to [`Self::report`].
+    #[inline]
+    pub fn error(&self, code: ErrorCode, message: impl Into<String>, span: Span) -> MearieError {
+        MearieError::validation(message).with_code(code).at(self.locate(span))
+    }
+
+    #[inline]
+    pub fn report(&mut self, error: MearieError) {
+        self.errors.push(error);
+    }
+
+    #[inline]
+    pub fn add_error(&mut self, code: ErrorCode, message: impl Into<String>, span: Span) {
+        let error = self.error(code, message, span);
+        self.report(error);
+    }
+
+    #[inline]
+    pub fn add_warning(&mut self, code: ErrorCode, message: impl Into<String>, span: Span) {
+        let error = self.error(code, message, span).with_severity(Severity::Warning);
+        self.report(error);
     }
 
     #[inline]
@@ -0,0 +1,356 @@
+use crate::error::ErrorCode;
+use crate::error::location::Span;
+use crate::graphql::ast::*;
+use crate::validation::visitor::{Control, Visitor};
+use crate::validation::{ValidationContext, ValidationRule};
+use std::marker::PhantomData;
+
+/// Default reason of the built-in `@deprecated` directive.
+const DEFAULT_DEPRECATION_REASON: &str = "No longer supported";
+
+/// Reports usages of schema elements marked with `@deprecated` as warnings.
+///
+/// Covers selected fields, field and directive arguments, input object fields and
+/// enum values written as literals. Values passed through variables are not known
+/// statically and are not checked.
+#[derive(Default)]
+pub struct DeprecationRules<'a, 'b> {
+    type_stack: Vec<Option<&'a str>>,
+    _phantom: PhantomData<(&'a (), &'b ())>,
+}
+
+impl<'a, 'b> DeprecationRules<'a, 'b> {
+    fn check_arguments(
+        &self,
+        ctx: &mut ValidationContext<'a, 'b>,
+        owner: &str,
+        arguments: &[Argument<'a>],
+        definitions: &[InputValueDefinition<'b>],
+    ) {
+        for argument in arguments {
+            let Some(arg_def) = definitions.iter().find(|a| a.name == argument.name.as_str()) else {
+                continue;
+            };
+
+            if let Some(reason) = deprecation_reason(arg_def.directives) {
+                ctx.add_warning(
+                    ErrorCode::DeprecatedArgument,
+                    format!("Argument '{}' on '{}' is deprecated: {}", argument.name, owner, reason),
+                    argument.name.span(),
+                );
+            }
+
+            self.check_value(ctx, &argument.value, &arg_def.typ, argument.span);
+        }
+    }
+
+    fn check_value(&self, ctx: &mut ValidationContext<'a, 'b>, value: &Value<'a>, typ: &Type<'_>, span: Span) {
+        let type_name = named_type(typ);
+
+        match &value.kind {
+            ValueKind::Object(fields) => {
+                let Some(input_obj_def) = ctx.schema().get_input_object_type(type_name) else {
+                    return;
+                };
+
+                for field in fields.iter() {
+                    let Some(field_def) = input_obj_def.fields.iter().find(|f| f.name == field.name.as_str()) else {
+                        continue;
+                    };
+
+                    if let Some(reason) = deprecation_reason(field_def.directives) {
+                        ctx.add_warning(
+                            ErrorCode::DeprecatedInputField,
+                            format!("Input field '{}.{}' is deprecated: {}", type_name, field.name, reason),
+                            span,
+                        );
+                    }
+
+                    self.check_value(ctx, &field.value, &field_def.typ, span);
+                }
+            }
+            ValueKind::List(values) => {
+                for value in values.iter() {
+                    self.check_value(ctx, value, typ, span);
+                }
+            }
+            ValueKind::Enum(enum_value) => {
+                if let Some(enum_def) = ctx.schema().get_enum_type(type_name)
+                    && let Some(value_def) = enum_def.values.iter().find(|v| v.value == enum_value.as_str())
+                    && let Some(reason) = deprecation_reason(value_def.directives)
+                {
+                    ctx.add_warning(
+                        ErrorCode::DeprecatedEnumValue,
+                        format!("Enum value '{}.{}' is deprecated: {}", type_name, enum_value, reason),
+                        span,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for DeprecationRules<'a, 'b> {
+    fn enter_operation(&mut self, ctx: &mut ValidationContext<'a, 'b>, operation: &OperationDefinition<'a>) -> Control {
+        let root_type = match operation.operation_type {
+            OperationType::Query => ctx.schema().query_type().or(Some("Query")),
+            OperationType::Mutation => ctx.schema().mutation_type().or(Some("Mutation")),
+            OperationType::Subscription => ctx.schema().subscription_type().or(Some("Subscription")),
+        };
+        self.type_stack.push(root_type);
+        Control::Next
+    }
+
+    fn leave_operation(
+        &mut self,
+        _ctx: &mut ValidationContext<'a, 'b>,
+        _operation: &OperationDefinition<'a>,
+    ) -> Control {
+        self.type_stack.pop();
+        Control::Next
+    }
+
+    fn enter_fragment(&mut self, _ctx: &mut ValidationContext<'a, 'b>, fragment: &FragmentDefinition<'a>) -> Control {
+        self.type_stack.push(Some(fragment.type_condition.as_str()));
+        Control::Next
+    }
+
+    fn leave_fragment(&mut self, _ctx: &mut ValidationContext<'a, 'b>, _fragment: &FragmentDefinition<'a>) -> Control {
+        self.type_stack.pop();
+        Control::Next
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        _ctx: &mut ValidationContext<'a, 'b>,
+        inline_fragment: &InlineFragment<'a>,
+    ) -> Control {
+        if let Some(type_condition) = &inline_fragment.type_condition {
+            self.type_stack.push(Some(type_condition.as_str()));
+        }
+        Control::Next
+    }
+
+    fn leave_inline_fragment(
+        &mut self,
+        _ctx: &mut ValidationContext<'a, 'b>,
+        inline_fragment: &InlineFragment<'a>,
+    ) -> Control {
+        if inline_fragment.type_condition.is_some() {
+            self.type_stack.pop();
+        }
+        Control::Next
+    }
+
+    fn enter_field(&mut self, ctx: &mut ValidationContext<'a, 'b>, field: &Field<'a>) -> Control {
+        let field_def = match self.type_stack.last() {
+            Some(Some(parent_type)) => ctx
+                .schema()
+                .get_field(parent_type, field.name.as_str())
+                .map(|field_def| (*parent_type, field_def)),
+            _ => None,
+        };
+
+        let Some((parent_type, field_def)) = field_def else {
+            self.type_stack.push(None);
+            return Control::Next;
+        };
+
+        let owner = format!("{}.{}", parent_type, field.name);
+        if let Some(reason) = deprecation_reason(field_def.directives) {
+            ctx.add_warning(
+                ErrorCode::DeprecatedField,
+                format!("Field '{}' is deprecated: {}", owner, reason),
+                field.name.span(),
+            );
+        }
+        self.check_arguments(ctx, &owner, field.arguments, field_def.arguments);
+
+        self.type_stack.push(Some(named_type(&field_def.typ)));
+        Control::Next
+    }
+
+    fn leave_field(&mut self, _ctx: &mut ValidationContext<'a, 'b>, _field: &Field<'a>) -> Control {
+        self.type_stack.pop();
+        Control::Next
+    }
+
+    fn enter_directive(&mut self, ctx: &mut ValidationContext<'a, 'b>, directive: &Directive<'a>) -> Control {
+        if let Some(directive_def) = ctx.schema().get_directive(directive.name.as_str()) {
+            let owner = format!("@{}", directive.name);
+            self.check_arguments(ctx, &owner, directive.arguments, directive_def.arguments);
+        }
+        Control::Next
+    }
+
+    fn enter_variable_definition(
+        &mut self,
+        ctx: &mut ValidationContext<'a, 'b>,
+        var_def: &VariableDefinition<'a>,
+    ) -> Control {
+        if let Some(default_value) = &var_def.default_value {
+            self.check_value(ctx, default_value, &var_def.typ, var_def.span);
+        }
+        Control::Next
+    }
+}
+
+impl<'a, 'b: 'a> ValidationRule<'a, 'b> for DeprecationRules<'a, 'b> {}
+
+/// Returns the deprecation reason if the directives contain `@deprecated`.
+fn deprecation_reason<'s>(directives: &'s [Directive<'_>]) -> Option<&'s str> {
+    let directive = directives.iter().find(|d| d.name == "deprecated")?;
+    match directive.get_argument("reason") {
+        Some(Value {
+            kind: ValueKind::String(reason),
+            ..
+        }) => Some(reason),
+        _ => Some(DEFAULT_DEPRECATION_REASON),
+    }
+}
+
+fn named_type<'t>(typ: &Type<'t>) -> &'t str {
+    match typ {
+        Type::Named(named) => named.name.as_str(),
+        Type::List(inner) => named_type(inner),
+        Type::NonNull(NonNullType::Named(named)) => named.name.as_str(),
+        Type::NonNull(NonNullType::List(inner)) => named_type(inner),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate_rules;
+    use assertables::*;
+
+    const SCHEMA: &str = r#"
+        type Query {
+            user(id: ID!, legacyId: Int @deprecated(reason: "Use id")): User
+            users(filter: UserFilter, status: Status): [User!]!
+        }
+        type User {
+            id: ID!
+            name: String!
+            fullName: String @deprecated(reason: "Use name")
+            nickname: String @deprecated
+        }
+        input UserFilter {
+            name: String
+            role: String @deprecated(reason: "Roles are gone")
+            status: Status
+        }
+        enum Status {
+            ACTIVE
+            INACTIVE @deprecated(reason: "Use ACTIVE")
+        }
+    "#;
+
+    #[test]
+    fn test_non_deprecated_usage_valid() {
+        assert_ok!(validate_rules!(
+            DeprecationRules,
+            SCHEMA,
+            r#"query Q { user(id: "1") { id name } users(status: ACTIVE, filter: { name: "a" }) { id } }"#
+        ));
+    }
+
+    #[test]
+    fn test_deprecated_field_warns_with_reason() {
+        let error = validate_rules!(DeprecationRules, SCHEMA, r#"query Q { user(id: "1") { fullName } }"#).unwrap_err();
+        assert_eq!(error.message(), "Field 'User.fullName' is deprecated: Use name");
+        assert_eq!(error.severity(), crate::error::Severity::Warning);
+        assert!(!error.is_error());
+    }
+
+    #[test]
+    fn test_deprecated_field_without_reason_uses_default() {
+        let error = validate_rules!(DeprecationRules, SCHEMA, r#"query Q { user(id: "1") { nickname } }"#).unwrap_err();
+        assert_eq!(
+            error.message(),
+            "Field 'User.nickname' is deprecated: No longer supported"
+        );
+    }
+
+    #[test]
+    fn test_deprecated_field_in_fragment_warns() {
+        let error = validate_rules!(
+            DeprecationRules,
+            SCHEMA,
+            r#"fragment F on User { ... on User { fullName } }"#
+        )
+        .unwrap_err();
+        assert_eq!(error.message(), "Field 'User.fullName' is deprecated: Use name");
+    }
+
+    #[test]
+    fn test_deprecated_argument_warns() {
+        let error = validate_rules!(
+            DeprecationRules,
+            SCHEMA,
+            r#"query Q { user(id: "1", legacyId: 1) { id } }"#
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.message(),
+            "Argument 'legacyId' on 'Query.user' is deprecated: Use id"
+        );
+    }
+
+    #[test]
+    fn test_deprecated_input_field_warns() {
+        let error = validate_rules!(
+            DeprecationRules,
+            SCHEMA,
+            r#"query Q { users(filter: { role: "admin" }) { id } }"#
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.message(),
+            "Input field 'UserFilter.role' is deprecated: Roles are gone"
+        );
+    }
+
+    #[test]
+    fn test_deprecated_enum_value_warns() {
+        let error = validate_rules!(
+            DeprecationRules,
+            SCHEMA,
+            r#"query Q { users(status: INACTIVE) { id } }"#
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.message(),
+            "Enum value 'Status.INACTIVE' is deprecated: Use ACTIVE"
+        );
+    }
+
+    #[test]
+    fn test_deprecated_enum_value_nested_in_input_warns() {
+        let error = validate_rules!(
+            DeprecationRules,
+            SCHEMA,
+            r#"query Q { users(filter: { status: INACTIVE }) { id } }"#
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.message(),
+            "Enum value 'Status.INACTIVE' is deprecated: Use ACTIVE"
+        );
+    }
+
+    #[test]
+    fn test_deprecated_enum_value_in_variable_default_warns() {
+        let error = validate_rules!(
+            DeprecationRules,
+            SCHEMA,
+            r#"query Q($status: Status = INACTIVE) { users(status: $status) { id } }"#
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.message(),
+            "Enum value 'Status.INACTIVE' is deprecated: Use ACTIVE"
+        );
+    }
+}
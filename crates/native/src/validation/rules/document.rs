@@ -1,3 +1,4 @@
+use crate::error::ErrorCode;
 use crate::error::location::Span;
 use crate::graphql::ast::*;
 use crate::validation::visitor::{Control, Visitor};
@@ -31,6 +32,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for DocumentRules<'a, 'b> {
 
         if total_definitions != 1 {
             ctx.add_error(
+                ErrorCode::DocumentDefinitionCount,
                 format!(
                     "Document must contain exactly one operation or exactly one fragment, found {}",
                     total_definitions
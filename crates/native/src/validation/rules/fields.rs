@@ -1,60 +1,197 @@
+use crate::error::ErrorCode;
+use crate::error::location::Location;
+use crate::error::location::Span;
+use crate::error::suggestion::suggestion_list;
 use crate::graphql::ast::*;
+use crate::schema::{DocumentIndex, SchemaIndex};
+use crate::source::Source;
 use crate::validation::visitor::{Control, Visitor};
 use crate::validation::{ValidationContext, ValidationRule};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::fmt;
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 #[derive(Default)]
 pub struct FieldRules<'a, 'b> {
     type_stack: Vec<Option<&'a str>>,
-    argument_names: Vec<&'a str>,
+    argument_names: Vec<(&'a str, Span)>,
+    compared_fragment_pairs: FxHashMap<(&'b str, &'b str), bool>,
     _phantom: PhantomData<&'b ()>,
 }
 
-impl<'a, 'b> FieldRules<'a, 'b> {
-    fn check_field_merging<'c>(&self, ctx: &mut ValidationContext<'a, 'b>, selection_set: &'c SelectionSet<'a>) {
-        let mut fields_by_response_key: FxHashMap<&str, Vec<&'c Field<'a>>> = FxHashMap::default();
+/// A selected field together with the type it was selected on and its schema definition.
+#[derive(Clone, Copy)]
+struct FieldAndDefinition<'c> {
+    parent_type: Option<&'c str>,
+    field: &'c Field<'c>,
+    definition: Option<&'c FieldDefinition<'c>>,
+    source: &'c Source<'c>,
+}
 
-        self.collect_fields(selection_set, &mut fields_by_response_key);
+/// Fields of a selection set (including inline fragments) grouped by response key in
+/// selection order, and the fragments spread into it.
+#[derive(Default)]
+struct FieldsAndFragments<'c> {
+    fields: Vec<(&'c str, Vec<FieldAndDefinition<'c>>)>,
+    field_indices: FxHashMap<&'c str, usize>,
+    fragment_spreads: Vec<(&'c str, Span)>,
+}
 
-        for (response_key, fields) in fields_by_response_key.iter() {
-            if fields.len() <= 1 {
-                continue;
-            }
+impl<'c> FieldsAndFragments<'c> {
+    fn get(&self, response_key: &str) -> Option<&[FieldAndDefinition<'c>]> {
+        self.field_indices
+            .get(response_key)
+            .map(|&index| self.fields[index].1.as_slice())
+    }
+}
 
-            let first_field = fields[0];
-            let first_field_name = first_field.name.as_str();
+struct Conflict<'c> {
+    response_key: String,
+    reason: ConflictReason<'c>,
+    span1: Span,
+    span2: Span,
+    source1: &'c Source<'c>,
+    source2: &'c Source<'c>,
+}
 
-            for field in fields.iter().skip(1) {
-                let field_name = field.name.as_str();
+enum ConflictReason<'c> {
+    Message(String),
+    Subfields(Vec<Conflict<'c>>),
+}
 
-                if first_field_name != field_name {
-                    ctx.add_error(
-                        format!(
-                            "Fields with response key '{}' have different field names: '{}' and '{}'",
-                            response_key, first_field_name, field_name
-                        ),
-                        field.span,
-                    );
-                    continue;
+impl fmt::Display for ConflictReason<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConflictReason::Message(message) => f.write_str(message),
+            ConflictReason::Subfields(conflicts) => {
+                for (i, conflict) in conflicts.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" and ")?;
+                    }
+                    write!(
+                        f,
+                        "subfields '{}' conflict because {}",
+                        conflict.response_key, conflict.reason
+                    )?;
                 }
+                Ok(())
+            }
+        }
+    }
+}
 
-                if !self.arguments_are_equal(&first_field.arguments, &field.arguments) {
-                    ctx.add_error(
-                        format!("Fields with response key '{}' have different arguments", response_key),
-                        field.span,
-                    );
-                }
+/// Implements the spec's OverlappingFieldsCanBeMerged algorithm across inline fragments,
+/// named fragments and nested selection sets.
+///
+/// Field maps are memoized per selection set for the duration of a single check, and
+/// compared fragment pairs are memoized for the whole document so that each pair of
+/// fragments is only compared once.
+struct FieldMerger<'s, 'b: 'c, 'c> {
+    schema: &'s SchemaIndex<'b>,
+    document: &'s DocumentIndex<'b>,
+    compared_fragment_pairs: &'s mut FxHashMap<(&'b str, &'b str), bool>,
+    fields_and_fragments: FxHashMap<*const SelectionSet<'c>, Rc<FieldsAndFragments<'c>>>,
+    compared_fragments_for_fields: FxHashMap<*const FieldsAndFragments<'c>, FxHashSet<&'c str>>,
+}
+
+impl<'s, 'b: 'c, 'c> FieldMerger<'s, 'b, 'c> {
+    fn new(
+        schema: &'s SchemaIndex<'b>,
+        document: &'s DocumentIndex<'b>,
+        compared_fragment_pairs: &'s mut FxHashMap<(&'b str, &'b str), bool>,
+    ) -> Self {
+        Self {
+            schema,
+            document,
+            compared_fragment_pairs,
+            fields_and_fragments: FxHashMap::default(),
+            compared_fragments_for_fields: FxHashMap::default(),
+        }
+    }
+
+    /// Finds conflicts in a selection set, each paired with the span to report it at.
+    ///
+    /// Reported spans always point into the selection set itself: the later of two
+    /// conflicting fields, the field conflicting with a spread fragment, or the later
+    /// of two conflicting fragment spreads.
+    fn find_conflicts_within_selection_set(
+        &mut self,
+        parent_type: Option<&'c str>,
+        selection_set: &'c SelectionSet<'c>,
+        source: &'c Source<'c>,
+    ) -> Vec<(Span, Conflict<'c>)> {
+        let mut reported = Vec::new();
+        let mut conflicts = Vec::new();
+
+        let fields_and_fragments = self.get_fields_and_fragments(parent_type, selection_set, source);
+
+        self.collect_conflicts_within(&mut conflicts, &fields_and_fragments);
+        reported.extend(conflicts.drain(..).map(|conflict| (conflict.span2, conflict)));
+
+        let spreads = &fields_and_fragments.fragment_spreads;
+        for (i, &(fragment_name, _)) in spreads.iter().enumerate() {
+            self.collect_conflicts_between_fields_and_fragment(
+                &mut conflicts,
+                false,
+                &fields_and_fragments,
+                fragment_name,
+            );
+            reported.extend(conflicts.drain(..).map(|conflict| (conflict.span1, conflict)));
+
+            for &(other_name, other_span) in &spreads[i + 1..] {
+                self.collect_conflicts_between_fragments(&mut conflicts, false, fragment_name, other_name);
+                reported.extend(conflicts.drain(..).map(|conflict| (other_span, conflict)));
             }
         }
+
+        reported
+    }
+
+    fn get_fields_and_fragments(
+        &mut self,
+        parent_type: Option<&'c str>,
+        selection_set: &'c SelectionSet<'c>,
+        source: &'c Source<'c>,
+    ) -> Rc<FieldsAndFragments<'c>> {
+        let key = selection_set as *const SelectionSet<'c>;
+        if let Some(cached) = self.fields_and_fragments.get(&key) {
+            return Rc::clone(cached);
+        }
+
+        let mut result = FieldsAndFragments::default();
+        self.collect_fields_and_fragments(parent_type, selection_set, source, &mut result);
+
+        let result = Rc::new(result);
+        self.fields_and_fragments.insert(key, Rc::clone(&result));
+        result
+    }
+
+    fn get_sub_fields_and_fragments(&mut self, field: FieldAndDefinition<'c>) -> Rc<FieldsAndFragments<'c>> {
+        let parent_type = field
+            .definition
+            .map(|definition| definition.typ.innermost_type().as_str());
+        self.get_fields_and_fragments(parent_type, &field.field.selection_set, field.source)
     }
 
-    fn collect_fields<'c>(
+    fn get_fragment_fields_and_fragments(&mut self, fragment_name: &str) -> Option<Rc<FieldsAndFragments<'c>>> {
+        let fragment: &'c FragmentDefinition<'c> = self.document.get_fragment(fragment_name)?;
+        let document: &'c Document<'c> = self.document.get_fragment_document(fragment)?;
+        Some(self.get_fields_and_fragments(
+            Some(fragment.type_condition.as_str()),
+            &fragment.selection_set,
+            document.source,
+        ))
+    }
+
+    fn collect_fields_and_fragments(
         &self,
-        selection_set: &'c SelectionSet<'a>,
-        fields: &mut FxHashMap<&'a str, Vec<&'c Field<'a>>>,
+        parent_type: Option<&'c str>,
+        selection_set: &'c SelectionSet<'c>,
+        source: &'c Source<'c>,
+        result: &mut FieldsAndFragments<'c>,
     ) {
-        for selection in &selection_set.selections {
+        for selection in selection_set.selections {
             match selection {
                 Selection::Field(field) => {
                     let response_key = field
@@ -62,17 +199,288 @@ impl<'a, 'b> FieldRules<'a, 'b> {
                         .as_ref()
                         .map(|a| a.as_str())
                         .unwrap_or_else(|| field.name.as_str());
-                    fields.entry(response_key).or_default().push(field);
+                    let definition: Option<&'c FieldDefinition<'c>> =
+                        parent_type.and_then(|parent_type| self.schema.get_field(parent_type, field.name.as_str()));
+
+                    let index = *result.field_indices.entry(response_key).or_insert_with(|| {
+                        result.fields.push((response_key, Vec::new()));
+                        result.fields.len() - 1
+                    });
+                    result.fields[index].1.push(FieldAndDefinition {
+                        parent_type,
+                        field,
+                        definition,
+                        source,
+                    });
                 }
                 Selection::InlineFragment(inline_fragment) => {
-                    self.collect_fields(&inline_fragment.selection_set, fields);
+                    let type_condition = inline_fragment
+                        .type_condition
+                        .map(|type_condition| type_condition.as_str())
+                        .or(parent_type);
+                    self.collect_fields_and_fragments(type_condition, &inline_fragment.selection_set, source, result);
+                }
+                Selection::FragmentSpread(spread) => {
+                    let fragment_name = spread.fragment_name.as_str();
+                    if !result.fragment_spreads.iter().any(|(name, _)| *name == fragment_name) {
+                        result.fragment_spreads.push((fragment_name, spread.span));
+                    }
+                }
+            }
+        }
+    }
+
+    fn collect_conflicts_within(
+        &mut self,
+        conflicts: &mut Vec<Conflict<'c>>,
+        fields_and_fragments: &FieldsAndFragments<'c>,
+    ) {
+        for (response_key, fields) in &fields_and_fragments.fields {
+            for (i, &field1) in fields.iter().enumerate() {
+                for &field2 in &fields[i + 1..] {
+                    if let Some(conflict) = self.find_conflict(false, response_key, field1, field2) {
+                        conflicts.push(conflict);
+                    }
                 }
-                Selection::FragmentSpread(_) => {}
             }
         }
     }
 
-    fn arguments_are_equal(&self, args1: &[Argument<'a>], args2: &[Argument<'a>]) -> bool {
+    fn collect_conflicts_between_fields_and_fragment(
+        &mut self,
+        conflicts: &mut Vec<Conflict<'c>>,
+        are_mutually_exclusive: bool,
+        fields_and_fragments: &Rc<FieldsAndFragments<'c>>,
+        fragment_name: &'c str,
+    ) {
+        let compared = self
+            .compared_fragments_for_fields
+            .entry(Rc::as_ptr(fields_and_fragments))
+            .or_default();
+        if !compared.insert(fragment_name) {
+            return;
+        }
+
+        let Some(fragment_fields) = self.get_fragment_fields_and_fragments(fragment_name) else {
+            return;
+        };
+        if Rc::ptr_eq(fields_and_fragments, &fragment_fields) {
+            return;
+        }
+
+        self.collect_conflicts_between(
+            conflicts,
+            are_mutually_exclusive,
+            fields_and_fragments,
+            &fragment_fields,
+        );
+
+        for &(nested_name, _) in &fragment_fields.fragment_spreads {
+            self.collect_conflicts_between_fields_and_fragment(
+                conflicts,
+                are_mutually_exclusive,
+                fields_and_fragments,
+                nested_name,
+            );
+        }
+    }
+
+    fn collect_conflicts_between_fragments(
+        &mut self,
+        conflicts: &mut Vec<Conflict<'c>>,
+        are_mutually_exclusive: bool,
+        fragment_name1: &'c str,
+        fragment_name2: &'c str,
+    ) {
+        if fragment_name1 == fragment_name2 {
+            return;
+        }
+
+        let (Some(fragment1), Some(fragment2)) = (
+            self.document.get_fragment(fragment_name1),
+            self.document.get_fragment(fragment_name2),
+        ) else {
+            return;
+        };
+
+        let (name1, name2) = (fragment1.name.as_str(), fragment2.name.as_str());
+        let pair = if name1 < name2 { (name1, name2) } else { (name2, name1) };
+
+        // A pair compared as not mutually exclusive also covers the mutually exclusive case.
+        if let Some(&compared_as_exclusive) = self.compared_fragment_pairs.get(&pair)
+            && (are_mutually_exclusive || !compared_as_exclusive)
+        {
+            return;
+        }
+        self.compared_fragment_pairs.insert(pair, are_mutually_exclusive);
+
+        let (Some(fields1), Some(fields2)) = (
+            self.get_fragment_fields_and_fragments(fragment_name1),
+            self.get_fragment_fields_and_fragments(fragment_name2),
+        ) else {
+            return;
+        };
+
+        self.collect_conflicts_between(conflicts, are_mutually_exclusive, &fields1, &fields2);
+
+        for &(nested_name, _) in &fields2.fragment_spreads {
+            self.collect_conflicts_between_fragments(conflicts, are_mutually_exclusive, fragment_name1, nested_name);
+        }
+        for &(nested_name, _) in &fields1.fragment_spreads {
+            self.collect_conflicts_between_fragments(conflicts, are_mutually_exclusive, nested_name, fragment_name2);
+        }
+    }
+
+    fn collect_conflicts_between(
+        &mut self,
+        conflicts: &mut Vec<Conflict<'c>>,
+        parent_fields_are_mutually_exclusive: bool,
+        fields_and_fragments1: &FieldsAndFragments<'c>,
+        fields_and_fragments2: &FieldsAndFragments<'c>,
+    ) {
+        for (response_key, fields1) in &fields_and_fragments1.fields {
+            let Some(fields2) = fields_and_fragments2.get(response_key) else {
+                continue;
+            };
+
+            for &field1 in fields1 {
+                for &field2 in fields2 {
+                    if let Some(conflict) =
+                        self.find_conflict(parent_fields_are_mutually_exclusive, response_key, field1, field2)
+                    {
+                        conflicts.push(conflict);
+                    }
+                }
+            }
+        }
+    }
+
+    fn find_conflicts_between_sub_selection_sets(
+        &mut self,
+        are_mutually_exclusive: bool,
+        field1: FieldAndDefinition<'c>,
+        field2: FieldAndDefinition<'c>,
+    ) -> Vec<Conflict<'c>> {
+        let mut conflicts = Vec::new();
+
+        let fields1 = self.get_sub_fields_and_fragments(field1);
+        let fields2 = self.get_sub_fields_and_fragments(field2);
+
+        self.collect_conflicts_between(&mut conflicts, are_mutually_exclusive, &fields1, &fields2);
+
+        for &(fragment_name, _) in &fields2.fragment_spreads {
+            self.collect_conflicts_between_fields_and_fragment(
+                &mut conflicts,
+                are_mutually_exclusive,
+                &fields1,
+                fragment_name,
+            );
+        }
+        for &(fragment_name, _) in &fields1.fragment_spreads {
+            self.collect_conflicts_between_fields_and_fragment(
+                &mut conflicts,
+                are_mutually_exclusive,
+                &fields2,
+                fragment_name,
+            );
+        }
+        for &(fragment_name1, _) in &fields1.fragment_spreads {
+            for &(fragment_name2, _) in &fields2.fragment_spreads {
+                self.collect_conflicts_between_fragments(
+                    &mut conflicts,
+                    are_mutually_exclusive,
+                    fragment_name1,
+                    fragment_name2,
+                );
+            }
+        }
+
+        conflicts
+    }
+
+    fn find_conflict(
+        &mut self,
+        parent_fields_are_mutually_exclusive: bool,
+        response_key: &str,
+        field1: FieldAndDefinition<'c>,
+        field2: FieldAndDefinition<'c>,
+    ) -> Option<Conflict<'c>> {
+        // Fields on two different object types can never be selected at the same time,
+        // so only their return types need to be compatible.
+        let are_mutually_exclusive = parent_fields_are_mutually_exclusive
+            || match (field1.parent_type, field2.parent_type) {
+                (Some(parent_type1), Some(parent_type2)) => {
+                    parent_type1 != parent_type2
+                        && self.schema.is_object(parent_type1)
+                        && self.schema.is_object(parent_type2)
+                }
+                _ => false,
+            };
+
+        let conflict = |reason| Conflict {
+            response_key: response_key.to_string(),
+            reason,
+            span1: field1.field.span,
+            span2: field2.field.span,
+            source1: field1.source,
+            source2: field2.source,
+        };
+
+        if !are_mutually_exclusive {
+            let (name1, name2) = (field1.field.name.as_str(), field2.field.name.as_str());
+            if name1 != name2 {
+                return Some(conflict(ConflictReason::Message(format!(
+                    "'{}' and '{}' are different fields",
+                    name1, name2
+                ))));
+            }
+
+            if !self.arguments_are_equal(field1.field.arguments, field2.field.arguments) {
+                return Some(conflict(ConflictReason::Message(
+                    "they have differing arguments".to_string(),
+                )));
+            }
+        }
+
+        if let (Some(definition1), Some(definition2)) = (field1.definition, field2.definition)
+            && self.types_conflict(&definition1.typ, &definition2.typ)
+        {
+            return Some(conflict(ConflictReason::Message(format!(
+                "they return conflicting types '{}' and '{}'",
+                definition1.typ, definition2.typ
+            ))));
+        }
+
+        if !field1.field.selection_set.is_empty() && !field2.field.selection_set.is_empty() {
+            let subfield_conflicts =
+                self.find_conflicts_between_sub_selection_sets(are_mutually_exclusive, field1, field2);
+            if !subfield_conflicts.is_empty() {
+                return Some(conflict(ConflictReason::Subfields(subfield_conflicts)));
+            }
+        }
+
+        None
+    }
+
+    /// Two types conflict if their list or non-null wrappers differ, or if either is a
+    /// leaf type and they are not the same type.
+    fn types_conflict(&self, type1: &Type<'c>, type2: &Type<'c>) -> bool {
+        match (type1, type2) {
+            (Type::NonNull(inner1), Type::NonNull(inner2)) => {
+                self.types_conflict(&inner1.to_nullable(), &inner2.to_nullable())
+            }
+            (Type::NonNull(_), _) | (_, Type::NonNull(_)) => true,
+            (Type::List(inner1), Type::List(inner2)) => self.types_conflict(inner1, inner2),
+            (Type::List(_), _) | (_, Type::List(_)) => true,
+            (Type::Named(named1), Type::Named(named2)) => {
+                let (name1, name2) = (named1.name.as_str(), named2.name.as_str());
+                let is_leaf = |name| self.schema.is_scalar(name) || self.schema.is_enum(name);
+                (is_leaf(name1) || is_leaf(name2)) && name1 != name2
+            }
+        }
+    }
+
+    fn arguments_are_equal(&self, args1: &[Argument<'c>], args2: &[Argument<'c>]) -> bool {
         if args1.len() != args2.len() {
             return false;
         }
@@ -95,19 +503,19 @@ impl<'a, 'b> FieldRules<'a, 'b> {
         true
     }
 
-    fn values_are_equal(&self, v1: &Value<'a>, v2: &Value<'a>) -> bool {
-        match (v1, v2) {
-            (Value::Variable(var1), Value::Variable(var2)) => var1.as_str() == var2.as_str(),
-            (Value::Int(i1), Value::Int(i2)) => i1 == i2,
-            (Value::Float(f1), Value::Float(f2)) => f1 == f2,
-            (Value::String(s1), Value::String(s2)) => s1 == s2,
-            (Value::Boolean(b1), Value::Boolean(b2)) => b1 == b2,
-            (Value::Null, Value::Null) => true,
-            (Value::Enum(e1), Value::Enum(e2)) => e1.as_str() == e2.as_str(),
-            (Value::List(l1), Value::List(l2)) => {
+    fn values_are_equal(&self, v1: &Value<'c>, v2: &Value<'c>) -> bool {
+        match (&v1.kind, &v2.kind) {
+            (ValueKind::Variable(var1), ValueKind::Variable(var2)) => var1.as_str() == var2.as_str(),
+            (ValueKind::Int(i1), ValueKind::Int(i2)) => i1 == i2,
+            (ValueKind::Float(f1), ValueKind::Float(f2)) => f1 == f2,
+            (ValueKind::String(s1), ValueKind::String(s2)) => s1 == s2,
+            (ValueKind::Boolean(b1), ValueKind::Boolean(b2)) => b1 == b2,
+            (ValueKind::Null, ValueKind::Null) => true,
+            (ValueKind::Enum(e1), ValueKind::Enum(e2)) => e1.as_str() == e2.as_str(),
+            (ValueKind::List(l1), ValueKind::List(l2)) => {
                 l1.len() == l2.len() && l1.iter().zip(l2.iter()).all(|(v1, v2)| self.values_are_equal(v1, v2))
             }
-            (Value::Object(o1), Value::Object(o2)) => {
+            (ValueKind::Object(o1), ValueKind::Object(o2)) => {
                 if o1.len() != o2.len() {
                     return false;
                 }
@@ -154,7 +562,32 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FieldRules<'a, 'b> {
         ctx: &mut ValidationContext<'a, 'b>,
         selection_set: &SelectionSet<'a>,
     ) -> Control {
-        self.check_field_merging(ctx, selection_set);
+        let parent_type = self.type_stack.last().copied().flatten();
+        let mut merger = FieldMerger::new(ctx.schema(), ctx.document(), &mut self.compared_fragment_pairs);
+
+        let source = ctx.source();
+        for (span, conflict) in merger.find_conflicts_within_selection_set(parent_type, selection_set, source) {
+            let mut error = ctx.error(
+                ErrorCode::OverlappingFields,
+                format!(
+                    "Fields '{}' conflict because {}. Use different aliases on the fields to fetch both if this was intentional",
+                    conflict.response_key, conflict.reason
+                ),
+                span,
+            );
+
+            for (other_span, other_source) in [(conflict.span1, conflict.source1), (conflict.span2, conflict.source2)] {
+                if other_span != span || !std::ptr::eq(other_source, source) {
+                    error = error.with_related(
+                        format!("Conflicting field '{}' is selected here", conflict.response_key),
+                        Location::from_span(other_source, other_span),
+                    );
+                }
+            }
+
+            ctx.report(error);
+        }
+
         Control::Next
     }
 
@@ -184,6 +617,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FieldRules<'a, 'b> {
 
             if is_leaf && !field.selection_set.selections.is_empty() {
                 ctx.add_error(
+                    ErrorCode::UnexpectedSelectionSet,
                     format!(
                         "Field '{}' must not have a selection set, since type '{}' is a leaf type (scalar or enum)",
                         field_name, named_type
@@ -192,6 +626,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FieldRules<'a, 'b> {
                 );
             } else if !is_leaf && field.selection_set.selections.is_empty() {
                 ctx.add_error(
+                    ErrorCode::MissingSelectionSet,
                     format!(
                         "Field '{}' must have a selection set, since type '{}' is not a leaf type",
                         field_name, named_type
@@ -200,7 +635,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FieldRules<'a, 'b> {
                 );
             }
 
-            for arg in &field.arguments {
+            for arg in field.arguments {
                 let arg_name = arg.name.as_str();
 
                 let arg_exists = field_def
@@ -209,13 +644,18 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FieldRules<'a, 'b> {
                     .any(|def_arg| def_arg.name.as_str() == arg_name);
 
                 if !arg_exists {
-                    ctx.add_error(
-                        format!(
-                            "Unknown argument '{}' on field '{}.{}'",
-                            arg_name, parent_type, field_name
-                        ),
-                        arg.span,
-                    );
+                    let suggestions = suggestion_list(arg_name, field_def.arguments.iter().map(|a| a.name.as_str()));
+                    let error = ctx
+                        .error(
+                            ErrorCode::UnknownArgument,
+                            format!(
+                                "Unknown argument '{}' on field '{}.{}'",
+                                arg_name, parent_type, field_name
+                            ),
+                            arg.name.span(),
+                        )
+                        .with_suggestions(suggestions);
+                    ctx.report(error);
                 }
             }
 
@@ -228,11 +668,12 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FieldRules<'a, 'b> {
 
                     if !arg_provided {
                         ctx.add_error(
+                            ErrorCode::MissingRequiredArgument,
                             format!(
                                 "Required argument '{}' on field '{}.{}' is not provided",
                                 arg_name, parent_type, field_name
                             ),
-                            field.span,
+                            field.name.span(),
                         );
                     }
                 }
@@ -240,10 +681,19 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FieldRules<'a, 'b> {
 
             self.type_stack.push(Some(named_type));
         } else {
-            ctx.add_error(
-                format!("Field '{}' is not defined on type '{}'", field_name, parent_type),
-                field.span,
-            );
+            let suggestions = ctx
+                .schema()
+                .get_object_fields(parent_type)
+                .map(|fields| suggestion_list(field_name, fields.keys().copied()))
+                .unwrap_or_default();
+            let error = ctx
+                .error(
+                    ErrorCode::UnknownField,
+                    format!("Field '{}' is not defined on type '{}'", field_name, parent_type),
+                    field.name.span(),
+                )
+                .with_suggestions(suggestions);
+            ctx.report(error);
             self.type_stack.push(None);
         }
 
@@ -295,11 +745,21 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FieldRules<'a, 'b> {
     fn enter_argument(&mut self, ctx: &mut ValidationContext<'a, 'b>, argument: &Argument<'a>) -> Control {
         let name = argument.name.as_str();
 
-        if self.argument_names.contains(&name) {
-            ctx.add_error(format!("Duplicate argument name '{}'", name), argument.span);
+        if let Some(&(_, first_span)) = self.argument_names.iter().find(|(n, _)| *n == name) {
+            let error = ctx
+                .error(
+                    ErrorCode::DuplicateArgument,
+                    format!("Duplicate argument name '{}'", name),
+                    argument.span,
+                )
+                .with_related(
+                    format!("Argument '{}' is first provided here", name),
+                    ctx.locate(first_span),
+                );
+            ctx.report(error);
         }
 
-        self.argument_names.push(name);
+        self.argument_names.push((name, argument.span));
         Control::Next
     }
 }
@@ -552,4 +1012,223 @@ mod tests {
             r#"query Q { a: field(arg: "a") b: field(arg: "b") }"#
         ));
     }
+
+    #[test]
+    fn test_field_merging_across_fragments_different_arguments() {
+        let result = validate_rules!(
+            FieldRules,
+            r#"type Query { user: User } type User { avatar(size: Int): String }"#,
+            r#"
+                fragment Small on User { avatar(size: 10) }
+                fragment Large on User { avatar(size: 20) }
+                query Q { user { ...Small ...Large } }
+            "#
+        );
+
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Fields 'avatar' conflict because they have differing arguments. Use different aliases on the fields to fetch both if this was intentional"
+        );
+    }
+
+    #[test]
+    fn test_field_merging_between_field_and_nested_fragment() {
+        let result = validate_rules!(
+            FieldRules,
+            r#"type Query { user: User } type User { id: ID! name: String email: String }"#,
+            r#"
+                fragment Outer on User { ...Inner }
+                fragment Inner on User { label: email }
+                query Q { user { label: name ...Outer } }
+            "#
+        );
+
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Fields 'label' conflict because 'name' and 'email' are different fields. Use different aliases on the fields to fetch both if this was intentional"
+        );
+    }
+
+    #[test]
+    fn test_field_merging_nested_subfields() {
+        let result = validate_rules!(
+            FieldRules,
+            r#"type Query { user: User } type User { id: ID! name: String email: String }"#,
+            r#"
+                fragment A on Query { user { contact: name } }
+                fragment B on Query { user { contact: email } }
+                query Q { ...A ...B }
+            "#
+        );
+
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Fields 'user' conflict because subfields 'contact' conflict because 'name' and 'email' are different fields. Use different aliases on the fields to fetch both if this was intentional"
+        );
+    }
+
+    #[test]
+    fn test_field_merging_conflicting_return_types() {
+        let schema = r#"
+            type Query { pet: Pet }
+            union Pet = Dog | Cat
+            type Dog { name: String! barkVolume: Int nickname: String }
+            type Cat { name: String meowVolume: Int nickname: String }
+        "#;
+
+        let result = validate_rules!(
+            FieldRules,
+            schema,
+            r#"query Q { pet { ... on Dog { name } ... on Cat { name } } }"#
+        );
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Fields 'name' conflict because they return conflicting types 'String!' and 'String'. Use different aliases on the fields to fetch both if this was intentional"
+        );
+
+        assert_ok!(validate_rules!(
+            FieldRules,
+            schema,
+            r#"query Q { pet { ... on Dog { volume: barkVolume } ... on Cat { volume: meowVolume } } }"#
+        ));
+    }
+
+    #[test]
+    fn test_field_merging_mutually_exclusive_parent_types() {
+        assert_ok!(validate_rules!(
+            FieldRules,
+            r#"
+                type Query { pet: Pet }
+                union Pet = Dog | Cat
+                type Dog { name(surname: Boolean): String }
+                type Cat { name(surname: Boolean): String }
+            "#,
+            r#"
+                fragment DogName on Dog { name(surname: true) }
+                fragment CatName on Cat { name(surname: false) }
+                query Q { pet { ...DogName ...CatName } }
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_field_merging_same_fragment_spread_twice() {
+        assert_ok!(validate_rules!(
+            FieldRules,
+            r#"type Query { user: User } type User { avatar(size: Int): String }"#,
+            r#"
+                fragment Avatar on User { avatar(size: 10) }
+                query Q { user { avatar(size: 10) ...Avatar ... on User { ...Avatar } } }
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_field_merging_with_cyclic_fragments_terminates() {
+        assert_ok!(validate_rules!(
+            FieldRules,
+            r#"type Query { user: User } type User { friend: User name: String }"#,
+            r#"
+                fragment A on User { name friend { ...B } }
+                fragment B on User { name friend { ...A } }
+                query Q { user { ...A ...B } }
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_unknown_field_suggests_similar_fields() {
+        let error = validate_rules!(
+            FieldRules,
+            r#"type Query { user: User } type User { id: ID! name: String names: [String] }"#,
+            r#"query Q { user { nme } }"#
+        )
+        .unwrap_err();
+
+        assert_eq!(error.code(), Some(ErrorCode::UnknownField));
+        assert_eq!(error.suggestions(), ["name", "names"]);
+        assert_eq!(
+            error.message(),
+            "Field 'nme' is not defined on type 'User'. Did you mean 'name' or 'names'?"
+        );
+    }
+
+    #[test]
+    fn test_unknown_field_without_close_match_has_no_suggestions() {
+        let error = validate_rules!(
+            FieldRules,
+            r#"type Query { user: User } type User { id: ID! }"#,
+            r#"query Q { user { email } }"#
+        )
+        .unwrap_err();
+
+        assert!(error.suggestions().is_empty());
+        assert_eq!(error.message(), "Field 'email' is not defined on type 'User'");
+    }
+
+    #[test]
+    fn test_unknown_argument_suggests_similar_arguments() {
+        let error = validate_rules!(
+            FieldRules,
+            r#"type Query { users(first: Int, after: String): [String] }"#,
+            r#"query Q { users(frist: 10) }"#
+        )
+        .unwrap_err();
+
+        assert_eq!(error.code(), Some(ErrorCode::UnknownArgument));
+        assert_eq!(
+            error.message(),
+            "Unknown argument 'frist' on field 'Query.users'. Did you mean 'first'?"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_argument_has_related_location() {
+        let error = validate_rules!(
+            FieldRules,
+            r#"type Query { field(arg: String): String }"#,
+            "query Q {\n  field(arg: \"a\", arg: \"b\")\n}"
+        )
+        .unwrap_err();
+
+        assert_eq!(error.code(), Some(ErrorCode::DuplicateArgument));
+        assert_eq!(error.location().unwrap().column, Some(19));
+        assert_eq!(error.related().len(), 1);
+        assert_eq!(error.related()[0].location.line, 2);
+        assert_eq!(error.related()[0].location.column, Some(9));
+    }
+
+    #[test]
+    fn test_field_merging_conflict_has_related_location() {
+        let error = validate_rules!(
+            FieldRules,
+            r#"type Query { user: User } type User { name: String email: String }"#,
+            "query Q {\n  user {\n    label: name\n    label: email\n  }\n}"
+        )
+        .unwrap_err();
+
+        assert_eq!(error.code(), Some(ErrorCode::OverlappingFields));
+        assert_eq!(error.location().unwrap().line, 4);
+        assert_eq!(error.related().len(), 1);
+        assert_eq!(error.related()[0].message, "Conflicting field 'label' is selected here");
+        assert_eq!(error.related()[0].location.line, 3);
+    }
+
+    #[test]
+    fn test_field_merging_conflict_between_fragments_relates_both_fields() {
+        let error = validate_rules!(
+            FieldRules,
+            r#"type Query { user: User } type User { name: String email: String }"#,
+            r#"
+                fragment A on User { label: name }
+                fragment B on User { label: email }
+                query Q { user { ...A ...B } }
+            "#
+        )
+        .unwrap_err();
+
+        assert_eq!(error.location().unwrap().line, 4);
+        let related_lines: Vec<_> = error.related().iter().map(|r| r.location.line).collect();
+        assert_eq!(related_lines, [2, 3]);
+    }
 }
@@ -1,8 +1,11 @@
+use crate::error::ErrorCode;
 use crate::error::location::Span;
+use crate::error::suggestion::suggestion_list;
 use crate::graphql::ast::*;
+use crate::schema::SchemaIndex;
 use crate::validation::visitor::{Control, Visitor};
 use crate::validation::{ValidationContext, ValidationRule};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::marker::PhantomData;
 
 #[derive(Default)]
@@ -28,20 +31,8 @@ impl<'a, 'b> FragmentRules<'a, 'b> {
             return true;
         }
 
-        let fragment_possible_types: FxHashSet<&str> = ctx.schema().get_possible_types(fragment_type).collect();
-        let parent_possible_types: FxHashSet<&str> = ctx.schema().get_possible_types(parent_type).collect();
-
-        if fragment_possible_types.is_empty() && parent_possible_types.is_empty() {
-            return false;
-        }
-
-        if fragment_possible_types.is_empty() {
-            return parent_possible_types.contains(fragment_type);
-        }
-
-        if parent_possible_types.is_empty() {
-            return fragment_possible_types.contains(parent_type);
-        }
+        let fragment_possible_types = possible_types(ctx.schema(), fragment_type);
+        let parent_possible_types = possible_types(ctx.schema(), parent_type);
 
         fragment_possible_types
             .intersection(&parent_possible_types)
@@ -50,6 +41,24 @@ impl<'a, 'b> FragmentRules<'a, 'b> {
     }
 }
 
+/// Returns the composite types that are close to an unknown type name.
+fn composite_type_suggestions<'a>(schema: &SchemaIndex<'a>, type_name: &str) -> Vec<&'a str> {
+    let composite_types = schema
+        .types()
+        .map(|(name, _)| name)
+        .filter(|name| schema.is_composite(name));
+    suggestion_list(type_name, composite_types)
+}
+
+/// Returns the object types a selection on `type_name` can apply to.
+fn possible_types<'s, 'a: 's>(schema: &SchemaIndex<'a>, type_name: &'s str) -> FxHashSet<&'s str> {
+    if schema.is_abstract(type_name) {
+        schema.get_possible_types(type_name).collect()
+    } else {
+        FxHashSet::from_iter([type_name])
+    }
+}
+
 impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
     fn enter_selection_set(
         &mut self,
@@ -57,13 +66,14 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
         selection_set: &SelectionSet<'a>,
     ) -> Control {
         let mut seen_spreads: Vec<(&str, &[Argument<'a>])> = Vec::new();
-        for selection in &selection_set.selections {
+        for selection in selection_set.selections {
             if let Selection::FragmentSpread(spread) = selection {
                 let name = spread.fragment_name.as_str();
                 if let Some((_, existing_args)) = seen_spreads.iter().find(|(n, _)| *n == name)
-                    && !spread_arguments_are_equal(existing_args, &spread.arguments)
+                    && !spread_arguments_are_equal(existing_args, spread.arguments)
                 {
                     ctx.add_error(
+                        ErrorCode::ConflictingFragmentArguments,
                         format!(
                             "Fragment '{}' is spread multiple times with different arguments in the same selection set",
                             name
@@ -71,7 +81,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
                         spread.span,
                     );
                 }
-                seen_spreads.push((name, &spread.arguments));
+                seen_spreads.push((name, spread.arguments));
             }
         }
         Control::Next
@@ -81,7 +91,18 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
         let name = fragment.name.as_str();
 
         if self.fragment_names.contains(&name) {
-            ctx.add_error(format!("Duplicate fragment name '{}'", name), fragment.span);
+            let mut error = ctx.error(
+                ErrorCode::DuplicateFragmentName,
+                format!("Duplicate fragment name '{}'", name),
+                fragment.name.span(),
+            );
+            if let Some((_, _, first_span)) = self.fragment_definitions.iter().find(|(n, _, _)| *n == name) {
+                error = error.with_related(
+                    format!("Fragment '{}' is first defined here", name),
+                    ctx.locate(*first_span),
+                );
+            }
+            ctx.report(error);
             return Control::Break;
         }
 
@@ -90,13 +111,17 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
         let type_name = fragment.type_condition.as_str();
 
         if !ctx.schema().has_type(type_name) {
-            ctx.add_error(
-                format!(
-                    "Fragment '{}' is defined on type '{}', which does not exist in the schema",
-                    name, type_name
-                ),
-                fragment.span,
-            );
+            let error = ctx
+                .error(
+                    ErrorCode::UnknownType,
+                    format!(
+                        "Fragment '{}' is defined on type '{}', which does not exist in the schema",
+                        name, type_name
+                    ),
+                    fragment.type_condition.span(),
+                )
+                .with_suggestions(composite_type_suggestions(ctx.schema(), type_name));
+            ctx.report(error);
         }
 
         if ctx.schema().has_type(type_name)
@@ -104,16 +129,16 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
             && !ctx.schema().is_interface(type_name)
             && !ctx.schema().is_union(type_name)
         {
-            ctx.add_error(format!(
+            ctx.add_error(ErrorCode::FragmentOnNonCompositeType, format!(
                 "Fragment '{}' cannot be defined on non-composite type '{}'. Fragments can only be defined on object, interface, or union types.",
                 name, type_name
             ),
-            fragment.span,
+            fragment.type_condition.span(),
         );
         }
 
         self.current_fragment = Some(name);
-        self.current_fragment_span = Some(fragment.span);
+        self.current_fragment_span = Some(fragment.name.span());
         self.current_spreads = Vec::new();
         self.type_stack.push(Some(type_name));
         Control::Next
@@ -184,13 +209,17 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
             let type_name = type_condition.as_str();
 
             if !ctx.schema().has_type(type_name) {
-                ctx.add_error(
-                    format!(
-                        "Inline fragment is defined on type '{}', which does not exist in the schema",
-                        type_name
-                    ),
-                    inline_fragment.span,
-                );
+                let error = ctx
+                    .error(
+                        ErrorCode::UnknownType,
+                        format!(
+                            "Inline fragment is defined on type '{}', which does not exist in the schema",
+                            type_name
+                        ),
+                        type_condition.span(),
+                    )
+                    .with_suggestions(composite_type_suggestions(ctx.schema(), type_name));
+                ctx.report(error);
             }
 
             if ctx.schema().has_type(type_name)
@@ -198,11 +227,11 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
                 && !ctx.schema().is_interface(type_name)
                 && !ctx.schema().is_union(type_name)
             {
-                ctx.add_error(format!(
+                ctx.add_error(ErrorCode::FragmentOnNonCompositeType, format!(
                     "Inline fragment cannot be defined on non-composite type '{}'. Fragments can only be defined on object, interface, or union types.",
                     type_name
                 ),
-                inline_fragment.span,
+                type_condition.span(),
             );
             }
 
@@ -250,6 +279,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
             && !self.check_type_compatibility(ctx, fragment_type, parent_type)
         {
             ctx.add_error(
+                ErrorCode::ImpossibleFragmentSpread,
                 format!(
                     "Fragment '{}' cannot be spread on type '{}'. Fragment is defined on '{}'.",
                     fragment_name, parent_type, fragment_type
@@ -259,18 +289,27 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
         }
 
         if let Some(fragment) = fragment {
-            let mut seen_args = FxHashSet::default();
-            for arg in &fragment_spread.arguments {
+            let mut seen_args: FxHashMap<&str, Span> = FxHashMap::default();
+            for arg in fragment_spread.arguments {
                 let arg_name = arg.name.as_str();
 
-                if !seen_args.insert(arg_name) {
-                    ctx.add_error(
-                        format!(
-                            "Duplicate argument '{}' in spread of fragment '{}'",
-                            arg_name, fragment_name
-                        ),
-                        arg.span,
-                    );
+                if let Some(&first_span) = seen_args.get(arg_name) {
+                    let error = ctx
+                        .error(
+                            ErrorCode::DuplicateArgument,
+                            format!(
+                                "Duplicate argument '{}' in spread of fragment '{}'",
+                                arg_name, fragment_name
+                            ),
+                            arg.span,
+                        )
+                        .with_related(
+                            format!("Argument '{}' is first provided here", arg_name),
+                            ctx.locate(first_span),
+                        );
+                    ctx.report(error);
+                } else {
+                    seen_args.insert(arg_name, arg.span);
                 }
 
                 if !fragment
@@ -278,17 +317,25 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
                     .iter()
                     .any(|v| v.variable.as_str() == arg_name)
                 {
-                    ctx.add_error(
-                        format!(
-                            "Unknown argument '{}' on fragment '{}'. Fragment does not define this variable.",
-                            arg_name, fragment_name
-                        ),
-                        arg.span,
+                    let suggestions = suggestion_list(
+                        arg_name,
+                        fragment.variable_definitions.iter().map(|v| v.variable.as_str()),
                     );
+                    let error = ctx
+                        .error(
+                            ErrorCode::UnknownArgument,
+                            format!(
+                                "Unknown argument '{}' on fragment '{}'. Fragment does not define this variable.",
+                                arg_name, fragment_name
+                            ),
+                            arg.span,
+                        )
+                        .with_suggestions(suggestions);
+                    ctx.report(error);
                 }
             }
 
-            for var_def in &fragment.variable_definitions {
+            for var_def in fragment.variable_definitions {
                 let var_name = var_def.variable.as_str();
                 let is_required = matches!(var_def.typ, Type::NonNull(_)) && var_def.default_value.is_none();
 
@@ -297,6 +344,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
                     match provided {
                         None => {
                             ctx.add_error(
+                                ErrorCode::MissingRequiredArgument,
                                 format!(
                                     "Required argument '{}' is missing in spread of fragment '{}'",
                                     var_name, fragment_name
@@ -304,8 +352,9 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
                                 fragment_spread.span,
                             );
                         }
-                        Some(arg) if matches!(arg.value, Value::Null) => {
+                        Some(arg) if matches!(arg.value.kind, ValueKind::Null) => {
                             ctx.add_error(
+                                ErrorCode::InvalidValue,
                                 format!(
                                     "Required argument '{}' must not be null in spread of fragment '{}'",
                                     var_name, fragment_name
@@ -325,7 +374,20 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
     fn leave_document(&mut self, ctx: &mut ValidationContext<'a, 'b>, _document: &Document<'a>) -> Control {
         for (spread_name, spread_span) in &self.fragment_spreads {
             if !self.fragment_names.contains(spread_name) && ctx.document().get_fragment(spread_name).is_none() {
-                ctx.add_error("Only known fragments may occur in fragment spreads.", *spread_span);
+                let known_fragments = self
+                    .fragment_names
+                    .iter()
+                    .copied()
+                    .chain(ctx.document().fragments().map(|f| f.name.as_str()));
+                let suggestions = suggestion_list(spread_name, known_fragments);
+                let error = ctx
+                    .error(
+                        ErrorCode::UnknownFragment,
+                        format!("Unknown fragment '{}'", spread_name),
+                        *spread_span,
+                    )
+                    .with_suggestions(suggestions);
+                ctx.report(error);
             }
         }
 
@@ -334,7 +396,11 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for FragmentRules<'a, 'b> {
             let mut rec_stack = Vec::new();
 
             if has_cycle(fragment_name, &self.fragment_definitions, &mut visited, &mut rec_stack) {
-                ctx.add_error("Fragment definitions must not form cycles.", *fragment_span);
+                ctx.add_error(
+                    ErrorCode::FragmentCycle,
+                    "Fragment definitions must not form cycles.",
+                    *fragment_span,
+                );
                 break;
             }
         }
@@ -362,18 +428,18 @@ fn spread_arguments_are_equal<'a>(args1: &[Argument<'a>], args2: &[Argument<'a>]
 }
 
 fn values_are_equal<'a>(v1: &Value<'a>, v2: &Value<'a>) -> bool {
-    match (v1, v2) {
-        (Value::Variable(a), Value::Variable(b)) => a.as_str() == b.as_str(),
-        (Value::Int(a), Value::Int(b)) => a == b,
-        (Value::Float(a), Value::Float(b)) => a == b,
-        (Value::String(a), Value::String(b)) => a == b,
-        (Value::Boolean(a), Value::Boolean(b)) => a == b,
-        (Value::Null, Value::Null) => true,
-        (Value::Enum(a), Value::Enum(b)) => a.as_str() == b.as_str(),
-        (Value::List(a), Value::List(b)) => {
+    match (&v1.kind, &v2.kind) {
+        (ValueKind::Variable(a), ValueKind::Variable(b)) => a.as_str() == b.as_str(),
+        (ValueKind::Int(a), ValueKind::Int(b)) => a == b,
+        (ValueKind::Float(a), ValueKind::Float(b)) => a == b,
+        (ValueKind::String(a), ValueKind::String(b)) => a == b,
+        (ValueKind::Boolean(a), ValueKind::Boolean(b)) => a == b,
+        (ValueKind::Null, ValueKind::Null) => true,
+        (ValueKind::Enum(a), ValueKind::Enum(b)) => a.as_str() == b.as_str(),
+        (ValueKind::List(a), ValueKind::List(b)) => {
             a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| values_are_equal(x, y))
         }
-        (Value::Object(a), Value::Object(b)) => {
+        (ValueKind::Object(a), ValueKind::Object(b)) => {
             a.len() == b.len()
                 && a.iter().all(|f1| {
                     b.iter()
@@ -495,6 +561,41 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_fragment_on_intermediate_interface() {
+        let schema = r#"
+            type Query { node: Node resource: Resource file: File }
+            interface Node { id: ID! }
+            interface Resource implements Node { id: ID! url: String! }
+            type File implements Resource & Node { id: ID! url: String! }
+        "#;
+
+        assert_ok!(validate_rules!(
+            FragmentRules,
+            schema,
+            r#"fragment ResourceFields on Resource { url } query Q { node { ...ResourceFields } file { ...ResourceFields } }"#
+        ));
+        assert_ok!(validate_rules!(
+            FragmentRules,
+            schema,
+            r#"fragment NodeFields on Node { id } query Q { resource { ...NodeFields } }"#
+        ));
+    }
+
+    #[test]
+    fn test_fragment_on_interface_without_shared_implementors() {
+        assert_err!(validate_rules!(
+            FragmentRules,
+            r#"
+                type Query { node: Node }
+                interface Node { id: ID! }
+                interface Resource implements Node { id: ID! }
+                type User implements Node { id: ID! }
+            "#,
+            r#"fragment ResourceFields on Resource { id } query Q { node { ...ResourceFields } }"#
+        ));
+    }
+
     #[test]
     fn test_fragment_on_object_type() {
         assert_ok!(validate_rules!(
@@ -755,4 +856,36 @@ mod tests {
             r#"fragment Avatar($size: Int!) on User { profilePic(size: $size) } fragment Name on User { name } query Q { user { ...Avatar(size: 100) ...Name } }"#
         ));
     }
+
+    #[test]
+    fn test_unknown_fragment_suggests_similar_fragments() {
+        let error = validate_rules!(
+            FragmentRules,
+            r#"type Query { user: User } type User { id: ID! }"#,
+            r#"
+                fragment UserFields on User { id }
+                query Q { user { ...UserFeilds } }
+            "#
+        )
+        .unwrap_err();
+
+        assert_eq!(error.code(), Some(ErrorCode::UnknownFragment));
+        assert_eq!(
+            error.message(),
+            "Unknown fragment 'UserFeilds'. Did you mean 'UserFields'?"
+        );
+    }
+
+    #[test]
+    fn test_unknown_type_condition_suggests_composite_types() {
+        let error = validate_rules!(
+            FragmentRules,
+            r#"type Query { user: User } type User { id: ID! } scalar Uuid"#,
+            r#"fragment F on Usr { id }"#
+        )
+        .unwrap_err();
+
+        assert_eq!(error.code(), Some(ErrorCode::UnknownType));
+        assert_eq!(error.suggestions(), ["User"]);
+    }
 }
@@ -1,3 +1,4 @@
+pub mod deprecation;
 pub mod document;
 pub mod fields;
 pub mod fragments;
@@ -5,6 +6,7 @@ pub mod operations;
 pub mod values;
 pub mod variables;
 
+pub use deprecation::DeprecationRules;
 pub use document::DocumentRules;
 pub use fields::FieldRules;
 pub use fragments::FragmentRules;
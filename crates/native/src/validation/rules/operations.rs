@@ -1,3 +1,4 @@
+use crate::error::ErrorCode;
 use crate::error::location::Span;
 use crate::graphql::ast::*;
 use crate::validation::visitor::{Control, Visitor};
@@ -6,7 +7,7 @@ use std::marker::PhantomData;
 
 #[derive(Default)]
 pub struct OperationRules<'a, 'b> {
-    operation_names: Vec<&'a str>,
+    operation_names: Vec<(&'a str, Span)>,
     operations: usize,
     has_anonymous: bool,
     anonymous_span: Option<Span>,
@@ -19,22 +20,37 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for OperationRules<'a, 'b> {
 
         if let Some(name) = operation.name {
             let name_str = name.as_str();
-            if self.operation_names.contains(&name_str) {
-                ctx.add_error(format!("Duplicate operation name '{}'", name_str), operation.span);
+            if let Some(&(_, first_span)) = self.operation_names.iter().find(|(n, _)| *n == name_str) {
+                let error = ctx
+                    .error(
+                        ErrorCode::DuplicateOperationName,
+                        format!("Duplicate operation name '{}'", name_str),
+                        name.span(),
+                    )
+                    .with_related(
+                        format!("Operation '{}' is first defined here", name_str),
+                        ctx.locate(first_span),
+                    );
+                ctx.report(error);
                 return Control::Break;
             }
 
             if ctx.schema().has_type(name_str) {
                 ctx.add_error(
+                    ErrorCode::OperationNameConflict,
                     format!("Operation name '{}' conflicts with a schema type name", name_str),
-                    operation.span,
+                    name.span(),
                 );
                 return Control::Break;
             }
 
-            self.operation_names.push(name_str);
+            self.operation_names.push((name_str, name.span()));
         } else {
-            ctx.add_error("Operation must have a name", operation.span);
+            ctx.add_error(
+                ErrorCode::MissingOperationName,
+                "Operation must have a name",
+                operation.span,
+            );
             self.has_anonymous = true;
             self.anonymous_span = Some(operation.span);
             return Control::Break;
@@ -50,6 +66,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for OperationRules<'a, 'b> {
 
             if field_count != 1 {
                 ctx.add_error(
+                    ErrorCode::SingleRootField,
                     format!(
                         "Subscription operation must have exactly one root field, found {}",
                         field_count
@@ -70,6 +87,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for OperationRules<'a, 'b> {
         if self.has_anonymous && self.operations > 1 {
             let span = self.anonymous_span.unwrap_or(Span { start: 0, end: 0 });
             ctx.add_error(
+                ErrorCode::LoneAnonymousOperation,
                 "A document containing an anonymous operation must contain only that operation.",
                 span,
             );
@@ -1,8 +1,10 @@
+use crate::error::ErrorCode;
 use crate::error::location::Span;
+use crate::error::suggestion::suggestion_list;
 use crate::graphql::ast::*;
 use crate::validation::visitor::{Control, Visitor};
 use crate::validation::{ValidationContext, ValidationRule};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::marker::PhantomData;
 
 #[derive(Default)]
@@ -38,28 +40,45 @@ impl<'a, 'b> ValueRules<'a, 'b> {
         directives: &[Directive<'a>],
         location: DirectiveLocation,
     ) {
-        let mut seen_directives = FxHashSet::default();
+        let mut seen_directives: FxHashMap<&str, Span> = FxHashMap::default();
         self.current_location = Some(location);
 
         for directive in directives {
             let directive_name = directive.name.as_str();
 
-            if !seen_directives.insert(directive_name) {
-                ctx.add_error(
-                    format!(
-                        "Directive '@{}' is used more than once at this location",
-                        directive_name
-                    ),
-                    directive.span,
-                );
+            if let Some(&first_span) = seen_directives.get(directive_name) {
+                let error = ctx
+                    .error(
+                        ErrorCode::DuplicateDirective,
+                        format!(
+                            "Directive '@{}' is used more than once at this location",
+                            directive_name
+                        ),
+                        directive.span,
+                    )
+                    .with_related(
+                        format!("Directive '@{}' is first used here", directive_name),
+                        ctx.locate(first_span),
+                    );
+                ctx.report(error);
+            } else {
+                seen_directives.insert(directive_name, directive.span);
             }
 
             let allowed_locations = if let Some(locations) = Self::get_built_in_directive_locations(directive_name) {
                 Some(locations)
             } else if let Some(custom_directive) = ctx.schema().get_directive(directive_name) {
-                Some(&custom_directive.locations[..])
+                Some(custom_directive.locations)
             } else {
-                ctx.add_error(format!("Unknown directive '@{}'", directive_name), directive.span);
+                let suggestions = suggestion_list(directive_name, ctx.schema().directives().map(|d| d.name.as_str()));
+                let error = ctx
+                    .error(
+                        ErrorCode::UnknownDirective,
+                        format!("Unknown directive '@{}'", directive_name),
+                        directive.span,
+                    )
+                    .with_suggestions(suggestions);
+                ctx.report(error);
                 None
             };
 
@@ -67,6 +86,7 @@ impl<'a, 'b> ValueRules<'a, 'b> {
                 && !allowed_locations.contains(&location)
             {
                 ctx.add_error(
+                    ErrorCode::MisplacedDirective,
                     format!(
                         "Directive '@{}' is not allowed in location '{:?}'",
                         directive_name, location
@@ -77,54 +97,53 @@ impl<'a, 'b> ValueRules<'a, 'b> {
         }
     }
 
-    fn validate_input_value(
-        &self,
-        ctx: &mut ValidationContext<'a, 'b>,
-        value: &Value<'a>,
-        expected_type: &Type<'a>,
-        value_span: Span,
-    ) {
+    fn validate_input_value(&self, ctx: &mut ValidationContext<'a, 'b>, value: &Value<'a>, expected_type: &Type<'a>) {
         let type_name = get_named_type(expected_type);
 
         if let Type::NonNull(_) = expected_type
-            && matches!(value, Value::Null)
+            && matches!(value.kind, ValueKind::Null)
         {
             ctx.add_error(
+                ErrorCode::InvalidValue,
                 format!(
                     "Expected non-null value for type '{}', but got null",
                     type_to_string_ref(expected_type)
                 ),
-                value_span,
+                value.span,
             );
             return;
         }
 
-        if matches!(value, Value::Null | Value::Variable(_)) {
+        if matches!(value.kind, ValueKind::Null | ValueKind::Variable(_)) {
             return;
         }
 
-        match value {
-            Value::Object(fields) => {
-                self.check_field_uniqueness(ctx, fields);
-
+        match &value.kind {
+            ValueKind::Object(fields) => {
                 if let Some(input_obj_def) = ctx.schema().get_input_object_type(type_name) {
                     let provided_fields: FxHashSet<&str> = fields.iter().map(|f| f.name.as_str()).collect();
 
-                    for field in fields {
+                    for field in fields.iter() {
                         let field_name = field.name.as_str();
 
                         let field_def = input_obj_def.fields.iter().find(|f| f.name.as_str() == field_name);
 
                         if field_def.is_none() {
-                            ctx.add_error(
-                                format!(
-                                    "Field '{}' is not defined on input object type '{}'",
-                                    field_name, type_name
-                                ),
-                                value_span,
-                            );
+                            let suggestions =
+                                suggestion_list(field_name, input_obj_def.fields.iter().map(|f| f.name.as_str()));
+                            let error = ctx
+                                .error(
+                                    ErrorCode::UnknownInputField,
+                                    format!(
+                                        "Field '{}' is not defined on input object type '{}'",
+                                        field_name, type_name
+                                    ),
+                                    field.name.span(),
+                                )
+                                .with_suggestions(suggestions);
+                            ctx.report(error);
                         } else if let Some(field_def) = field_def {
-                            self.validate_input_value(ctx, &field.value, &field_def.typ, value_span);
+                            self.validate_input_value(ctx, &field.value, &field_def.typ);
                         }
                     }
 
@@ -135,11 +154,12 @@ impl<'a, 'b> ValueRules<'a, 'b> {
 
                         if is_required && !provided_fields.contains(field_name) {
                             ctx.add_error(
+                                ErrorCode::MissingRequiredInputField,
                                 format!(
                                     "Required field '{}' on input object type '{}' is not provided",
                                     field_name, type_name
                                 ),
-                                value_span,
+                                value.span,
                             );
                         }
                     }
@@ -149,65 +169,74 @@ impl<'a, 'b> ValueRules<'a, 'b> {
                     || ctx.schema().is_input_object(type_name)
                 {
                     ctx.add_error(
+                        ErrorCode::InvalidValue,
                         format!(
                             "Expected input object type '{}', but got object value for non-input-object type",
                             type_name
                         ),
-                        value_span,
+                        value.span,
                     );
                 }
             }
-            Value::List(values) => {
+            ValueKind::List(values) => {
                 if let Type::List(inner_type) = expected_type {
-                    for val in values {
-                        self.validate_input_value(ctx, val, inner_type, value_span);
+                    for val in values.iter() {
+                        self.validate_input_value(ctx, val, inner_type);
                     }
                 } else if let Type::NonNull(NonNullType::List(inner_type)) = expected_type {
-                    for val in values {
-                        self.validate_input_value(ctx, val, inner_type, value_span);
+                    for val in values.iter() {
+                        self.validate_input_value(ctx, val, inner_type);
                     }
                 } else {
                     ctx.add_error(
+                        ErrorCode::InvalidValue,
                         format!(
                             "Expected type '{}', but got list value",
                             type_to_string_ref(expected_type)
                         ),
-                        value_span,
+                        value.span,
                     );
                 }
             }
-            Value::Enum(enum_value) => {
+            ValueKind::Enum(enum_value) => {
                 if let Some(enum_def) = ctx.schema().get_enum_type(type_name) {
                     let enum_val_str = enum_value.as_str();
                     if !enum_def.values.iter().any(|v| v.value.as_str() == enum_val_str) {
                         ctx.add_error(
+                            ErrorCode::InvalidValue,
                             format!(
                                 "Value '{}' is not a valid value for enum type '{}'",
                                 enum_val_str, type_name
                             ),
-                            value_span,
+                            value.span,
                         );
                     }
                 } else {
                     ctx.add_error(
+                        ErrorCode::InvalidValue,
                         format!(
                             "Expected enum type '{}', but got enum value for non-enum type",
                             type_name
                         ),
-                        value_span,
+                        value.span,
                     );
                 }
             }
-            Value::Int(_) if type_name != "Int" && type_name != "Float" => {
-                ctx.add_error(format!("Expected type '{}', but got Int value", type_name), value_span);
+            ValueKind::Int(_) if type_name != "Int" && type_name != "Float" => {
+                ctx.add_error(
+                    ErrorCode::InvalidValue,
+                    format!("Expected type '{}', but got Int value", type_name),
+                    value.span,
+                );
             }
-            Value::Float(_) if type_name != "Float" => {
+            ValueKind::Float(_) if type_name != "Float" => {
                 ctx.add_error(
+                    ErrorCode::InvalidValue,
                     format!("Expected type '{}', but got Float value", type_name),
-                    value_span,
+                    value.span,
                 );
             }
-            Value::String(_) => {
+            ValueKind::String(_) => {
                 let is_built_in_scalar = type_name == "Int" || type_name == "Float" || type_name == "Boolean";
                 let is_custom_scalar = ctx.schema().is_scalar(type_name)
                     && !is_built_in_scalar
@@ -216,48 +245,54 @@ impl<'a, 'b> ValueRules<'a, 'b> {
 
                 if type_name != "String" && type_name != "ID" && !is_custom_scalar {
                     ctx.add_error(
+                        ErrorCode::InvalidValue,
                         format!("Expected type '{}', but got String value", type_name),
-                        value_span,
+                        value.span,
                     );
                 }
             }
-            Value::Boolean(_) if type_name != "Boolean" => {
+            ValueKind::Boolean(_) if type_name != "Boolean" => {
                 ctx.add_error(
+                    ErrorCode::InvalidValue,
                     format!("Expected type '{}', but got Boolean value", type_name),
-                    value_span,
+                    value.span,
                 );
             }
             _ => {}
         }
     }
 
-    fn check_field_uniqueness(&self, ctx: &mut ValidationContext<'a, 'b>, fields: &[ObjectField]) {
-        let mut seen_fields = FxHashSet::default();
+    fn check_field_uniqueness(&self, ctx: &mut ValidationContext<'a, 'b>, fields: &[ObjectField<'a>]) {
+        let mut seen_fields = FxHashMap::default();
 
         for field in fields {
             let field_name = field.name.as_str();
 
-            if !seen_fields.insert(field_name) {
-                ctx.add_error(format!("Duplicate input object field '{}'", field_name), Span::empty());
+            if let Some(&first_span) = seen_fields.get(field_name) {
+                let error = ctx
+                    .error(
+                        ErrorCode::DuplicateInputField,
+                        format!("Duplicate input object field '{}'", field_name),
+                        field.span,
+                    )
+                    .with_related(
+                        format!("Field '{}' is first provided here", field_name),
+                        ctx.locate(first_span),
+                    );
+                ctx.report(error);
+            } else {
+                seen_fields.insert(field_name, field.span);
             }
 
-            if let Value::Object(nested_fields) = &field.value {
-                self.check_field_uniqueness(ctx, nested_fields);
-            } else if let Value::List(values) = &field.value {
-                for value in values {
-                    if let Value::Object(nested_fields) = value {
-                        self.check_field_uniqueness(ctx, nested_fields);
-                    }
-                }
-            }
+            self.check_argument_input_uniqueness(ctx, &field.value);
         }
     }
 
     fn check_argument_input_uniqueness(&self, ctx: &mut ValidationContext<'a, 'b>, value: &Value<'a>) {
-        if let Value::Object(fields) = value {
+        if let ValueKind::Object(fields) = &value.kind {
             self.check_field_uniqueness(ctx, fields);
-        } else if let Value::List(values) = value {
-            for val in values {
+        } else if let ValueKind::List(values) = &value.kind {
+            for val in values.iter() {
                 self.check_argument_input_uniqueness(ctx, val);
             }
         }
@@ -271,7 +306,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for ValueRules<'a, 'b> {
             OperationType::Mutation => DirectiveLocation::Mutation,
             OperationType::Subscription => DirectiveLocation::Subscription,
         };
-        self.check_directive_uniqueness_and_validity(ctx, &operation.directives, location);
+        self.check_directive_uniqueness_and_validity(ctx, operation.directives, location);
 
         let root_type = match operation.operation_type {
             OperationType::Query => ctx.schema().query_type().or(Some("Query")),
@@ -293,7 +328,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for ValueRules<'a, 'b> {
     }
 
     fn enter_field(&mut self, ctx: &mut ValidationContext<'a, 'b>, field: &Field<'a>) -> Control {
-        self.check_directive_uniqueness_and_validity(ctx, &field.directives, DirectiveLocation::Field);
+        self.check_directive_uniqueness_and_validity(ctx, field.directives, DirectiveLocation::Field);
 
         self.current_field_name = Some(field.name.as_str());
 
@@ -322,7 +357,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for ValueRules<'a, 'b> {
     }
 
     fn enter_fragment(&mut self, ctx: &mut ValidationContext<'a, 'b>, fragment: &FragmentDefinition<'a>) -> Control {
-        self.check_directive_uniqueness_and_validity(ctx, &fragment.directives, DirectiveLocation::FragmentDefinition);
+        self.check_directive_uniqueness_and_validity(ctx, fragment.directives, DirectiveLocation::FragmentDefinition);
         self.type_stack.push(Some(fragment.type_condition.as_str()));
         Control::Next
     }
@@ -339,7 +374,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for ValueRules<'a, 'b> {
     ) -> Control {
         self.check_directive_uniqueness_and_validity(
             ctx,
-            &fragment_spread.directives,
+            fragment_spread.directives,
             DirectiveLocation::FragmentSpread,
         );
         Control::Next
@@ -352,7 +387,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for ValueRules<'a, 'b> {
     ) -> Control {
         self.check_directive_uniqueness_and_validity(
             ctx,
-            &inline_fragment.directives,
+            inline_fragment.directives,
             DirectiveLocation::InlineFragment,
         );
         Control::Next
@@ -363,10 +398,11 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for ValueRules<'a, 'b> {
         ctx: &mut ValidationContext<'a, 'b>,
         var_def: &VariableDefinition<'a>,
     ) -> Control {
-        self.check_directive_uniqueness_and_validity(ctx, &var_def.directives, DirectiveLocation::VariableDefinition);
+        self.check_directive_uniqueness_and_validity(ctx, var_def.directives, DirectiveLocation::VariableDefinition);
 
         if let Some(default_value) = &var_def.default_value {
-            self.validate_input_value(ctx, default_value, &var_def.typ, var_def.span);
+            self.check_argument_input_uniqueness(ctx, default_value);
+            self.validate_input_value(ctx, default_value, &var_def.typ);
         }
         Control::Next
     }
@@ -383,7 +419,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for ValueRules<'a, 'b> {
                 .iter()
                 .find(|a| a.name.as_str() == argument.name.as_str())
         {
-            self.validate_input_value(ctx, &argument.value, &arg_def.typ, argument.span);
+            self.validate_input_value(ctx, &argument.value, &arg_def.typ);
         }
 
         Control::Next
@@ -856,4 +892,60 @@ mod tests {
             r#"query Q { field(arg: null) }"#
         ));
     }
+
+    #[test]
+    fn test_unknown_directive_suggests_similar_directives() {
+        let error = validate_rules!(
+            ValueRules,
+            r#"type Query { field: String }"#,
+            r#"query Q { field @skp(if: true) }"#
+        )
+        .unwrap_err();
+
+        assert_eq!(error.code(), Some(ErrorCode::UnknownDirective));
+        assert_eq!(error.message(), "Unknown directive '@skp'. Did you mean 'skip'?");
+    }
+
+    #[test]
+    fn test_unknown_input_field_suggests_similar_fields() {
+        let error = validate_rules!(
+            ValueRules,
+            r#"type Query { users(filter: Filter): [String] } input Filter { name: String }"#,
+            r#"query Q { users(filter: { nmae: "a" }) }"#
+        )
+        .unwrap_err();
+
+        assert_eq!(error.code(), Some(ErrorCode::UnknownInputField));
+        assert_eq!(error.suggestions(), ["name"]);
+    }
+
+    #[test]
+    fn test_duplicate_input_field_points_at_duplicate() {
+        let error = validate_rules!(
+            ValueRules,
+            r#"type Query { field(arg: UserInput): String } input UserInput { name: String email: String }"#,
+            "query Q {\n  field(arg: { name: \"a\", name: \"b\" })\n}"
+        )
+        .unwrap_err();
+
+        assert_eq!(error.code(), Some(ErrorCode::DuplicateInputField));
+        let location = error.location().unwrap();
+        assert_eq!((location.line, location.column), (2, Some(27)));
+        assert_eq!((location.end_line, location.end_column), (Some(2), Some(36)));
+        assert_eq!(error.related()[0].location.column, Some(16));
+    }
+
+    #[test]
+    fn test_invalid_list_item_points_at_item() {
+        let error = validate_rules!(
+            ValueRules,
+            r#"type Query { field(arg: [Int]): String }"#,
+            r#"query Q { field(arg: [1, "two", 3]) }"#
+        )
+        .unwrap_err();
+
+        assert_eq!(error.message(), "Expected type 'Int', but got String value");
+        let location = error.location().unwrap();
+        assert_eq!((location.column, location.end_column), (Some(26), Some(31)));
+    }
 }
@@ -1,3 +1,4 @@
+use crate::error::ErrorCode;
 use crate::error::location::Span;
 use crate::graphql::ast::*;
 use crate::validation::visitor::{Control, Visitor};
@@ -57,17 +58,17 @@ pub struct VariableRules<'a, 'b> {
 
 impl<'a, 'b> VariableRules<'a, 'b> {
     fn collect_variables_from_value(&mut self, value: &Value<'a>) {
-        match value {
-            Value::Variable(var) => {
+        match &value.kind {
+            ValueKind::Variable(var) => {
                 self.current_used_vars.push(var.as_str());
             }
-            Value::List(list) => {
-                for item in list {
+            ValueKind::List(list) => {
+                for item in list.iter() {
                     self.collect_variables_from_value(item);
                 }
             }
-            Value::Object(fields) => {
-                for field in fields {
+            ValueKind::Object(fields) => {
+                for field in fields.iter() {
                     self.collect_variables_from_value(&field.value);
                 }
             }
@@ -76,8 +77,8 @@ impl<'a, 'b> VariableRules<'a, 'b> {
     }
 
     fn collect_fragment_variables_from_value(&mut self, value: &Value<'a>) {
-        match value {
-            Value::Variable(var) => {
+        match &value.kind {
+            ValueKind::Variable(var) => {
                 let var_name = var.as_str();
                 if self.fragment_variable_definitions.contains_key(var_name) {
                     self.fragment_used_vars.push(var_name);
@@ -85,13 +86,13 @@ impl<'a, 'b> VariableRules<'a, 'b> {
                     self.fragment_op_var_refs.push(var_name);
                 }
             }
-            Value::List(list) => {
-                for item in list {
+            ValueKind::List(list) => {
+                for item in list.iter() {
                     self.collect_fragment_variables_from_value(item);
                 }
             }
-            Value::Object(fields) => {
-                for field in fields {
+            ValueKind::Object(fields) => {
+                for field in fields.iter() {
                     self.collect_fragment_variables_from_value(&field.value);
                 }
             }
@@ -99,31 +100,26 @@ impl<'a, 'b> VariableRules<'a, 'b> {
         }
     }
 
-    fn collect_variable_usages_from_value(
-        &mut self,
-        value: &Value<'a>,
-        expected_type: Option<&'a Type<'a>>,
-        value_span: Span,
-    ) {
-        match value {
-            Value::Variable(var) => {
+    fn collect_variable_usages_from_value(&mut self, value: &Value<'a>, expected_type: Option<&'a Type<'a>>) {
+        match &value.kind {
+            ValueKind::Variable(var) => {
                 self.variable_usage_infos.push(VariableUsageInfo {
                     var_name: var.as_str(),
                     expected_type,
-                    usage_span: value_span,
+                    usage_span: value.span,
                 });
             }
-            Value::List(list) => {
+            ValueKind::List(list) => {
                 let inner_type = expected_type.and_then(|t| match t {
                     Type::List(inner) => Some(&**inner),
                     Type::NonNull(NonNullType::List(inner)) => Some(&**inner),
                     _ => None,
                 });
-                for item in list {
-                    self.collect_variable_usages_from_value(item, inner_type, value_span);
+                for item in list.iter() {
+                    self.collect_variable_usages_from_value(item, inner_type);
                 }
             }
-            Value::Object(_fields) => {}
+            ValueKind::Object(_fields) => {}
             _ => {}
         }
     }
@@ -217,8 +213,18 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for VariableRules<'a, 'b> {
         let name = var_def.variable.as_str();
 
         if self.current_fragment_name.is_some() {
-            if self.fragment_variable_names.contains(&name) {
-                ctx.add_error(format!("Duplicate variable name '${}'", name), var_def.span);
+            if let Some(first) = self.fragment_variable_definitions.get(name) {
+                let error = ctx
+                    .error(
+                        ErrorCode::DuplicateVariableName,
+                        format!("Duplicate variable name '${}'", name),
+                        var_def.span,
+                    )
+                    .with_related(
+                        format!("Variable '${}' is first defined here", name),
+                        ctx.locate(first.span),
+                    );
+                ctx.report(error);
             }
             self.fragment_variable_names.push(name);
             self.fragment_defined_vars.push(name);
@@ -229,7 +235,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for VariableRules<'a, 'b> {
                 || ctx.schema().is_input_object(type_name);
 
             if ctx.schema().has_type(type_name) && !is_input_type {
-                ctx.add_error(format!(
+                ctx.add_error(ErrorCode::NonInputVariableType, format!(
                     "Variable '${} is declared with type '{}', which is not an input type. Variables must be input types (scalar, enum, or input object).",
                     name, type_name
                 ), var_def.span);
@@ -244,8 +250,18 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for VariableRules<'a, 'b> {
                 },
             );
         } else {
-            if self.variable_names.contains(&name) {
-                ctx.add_error(format!("Duplicate variable name '${}'", name), var_def.span);
+            if let Some(first) = self.variable_definitions.get(name) {
+                let error = ctx
+                    .error(
+                        ErrorCode::DuplicateVariableName,
+                        format!("Duplicate variable name '${}'", name),
+                        var_def.span,
+                    )
+                    .with_related(
+                        format!("Variable '${}' is first defined here", name),
+                        ctx.locate(first.span),
+                    );
+                ctx.report(error);
             }
 
             self.variable_names.push(name);
@@ -257,7 +273,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for VariableRules<'a, 'b> {
                 || ctx.schema().is_input_object(type_name);
 
             if ctx.schema().has_type(type_name) && !is_input_type {
-                ctx.add_error(format!(
+                ctx.add_error(ErrorCode::NonInputVariableType, format!(
                     "Variable '${} is declared with type '{}', which is not an input type. Variables must be input types (scalar, enum, or input object).",
                     name, type_name
                 ), var_def.span);
@@ -320,7 +336,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for VariableRules<'a, 'b> {
             && let Some(field_def) = ctx.schema().get_field(parent_type_name, field_name)
             && let Some(arg_def) = field_def.arguments.iter().find(|a| a.name == argument.name.as_str())
         {
-            self.collect_variable_usages_from_value(&argument.value, Some(&arg_def.typ), argument.span);
+            self.collect_variable_usages_from_value(&argument.value, Some(&arg_def.typ));
         }
 
         Control::Next
@@ -342,6 +358,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for VariableRules<'a, 'b> {
                 && !self.is_type_compatible(&var_info.typ, expected_type, var_info.has_default_value)
             {
                 ctx.add_error(
+                    ErrorCode::VariableTypeMismatch,
                     format!(
                         "Variable '{}' of type '{}' cannot be used where '{}' is expected",
                         usage.var_name,
@@ -397,6 +414,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for VariableRules<'a, 'b> {
         for defined_var in &self.fragment_defined_vars {
             if !self.fragment_used_vars.contains(defined_var) {
                 ctx.add_error(
+                    ErrorCode::UnusedVariable,
                     format!(
                         "Variable '{}' is defined but not used in fragment '{}'",
                         defined_var, fragment_name
@@ -454,6 +472,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for VariableRules<'a, 'b> {
             for used_var in &all_used_vars {
                 if !defined_vars.contains(used_var) {
                     ctx.add_error(
+                        ErrorCode::UndefinedVariable,
                         format!("Variable '{}' is not defined in operation '{}'", used_var, op_name),
                         *op_span,
                     );
@@ -463,6 +482,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for VariableRules<'a, 'b> {
             for defined_var in defined_vars {
                 if !all_used_vars.contains(defined_var) {
                     ctx.add_error(
+                        ErrorCode::UnusedVariable,
                         format!(
                             "Variable '{}' is defined but not used in operation '{}'",
                             defined_var, op_name
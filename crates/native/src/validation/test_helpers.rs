@@ -19,6 +19,7 @@ macro_rules! validate_rules {
         let schema_source = Source::ephemeral($schema_code);
         let schema_document = Parser::new(&arena).with_source(&schema_source).parse().unwrap();
         schema_builder.add_document(schema_document).unwrap();
+        assert!(schema_builder.apply_extensions(&arena).is_empty());
 
         let schema_index = schema_builder.build();
 
@@ -13,6 +13,7 @@ pub struct Validator<'a, 'b> {
     fragments: FragmentRules<'a, 'b>,
     variables: VariableRules<'a, 'b>,
     values: ValueRules<'a, 'b>,
+    deprecation: DeprecationRules<'a, 'b>,
 }
 
 impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
@@ -23,6 +24,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.enter_document(ctx, document);
         self.variables.enter_document(ctx, document);
         self.values.enter_document(ctx, document);
+        self.deprecation.enter_document(ctx, document);
         Control::Next
     }
 
@@ -33,6 +35,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.leave_document(ctx, document);
         self.variables.leave_document(ctx, document);
         self.values.leave_document(ctx, document);
+        self.deprecation.leave_document(ctx, document);
         Control::Next
     }
 
@@ -43,6 +46,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.enter_operation(ctx, operation);
         self.variables.enter_operation(ctx, operation);
         self.values.enter_operation(ctx, operation);
+        self.deprecation.enter_operation(ctx, operation);
         Control::Next
     }
 
@@ -53,6 +57,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.leave_operation(ctx, operation);
         self.variables.leave_operation(ctx, operation);
         self.values.leave_operation(ctx, operation);
+        self.deprecation.leave_operation(ctx, operation);
         Control::Next
     }
 
@@ -63,6 +68,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.enter_fragment(ctx, fragment);
         self.variables.enter_fragment(ctx, fragment);
         self.values.enter_fragment(ctx, fragment);
+        self.deprecation.enter_fragment(ctx, fragment);
         Control::Next
     }
 
@@ -73,6 +79,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.leave_fragment(ctx, fragment);
         self.variables.leave_fragment(ctx, fragment);
         self.values.leave_fragment(ctx, fragment);
+        self.deprecation.leave_fragment(ctx, fragment);
         Control::Next
     }
 
@@ -87,6 +94,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.enter_selection_set(ctx, selection_set);
         self.variables.enter_selection_set(ctx, selection_set);
         self.values.enter_selection_set(ctx, selection_set);
+        self.deprecation.enter_selection_set(ctx, selection_set);
         Control::Next
     }
 
@@ -101,6 +109,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.leave_selection_set(ctx, selection_set);
         self.variables.leave_selection_set(ctx, selection_set);
         self.values.leave_selection_set(ctx, selection_set);
+        self.deprecation.leave_selection_set(ctx, selection_set);
         Control::Next
     }
 
@@ -111,6 +120,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.enter_field(ctx, field);
         self.variables.enter_field(ctx, field);
         self.values.enter_field(ctx, field);
+        self.deprecation.enter_field(ctx, field);
         Control::Next
     }
 
@@ -121,6 +131,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.leave_field(ctx, field);
         self.variables.leave_field(ctx, field);
         self.values.leave_field(ctx, field);
+        self.deprecation.leave_field(ctx, field);
         Control::Next
     }
 
@@ -131,6 +142,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.enter_argument(ctx, argument);
         self.variables.enter_argument(ctx, argument);
         self.values.enter_argument(ctx, argument);
+        self.deprecation.enter_argument(ctx, argument);
         Control::Next
     }
 
@@ -141,6 +153,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.leave_argument(ctx, argument);
         self.variables.leave_argument(ctx, argument);
         self.values.leave_argument(ctx, argument);
+        self.deprecation.leave_argument(ctx, argument);
         Control::Next
     }
 
@@ -155,6 +168,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.enter_fragment_spread(ctx, fragment_spread);
         self.variables.enter_fragment_spread(ctx, fragment_spread);
         self.values.enter_fragment_spread(ctx, fragment_spread);
+        self.deprecation.enter_fragment_spread(ctx, fragment_spread);
         Control::Next
     }
 
@@ -169,6 +183,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.leave_fragment_spread(ctx, fragment_spread);
         self.variables.leave_fragment_spread(ctx, fragment_spread);
         self.values.leave_fragment_spread(ctx, fragment_spread);
+        self.deprecation.leave_fragment_spread(ctx, fragment_spread);
         Control::Next
     }
 
@@ -183,6 +198,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.enter_inline_fragment(ctx, inline_fragment);
         self.variables.enter_inline_fragment(ctx, inline_fragment);
         self.values.enter_inline_fragment(ctx, inline_fragment);
+        self.deprecation.enter_inline_fragment(ctx, inline_fragment);
         Control::Next
     }
 
@@ -197,6 +213,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.leave_inline_fragment(ctx, inline_fragment);
         self.variables.leave_inline_fragment(ctx, inline_fragment);
         self.values.leave_inline_fragment(ctx, inline_fragment);
+        self.deprecation.leave_inline_fragment(ctx, inline_fragment);
         Control::Next
     }
 
@@ -211,6 +228,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.enter_variable_definition(ctx, variable_definition);
         self.variables.enter_variable_definition(ctx, variable_definition);
         self.values.enter_variable_definition(ctx, variable_definition);
+        self.deprecation.enter_variable_definition(ctx, variable_definition);
         Control::Next
     }
 
@@ -225,6 +243,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.leave_variable_definition(ctx, variable_definition);
         self.variables.leave_variable_definition(ctx, variable_definition);
         self.values.leave_variable_definition(ctx, variable_definition);
+        self.deprecation.leave_variable_definition(ctx, variable_definition);
         Control::Next
     }
 
@@ -235,6 +254,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.enter_directive(ctx, directive);
         self.variables.enter_directive(ctx, directive);
         self.values.enter_directive(ctx, directive);
+        self.deprecation.enter_directive(ctx, directive);
         Control::Next
     }
 
@@ -245,6 +265,7 @@ impl<'a, 'b> Visitor<'a, ValidationContext<'a, 'b>> for Validator<'a, 'b> {
         self.fragments.leave_directive(ctx, directive);
         self.variables.leave_directive(ctx, directive);
         self.values.leave_directive(ctx, directive);
+        self.deprecation.leave_directive(ctx, directive);
         Control::Next
     }
 }
@@ -112,7 +112,7 @@ impl<'a> VisitNode<'a> for Document<'a> {
     fn visit<C, V: Visitor<'a, C>>(&'a self, ctx: &mut C, visitor: &mut V) -> Control {
         let flow = visitor.enter_document(ctx, self);
         if let Control::Next = flow {
-            for definition in &self.definitions {
+            for definition in self.definitions {
                 match definition {
                     Definition::Executable(exec_def) => match exec_def {
                         ExecutableDefinition::Operation(op) => {
@@ -143,13 +143,13 @@ impl<'a> VisitNode<'a> for OperationDefinition<'a> {
             flow => return flow,
         }
 
-        for var_def in &self.variable_definitions {
+        for var_def in self.variable_definitions {
             if var_def.visit(ctx, visitor) == Control::Break {
                 return Control::Break;
             }
         }
 
-        if visit_directives(&self.directives, ctx, visitor) == Control::Break {
+        if visit_directives(self.directives, ctx, visitor) == Control::Break {
             return Control::Break;
         }
 
@@ -168,13 +168,13 @@ impl<'a> VisitNode<'a> for FragmentDefinition<'a> {
             flow => return flow,
         }
 
-        for var_def in &self.variable_definitions {
+        for var_def in self.variable_definitions {
             if var_def.visit(ctx, visitor) == Control::Break {
                 return Control::Break;
             }
         }
 
-        if visit_directives(&self.directives, ctx, visitor) == Control::Break {
+        if visit_directives(self.directives, ctx, visitor) == Control::Break {
             return Control::Break;
         }
 
@@ -193,7 +193,7 @@ impl<'a> VisitNode<'a> for VariableDefinition<'a> {
             flow => return flow,
         }
 
-        if visit_directives(&self.directives, ctx, visitor) == Control::Break {
+        if visit_directives(self.directives, ctx, visitor) == Control::Break {
             return Control::Break;
         }
 
@@ -205,7 +205,7 @@ impl<'a> VisitNode<'a> for SelectionSet<'a> {
     fn visit<C, V: Visitor<'a, C>>(&'a self, ctx: &mut C, visitor: &mut V) -> Control {
         let flow = visitor.enter_selection_set(ctx, self);
         if let Control::Next = flow {
-            for selection in &self.selections {
+            for selection in self.selections {
                 let flow = match selection {
                     Selection::Field(field) => field.visit(ctx, visitor),
                     Selection::FragmentSpread(spread) => spread.visit(ctx, visitor),
@@ -229,13 +229,13 @@ impl<'a> VisitNode<'a> for Field<'a> {
             flow => return flow,
         }
 
-        for argument in &self.arguments {
+        for argument in self.arguments {
             if argument.visit(ctx, visitor) == Control::Break {
                 return Control::Break;
             }
         }
 
-        if visit_directives(&self.directives, ctx, visitor) == Control::Break {
+        if visit_directives(self.directives, ctx, visitor) == Control::Break {
             return Control::Break;
         }
 
@@ -254,13 +254,13 @@ impl<'a> VisitNode<'a> for FragmentSpread<'a> {
             flow => return flow,
         }
 
-        for argument in &self.arguments {
+        for argument in self.arguments {
             if argument.visit(ctx, visitor) == Control::Break {
                 return Control::Break;
             }
         }
 
-        if visit_directives(&self.directives, ctx, visitor) == Control::Break {
+        if visit_directives(self.directives, ctx, visitor) == Control::Break {
             return Control::Break;
         }
 
@@ -275,7 +275,7 @@ impl<'a> VisitNode<'a> for InlineFragment<'a> {
             flow => return flow,
         }
 
-        if visit_directives(&self.directives, ctx, visitor) == Control::Break {
+        if visit_directives(self.directives, ctx, visitor) == Control::Break {
             return Control::Break;
         }
 
@@ -291,7 +291,7 @@ impl<'a> VisitNode<'a> for Directive<'a> {
     fn visit<C, V: Visitor<'a, C>>(&'a self, ctx: &mut C, visitor: &mut V) -> Control {
         let flow = visitor.enter_directive(ctx, self);
         if let Control::Next = flow {
-            for argument in &self.arguments {
+            for argument in self.arguments {
                 if argument.visit(ctx, visitor) == Control::Break {
                     return Control::Break;
                 }
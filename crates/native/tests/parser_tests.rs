@@ -789,6 +789,18 @@ fn test_extend_input() {
     ));
 }
 
+#[test]
+fn test_extend_schema() {
+    assert_debug_snapshot!(parse!(
+        "extend schema { mutation: Mutation subscription: Subscription }"
+    ));
+}
+
+#[test]
+fn test_extend_schema_with_directives_only() {
+    assert_debug_snapshot!(parse!("extend schema @link(url: \"https://example.com\")"));
+}
+
 // =============================================================================
 // KEYWORD NAMES (GraphQL keywords used as identifiers)
 // =============================================================================
@@ -819,3 +831,235 @@ fn test_type_implements_keyword_interface() {
 fn test_extend_type_with_keyword_name() {
     assert_debug_snapshot!(parse!("extend type query { name: String }"));
 }
+
+// =============================================================================
+// SPANS
+// =============================================================================
+
+#[test]
+fn test_names_and_values_carry_spans() {
+    use mearie_native::graphql::ast::{Definition, ExecutableDefinition, Selection, ValueKind};
+
+    let code = "query Q { user(filter: { ids: [1, $id] }) { id } }";
+    let document = parse!(code);
+    let Definition::Executable(ExecutableDefinition::Operation(operation)) = &document.definitions[0] else {
+        panic!("Expected operation");
+    };
+    let Selection::Field(field) = &operation.selection_set.selections[0] else {
+        panic!("Expected field");
+    };
+    let text = |span: mearie_native::error::location::Span| &code[span.start..span.end];
+
+    assert_eq!(text(operation.name.unwrap().span()), "Q");
+    assert_eq!(text(field.span), "user(filter: { ids: [1, $id] }) { id }");
+    assert_eq!(text(field.name.span()), "user");
+
+    let argument = &field.arguments[0];
+    assert_eq!(text(argument.span), "filter: { ids: [1, $id] }");
+    assert_eq!(text(argument.value.span), "{ ids: [1, $id] }");
+
+    let ValueKind::Object(fields) = &argument.value.kind else {
+        panic!("Expected object value");
+    };
+    assert_eq!(text(fields[0].span), "ids: [1, $id]");
+    let ValueKind::List(items) = &fields[0].value.kind else {
+        panic!("Expected list value");
+    };
+    assert_eq!(text(items[0].span), "1");
+    assert_eq!(text(items[1].span), "$id");
+}
+
+// =============================================================================
+// LEXER ERRORS
+// =============================================================================
+
+macro_rules! parse_err {
+    ($code:expr) => {{
+        let arena = Box::leak(Box::new(Arena::new()));
+        let source = Box::leak(Box::new(Source::ephemeral($code)));
+        Parser::new(arena).with_source(source).parse().unwrap_err()
+    }};
+}
+
+#[test]
+fn test_unexpected_character_is_reported() {
+    let error = parse_err!("query { user ^ { id } }");
+    assert_eq!(error.message(), "unexpected character '^'");
+    assert_eq!(error.location().unwrap().column, Some(14));
+}
+
+#[test]
+fn test_unterminated_string_is_reported() {
+    let error = parse_err!("query {\n  user(id: \"1) { id }\n}");
+    assert_eq!(error.message(), "unterminated string starting at line 2");
+    assert_eq!(error.location().unwrap().column, Some(12));
+}
+
+#[test]
+fn test_unterminated_block_string_is_reported() {
+    let error = parse_err!("type Query {\n  id: ID\n}\n\"\"\"\nUser type\ntype User { id: ID }");
+    assert_eq!(error.message(), "unterminated block string starting at line 4");
+}
+
+#[test]
+fn test_invalid_escape_sequence_is_reported() {
+    let error = parse_err!(r#"query { user(id: "a\xb") { id } }"#);
+    assert_eq!(error.message(), r"invalid escape sequence '\x' in string");
+}
+
+#[test]
+fn test_invalid_number_is_reported() {
+    let error = parse_err!("query { user(limit: 1.) { id } }");
+    assert_eq!(error.message(), "invalid number '1.'");
+}
+
+#[test]
+fn test_unexpected_end_of_input_is_reported() {
+    let error = parse_err!("query { user(id: ");
+    assert_eq!(error.message(), "unexpected end of input: expected value");
+    assert_eq!(error.location().unwrap().column, Some(18));
+}
+
+#[test]
+fn test_truncated_selection_set_is_reported() {
+    let error = parse_err!("query { user { id");
+    assert_eq!(error.message(), "unexpected end of input: expected }");
+    assert_eq!(error.location().unwrap().column, Some(18));
+
+    let error = parse_err!("query GetUser");
+    assert_eq!(error.message(), "unexpected end of input: expected {");
+}
+
+#[test]
+fn test_truncated_arguments_are_reported() {
+    let error = parse_err!("query { user(id: 1");
+    assert_eq!(error.message(), "unexpected end of input: expected argument name");
+
+    let error = parse_err!("query { user(filter: { ids: [1, 2");
+    assert_eq!(error.message(), "unexpected end of input: expected value");
+}
+
+// =============================================================================
+// ERROR RECOVERY
+// =============================================================================
+
+macro_rules! parse_recovering {
+    ($code:expr) => {{
+        let arena = Box::leak(Box::new(Arena::new()));
+        let source = Box::leak(Box::new(Source::ephemeral($code)));
+        Parser::new(arena).with_source(source).parse_recovering()
+    }};
+}
+
+#[test]
+fn test_recovering_parse_without_errors_matches_strict_parse() {
+    let code = "query A { user { id } } fragment F on User { name }";
+    let (document, errors) = parse_recovering!(code);
+    assert!(errors.is_empty());
+    assert_eq!(format!("{:?}", document), format!("{:?}", parse!(code)));
+}
+
+#[test]
+fn test_recovering_parse_skips_broken_definition() {
+    let (document, errors) = parse_recovering!("query A($id: ) { user { id } }\nquery B { user { id } }");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].location().unwrap().line, 1);
+    assert_eq!(document.definitions.len(), 1);
+    assert_debug_snapshot!(document);
+}
+
+#[test]
+fn test_recovering_parse_skips_broken_selections() {
+    let (document, errors) =
+        parse_recovering!("query A {\n  user(id: !) { id }\n  viewer { ... }\n  name\n}\nfragment F on User { id }");
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].location().unwrap().line, 2);
+    assert_eq!(errors[1].location().unwrap().line, 3);
+    assert_eq!(document.definitions.len(), 2);
+    assert_debug_snapshot!(document);
+}
+
+#[test]
+fn test_recovering_parse_skips_stray_tokens_between_definitions() {
+    let (document, errors) = parse_recovering!("} query A { id } ) fragment F on User { id }");
+    assert_eq!(errors.len(), 2);
+    assert_eq!(document.definitions.len(), 2);
+}
+
+#[test]
+fn test_recovering_parse_stops_at_end_of_input() {
+    let (document, errors) = parse_recovering!("query A { user(id: ");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(document.definitions.len(), 1);
+}
+
+#[test]
+fn test_recovering_parse_reports_truncated_input() {
+    let (document, errors) = parse_recovering!("query A { user { id");
+    let messages: Vec<_> = errors.iter().map(|e| e.message()).collect();
+    assert_eq!(messages, ["unexpected end of input: expected }"]);
+    assert_eq!(document.definitions.len(), 1);
+
+    let (_, errors) = parse_recovering!("query A { user(id: 1");
+    let messages: Vec<_> = errors.iter().map(|e| e.message()).collect();
+    assert_eq!(messages, ["unexpected end of input: expected argument name"]);
+}
+
+#[test]
+fn test_strict_and_recovering_parse_report_the_same_error() {
+    for code in [
+        "query { a } }}} query B { b }",
+        "query A { a } ) query B { b }",
+        "query { user { id",
+        "query { user(id: 1",
+        "query A($id: ) { a }",
+        "fragment F on User",
+        "query { a ^ }",
+    ] {
+        let strict = parse_err!(code);
+        let (_, errors) = parse_recovering!(code);
+        let errors: Vec<_> = errors.iter().map(|e| (e.message(), e.location())).collect();
+        assert_eq!(errors, [(strict.message(), strict.location())], "{}", code);
+    }
+}
+
+#[test]
+fn test_recovering_parse_reports_every_lexer_error() {
+    let (document, errors) = parse_recovering!("query A { a ^ b % }\nquery B { c }");
+    let messages: Vec<_> = errors.iter().map(|e| e.message()).collect();
+    assert_eq!(messages, ["unexpected character '^'", "unexpected character '%'"]);
+    assert_eq!(document.definitions.len(), 2);
+}
+
+// =============================================================================
+// LOSSLESS PARSING
+// =============================================================================
+
+#[test]
+fn test_lossless_parse_round_trips_schema_and_operations() {
+    let code = r#"
+"""
+A user of the app.
+"""
+type User implements Node & Entity @key(fields: "id") {
+  id: ID! # never null
+  name(format: String = "full",): String
+}
+
+# Input values are separated by commas and newlines alike.
+input UserFilter { ids: [ID!], active: Boolean = true }
+
+query GetUsers($filter: UserFilter = { active: true, ids: ["1", "2"] }) {
+  users(filter: $filter) {
+    ...UserFields @include(if: true)
+  }
+}
+"#;
+
+    let arena = Box::leak(Box::new(Arena::new()));
+    let source = Box::leak(Box::new(Source::ephemeral(code)));
+    let lossless = Parser::new(arena).with_source(source).parse_lossless().unwrap();
+
+    assert_eq!(lossless.to_string(), code);
+    assert_eq!(lossless.document.definitions.len(), 3);
+}
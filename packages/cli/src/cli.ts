@@ -22,10 +22,10 @@ const generate = defineCommand({
         filename: args.config,
       });
 
-      const { schema, document, exclude, scalars } = config;
+      const { schema, document, exclude, scalars, keyFields, graphqlModules, globalGraphql, resolve } = config;
 
       const context = new CodegenContext(cwd);
-      context.setConfig({ scalars });
+      context.setConfig({ scalars, keyFields, graphqlModules, globalGraphql, resolve });
 
       logger.info('Finding schema and document files...');
 
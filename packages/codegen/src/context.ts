@@ -1,10 +1,19 @@
 import { readFile } from 'node:fs/promises';
-import { extractGraphQLSourcesFromDocuments } from './extractor.ts';
+import path from 'node:path';
+import type { ModuleResolutionConfig } from '@mearie/config';
+import { ExtractionSession, type ExtractConfig } from './extractor.ts';
 import { MearieAggregateError } from './errors.ts';
 import { generate, type GenerateConfig } from './generator.ts';
+import { logger, reportDiagnostics } from './logger.ts';
 import { writeFiles } from './writer.ts';
 import type { Source } from './types.ts';
 
+export type CodegenConfig = GenerateConfig & {
+  graphqlModules?: string[];
+  globalGraphql?: boolean;
+  resolve?: ModuleResolutionConfig;
+};
+
 /**
  * Stateful context for incremental code generation.
  * Reads and caches file contents when files are added/updated.
@@ -13,14 +22,16 @@ export class CodegenContext {
   private schemas = new Map<string, Source>();
   private documents = new Map<string, Source>();
   private cwd: string;
-  private config?: GenerateConfig;
+  private config?: CodegenConfig;
+  private extractionSession?: ExtractionSession;
 
   constructor(cwd: string = process.cwd()) {
     this.cwd = cwd;
   }
 
-  setConfig(config: GenerateConfig): void {
+  setConfig(config: CodegenConfig): void {
     this.config = config;
+    this.extractionSession = undefined;
   }
 
   /**
@@ -29,7 +40,7 @@ export class CodegenContext {
    */
   async addSchema(filePath: string): Promise<void> {
     const code = await readFile(filePath, 'utf8');
-    this.schemas.set(filePath, { code, filePath, startLine: 1 });
+    this.schemas.set(filePath, { code, filePath, startLine: 1, startColumn: 1, offset: 0 });
   }
 
   /**
@@ -46,7 +57,7 @@ export class CodegenContext {
    */
   async addDocument(filePath: string): Promise<void> {
     const code = await readFile(filePath, 'utf8');
-    this.documents.set(filePath, { code, filePath, startLine: 1 });
+    this.documents.set(filePath, { code, filePath, startLine: 1, startColumn: 1, offset: 0 });
   }
 
   /**
@@ -64,7 +75,8 @@ export class CodegenContext {
    */
   async generate(): Promise<void> {
     const schemas = [...this.schemas.values()];
-    const extractedDocuments = await extractGraphQLSourcesFromDocuments([...this.documents.values()]);
+    this.extractionSession ??= new ExtractionSession(this.extractConfig());
+    const extractedDocuments = this.extractionSession.extract([...this.documents.values()]);
 
     if (extractedDocuments.errors.length > 0) {
       throw new MearieAggregateError(extractedDocuments.errors);
@@ -73,13 +85,41 @@ export class CodegenContext {
     const { sources, errors } = generate({
       schemas,
       documents: extractedDocuments.sources,
-      config: this.config,
+      config: { ...this.config, documentFiles: [...this.documents.keys()] },
     });
 
     await writeFiles(this.cwd, sources);
 
-    if (errors.length > 0) {
-      throw new MearieAggregateError(errors);
+    reportDiagnostics(logger, errors.filter((error) => error.severity !== 'error'));
+
+    const fatalErrors = errors.filter((error) => error.severity === 'error');
+    if (fatalErrors.length > 0) {
+      throw new MearieAggregateError(fatalErrors);
     }
   }
+
+  /**
+   * Builds the extraction config, resolving relative resolve paths against the working directory.
+   * Path alias targets are resolved against `baseUrl`, or the working directory without one.
+   */
+  private extractConfig(): ExtractConfig {
+    const { baseUrl, paths, packages } = this.config?.resolve ?? {};
+    const resolvedBaseUrl = baseUrl === undefined ? undefined : path.resolve(this.cwd, baseUrl);
+    const pathsRoot = resolvedBaseUrl ?? this.cwd;
+
+    return {
+      graphqlModules: this.config?.graphqlModules,
+      globalGraphql: this.config?.globalGraphql,
+      baseUrl: resolvedBaseUrl,
+      paths:
+        paths &&
+        Object.fromEntries(
+          Object.entries(paths).map(([pattern, targets]) => [
+            pattern,
+            targets.map((target) => path.resolve(pathsRoot, target)),
+          ]),
+        ),
+      packages: packages?.map((pkg) => ({ ...pkg, root: path.resolve(this.cwd, pkg.root) })),
+    };
+  }
 }
@@ -2,12 +2,44 @@ type NativeLocation = {
   file_path: string;
   line: number;
   column: number;
+  end_line?: number | null;
+  end_column?: number | null;
+};
+
+/**
+ * Severity of a diagnostic. Only `error` diagnostics fail code generation.
+ */
+export type MearieErrorSeverity = 'error' | 'warning' | 'info';
+
+/**
+ * A secondary location of a diagnostic, such as the original definition of a duplicate.
+ */
+export type MearieRelatedLocation = {
+  message: string;
+  filePath: string;
+  line: number;
+  column?: number;
+  endLine?: number;
+  endColumn?: number;
+};
+
+export type MearieErrorOptions = {
+  endLine?: number;
+  endColumn?: number;
+  severity?: MearieErrorSeverity;
+  code?: string;
+  related?: MearieRelatedLocation[];
+  suggestions?: string[];
 };
 
 type NativeError = {
   message: string;
   type: string;
+  severity?: MearieErrorSeverity;
+  code?: string;
   location?: NativeLocation;
+  related?: { message: string; location: NativeLocation }[];
+  suggestions?: string[];
   [key: string]: unknown;
 };
 
@@ -18,13 +50,27 @@ export class MearieError extends Error {
   readonly filePath?: string;
   readonly line?: number;
   readonly column?: number;
+  /** Line just past the end of the offending source range, when known. */
+  readonly endLine?: number;
+  /** Column just past the end of the offending source range, when known. */
+  readonly endColumn?: number;
+  readonly severity: MearieErrorSeverity;
+  readonly code?: string;
+  readonly related: MearieRelatedLocation[];
+  readonly suggestions: string[];
 
-  constructor(message: string, filePath?: string, line?: number, column?: number) {
+  constructor(message: string, filePath?: string, line?: number, column?: number, options: MearieErrorOptions = {}) {
     super(message);
     this.name = 'MearieError';
     this.filePath = filePath;
     this.line = line;
     this.column = column;
+    this.endLine = options.endLine;
+    this.endColumn = options.endColumn;
+    this.severity = options.severity ?? 'error';
+    this.code = options.code;
+    this.related = options.related ?? [];
+    this.suggestions = options.suggestions ?? [];
   }
 
   static fromNative(data: unknown): MearieError {
@@ -37,7 +83,21 @@ export class MearieError extends Error {
     const line = error.location?.line;
     const column = error.location?.column;
 
-    return new MearieError(error.message, filePath, line, column);
+    return new MearieError(error.message, filePath, line, column, {
+      endLine: error.location?.end_line ?? undefined,
+      endColumn: error.location?.end_column ?? undefined,
+      severity: error.severity,
+      code: error.code,
+      related: error.related?.map((related) => ({
+        message: related.message,
+        filePath: related.location.file_path,
+        line: related.location.line,
+        column: related.location.column,
+        endLine: related.location.end_line ?? undefined,
+        endColumn: related.location.end_column ?? undefined,
+      })),
+      suggestions: error.suggestions,
+    });
   }
 }
 
@@ -53,60 +113,3 @@ export class MearieAggregateError extends Error {
     this.errors = errors;
   }
 }
-
-/**
- * Error thrown when `@vue/compiler-sfc` is not installed.
- */
-export class MissingVueCompilerError extends Error {
-  constructor() {
-    super(`
-GraphQL operations cannot be extracted from Vue files without @vue/compiler-sfc.
-
-Install it with:
-  npm install @vue/compiler-sfc
-  # or
-  pnpm add @vue/compiler-sfc
-  # or
-  yarn add @vue/compiler-sfc
-`);
-    this.name = 'MissingVueCompilerError';
-  }
-}
-
-/**
- * Error thrown when svelte compiler is not installed.
- */
-export class MissingSvelteCompilerError extends Error {
-  constructor() {
-    super(`
-GraphQL operations cannot be extracted from Svelte files without svelte.
-
-Install it with:
-  npm install svelte
-  # or
-  pnpm add svelte
-  # or
-  yarn add svelte
-`);
-    this.name = 'MissingSvelteCompilerError';
-  }
-}
-
-/**
- * Error thrown when TypeScript is not installed.
- */
-export class MissingTypeScriptError extends Error {
-  constructor() {
-    super(`
-GraphQL operations cannot be extracted from Vue files without typescript.
-
-Install it with:
-  npm install typescript
-  # or
-  pnpm add typescript
-  # or
-  yarn add typescript
-`);
-    this.name = 'MissingTypeScriptError';
-  }
-}
@@ -1,71 +1,82 @@
 import {
+  ExtractionSession as NativeExtractionSession,
   extractGraphQLSources as extractGraphQLSourcesNative,
   extractGraphQLSourcesFromDocuments as extractGraphQLSourcesFromDocumentsNative,
+  type ExtractGraphQLSourcesConfig,
 } from '@mearie/native';
 import { MearieError } from './errors.ts';
-import { extractAstroScripts, extractMarkdownCodeBlocks, extractSvelteScript, extractVueScript } from './parsers.ts';
 import type { Source } from './types.ts';
 
+export type ExtractConfig = ExtractGraphQLSourcesConfig;
+
 type ExtractGraphQLSourcesResult = {
   sources: Source[];
   errors: MearieError[];
 };
 
-const extractGraphQLSourceBlocks = async (source: Source): Promise<Source[]> => {
-  const ext = source.filePath.split('.').pop()?.toLowerCase();
+const SUPPORTED_EXTENSIONS = new Set(['vue', 'svelte', 'astro', 'md', 'js', 'jsx', 'ts', 'tsx', 'graphql', 'gql']);
 
-  try {
-    switch (ext) {
-      case 'vue': {
-        return extractVueScript(source);
-      }
-      case 'svelte': {
-        return extractSvelteScript(source);
-      }
-      case 'astro': {
-        return extractAstroScripts(source);
-      }
-      case 'md': {
-        return extractMarkdownCodeBlocks(source);
-      }
-      case 'js':
-      case 'jsx':
-      case 'ts':
-      case 'tsx': {
-        return [source];
-      }
-      default: {
-        return [];
-      }
-    }
-  } catch (error) {
-    const message = error instanceof Error ? error.message : String(error);
-    throw new MearieError(message, source.filePath);
-  }
+/**
+ * Returns whether GraphQL can be extracted from the source. Component and Markdown files are split into
+ * their script blocks natively, and GraphQL document files are used as is.
+ * @param source - Source file.
+ * @returns Whether the source is a supported document.
+ */
+const isSupportedDocument = (source: Source): boolean => {
+  const ext = source.filePath.split('.').pop()?.toLowerCase();
+  return ext !== undefined && SUPPORTED_EXTENSIONS.has(ext);
 };
 
-export const extractGraphQLSources = async (source: Source): Promise<ExtractGraphQLSourcesResult> => {
-  const sources: Source[] = [];
-  const errors: MearieError[] = [];
-
-  const blocks = await extractGraphQLSourceBlocks(source);
-
-  for (const block of blocks) {
-    const result = extractGraphQLSourcesNative(block);
-    sources.push(...result.sources);
-    errors.push(...result.errors.map((error) => MearieError.fromNative(error)));
+export const extractGraphQLSources = async (
+  source: Source,
+  config?: ExtractConfig,
+): Promise<ExtractGraphQLSourcesResult> => {
+  if (!isSupportedDocument(source)) {
+    return { sources: [], errors: [] };
   }
 
-  return { sources, errors };
+  const result = extractGraphQLSourcesNative(source, config);
+
+  return {
+    sources: result.sources,
+    errors: result.errors.map((error) => MearieError.fromNative(error)),
+  };
 };
 
-export const extractGraphQLSourcesFromDocuments = async (documents: Source[]): Promise<ExtractGraphQLSourcesResult> => {
-  const documentBlocks = await Promise.all(documents.map((document) => extractGraphQLSourceBlocks(document)));
-  const blocks = documentBlocks.flat();
-  const result = extractGraphQLSourcesFromDocumentsNative(blocks);
+export const extractGraphQLSourcesFromDocuments = async (
+  documents: Source[],
+  config?: ExtractConfig,
+): Promise<ExtractGraphQLSourcesResult> => {
+  const result = extractGraphQLSourcesFromDocumentsNative(documents.filter(isSupportedDocument), config);
 
   return {
     sources: result.sources,
     errors: result.errors.map((error) => MearieError.fromNative(error)),
   };
 };
+
+/**
+ * Extracts GraphQL sources from the documents of a project across repeated calls, such as in watch mode.
+ * Results are reused for documents whose content did not change since the previous call.
+ */
+export class ExtractionSession {
+  private session: NativeExtractionSession;
+
+  constructor(config?: ExtractConfig) {
+    this.session = new NativeExtractionSession(config);
+  }
+
+  /**
+   * Extracts GraphQL sources from all documents of the project.
+   * @param documents - All documents of the project. Documents missing since the previous call are forgotten.
+   * @returns Extracted sources and errors.
+   */
+  extract(documents: Source[]): ExtractGraphQLSourcesResult {
+    const result = this.session.extract(documents.filter(isSupportedDocument));
+
+    return {
+      sources: result.sources,
+      errors: result.errors.map((error) => MearieError.fromNative(error)),
+    };
+  }
+}
@@ -4,6 +4,8 @@ import type { Source } from './types.ts';
 
 export type GenerateConfig = {
   scalars?: Record<string, string>;
+  keyFields?: Record<string, string[]>;
+  documentFiles?: string[];
 };
 
 export type GenerateOptions = {
@@ -1,3 +1,3 @@
 export { findFiles, createMatcher } from './glob.ts';
 export { CodegenContext } from './context.ts';
-export { logger, report } from './logger.ts';
+export { logger, report, reportDiagnostics } from './logger.ts';
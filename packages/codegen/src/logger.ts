@@ -21,17 +21,43 @@ configureSync({
 
 export const logger = getLogger(['mearie']);
 
-const formatMearieError = (error: MearieError): string => {
-  const parts = [error.filePath, error.line, error.column]
+const formatLocation = (filePath?: string, line?: number, column?: number): string =>
+  [filePath, line, column]
     .filter((part) => part !== undefined && part !== null)
-    .map(String);
+    .map(String)
+    .join(':');
+
+const formatMearieError = (error: MearieError): string => {
+  const location = formatLocation(error.filePath, error.line, error.column);
+  const code = error.code ? ` ${pc.dim(`[${error.code}]`)}` : '';
 
-  const location = parts.length > 0 ? parts.join(':') : '';
+  let message = location
+    ? `${pc.bold(error.message)}${code} ${pc.cyan(pc.underline(location))}`
+    : `${pc.bold(error.message)}${code}`;
 
-  if (location) {
-    return `${pc.bold(error.message)} ${pc.cyan(pc.underline(location))}`;
+  for (const related of error.related) {
+    const relatedLocation = formatLocation(related.filePath, related.line, related.column);
+    message += `\n  ${related.message} ${pc.cyan(pc.underline(relatedLocation))}`;
+  }
+
+  return message;
+};
+
+/**
+ * Reports non-fatal diagnostics (warnings and infos) using the provided logger.
+ * @param logger - The logger to use.
+ * @param diagnostics - The diagnostics to report.
+ */
+export const reportDiagnostics = (logger: Logger, diagnostics: MearieError[]): void => {
+  for (const diagnostic of diagnostics) {
+    if (diagnostic.severity === 'warning') {
+      logger.warn(formatMearieError(diagnostic));
+    } else if (diagnostic.severity === 'info') {
+      logger.info(formatMearieError(diagnostic));
+    } else {
+      logger.error(formatMearieError(diagnostic));
+    }
   }
-  return pc.bold(error.message);
 };
 
 /**
@@ -1,6 +1,11 @@
+import type { SourceMapping } from '@mearie/native';
+
 export type Source = {
   code: string;
   filePath: string;
   importableFilePath?: string;
   startLine: number;
+  startColumn: number;
+  offset: number;
+  mappings?: SourceMapping[];
 };
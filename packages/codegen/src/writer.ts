@@ -7,6 +7,7 @@ export const writeFiles = async (cwd: string, sources: Source[]): Promise<void>
 
   await mkdir(mearieDir, { recursive: true });
 
-  // Write generated files (graphql.js, graphql.d.ts, types.d.ts)
-  await Promise.all(sources.map((source) => writeFile(path.join(mearieDir, source.filePath), source.code, 'utf8')));
+  // Write generated files (graphql.js, graphql.d.ts, types.d.ts) into .mearie, and the `.d.ts` declarations of
+  // .graphql document files next to those files, as their paths are absolute
+  await Promise.all(sources.map((source) => writeFile(path.resolve(mearieDir, source.filePath), source.code, 'utf8')));
 };
@@ -9,9 +9,13 @@ const generatedDir = path.join(fixtureDir, '__generated__');
 export const generateFixture = async (): Promise<void> => {
   const schema = await readFile(path.join(fixtureDir, 'schema.graphql'), 'utf8');
 
-  const result = generateCode([{ code: schema, filePath: 'schema.graphql', startLine: 1 }], [], {
-    scalars: { URL: 'URL' },
-  });
+  const result = generateCode(
+    [{ code: schema, filePath: 'schema.graphql', startLine: 1, startColumn: 1, offset: 0 }],
+    [],
+    {
+      scalars: { URL: 'URL' },
+    },
+  );
 
   if (result.errors.length > 0) {
     throw new Error(`Failed to generate fixture:\n${JSON.stringify(result.errors, null, 2)}`);
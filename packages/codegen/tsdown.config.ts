@@ -3,6 +3,5 @@ import { defineConfig } from 'tsdown';
 export default defineConfig({
   format: ['esm', 'cjs'],
   dts: true,
-  external: ['@vue/compiler-sfc', 'svelte'],
   skipNodeModulesBundle: true,
 });
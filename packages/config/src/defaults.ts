@@ -5,4 +5,8 @@ export const defaultResolvedMearieConfig: ResolvedMearieConfig = {
   document: '**/*.{js,jsx,ts,tsx,vue,svelte,astro}',
   exclude: ['**/node_modules/**', '**/dist/**'],
   scalars: {},
+  keyFields: {},
+  graphqlModules: ['$mearie'],
+  globalGraphql: false,
+  resolve: {},
 };
@@ -1,4 +1,4 @@
-export type { MearieConfig, ResolvedMearieConfig } from './types.ts';
+export type { MearieConfig, ModuleResolutionConfig, ResolvedMearieConfig, WorkspacePackage } from './types.ts';
 export { loadConfig, type LoadConfigOptions } from './load.ts';
 export { defineConfig } from './define.ts';
 export { mergeConfig } from './merge.ts';
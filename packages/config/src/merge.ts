@@ -5,7 +5,7 @@ import type { MearieConfig, ResolvedMearieConfig } from './types.ts';
  * @param base - Base configuration.
  * @param override - Override configuration.
  * @returns Merged configuration where override values replace base values for schemas and documents,
- * exclude arrays are concatenated, and scalars are merged.
+ * exclude arrays are concatenated, scalars, key fields and resolve options are merged, and graphql modules are replaced.
  */
 export const mergeConfig = (base: ResolvedMearieConfig, override: Partial<MearieConfig>): ResolvedMearieConfig => {
   const baseExclude = Array.isArray(base.exclude) ? base.exclude : [base.exclude];
@@ -23,5 +23,15 @@ export const mergeConfig = (base: ResolvedMearieConfig, override: Partial<Mearie
       ...base.scalars,
       ...override.scalars,
     },
+    keyFields: {
+      ...base.keyFields,
+      ...override.keyFields,
+    },
+    graphqlModules: override.graphqlModules ?? base.graphqlModules,
+    globalGraphql: override.globalGraphql ?? base.globalGraphql,
+    resolve: {
+      ...base.resolve,
+      ...override.resolve,
+    },
   };
 };
@@ -6,4 +6,22 @@ export const mearieConfigSchema: z.ZodType<MearieConfig> = z.object({
   document: z.union([z.string(), z.array(z.string())]).optional(),
   exclude: z.union([z.string(), z.array(z.string())]).optional(),
   scalars: z.record(z.string(), z.string()).optional(),
+  keyFields: z.record(z.string(), z.array(z.string())).optional(),
+  graphqlModules: z.array(z.string()).optional(),
+  globalGraphql: z.boolean().optional(),
+  resolve: z
+    .object({
+      baseUrl: z.string().optional(),
+      paths: z.record(z.string(), z.array(z.string())).optional(),
+      packages: z
+        .array(
+          z.object({
+            name: z.string(),
+            root: z.string(),
+            exports: z.record(z.string(), z.string()).optional(),
+          }),
+        )
+        .optional(),
+    })
+    .optional(),
 });
@@ -23,6 +23,61 @@ export type MearieConfig = {
    * @default { ID: "string", String: "string", Int: "number", Float: "number", Boolean: "boolean" }
    */
   scalars?: Record<string, string>;
+
+  /**
+   * Entity key fields per object type, used to normalize entities in the cache.
+   * Overrides `@key(fields: "...")` directives and the default `id`, `_id`, `uuid` fields.
+   * `@key` directives are read only when the schema declares `@key`, e.g. as in Apollo Federation.
+   * An empty array opts the type out of normalization.
+   * @default {}
+   * @example { Issue: ["orgId", "number"], Article: ["slug"], SearchHit: [] }
+   */
+  keyFields?: Record<string, string[]>;
+
+  /**
+   * Modules the `graphql` function is imported from. Calls are extracted only when
+   * `graphql` comes from one of these modules, under any local name.
+   * @default ["$mearie"]
+   * @example ["$mearie", "~/lib/graphql"]
+   */
+  graphqlModules?: string[];
+
+  /**
+   * Whether `graphql` is provided as a global. When enabled, calls through a `graphql`
+   * that is neither imported nor declared in the file are extracted too.
+   * @default false
+   */
+  globalGraphql?: boolean;
+
+  /**
+   * Module resolution for fragments imported from other files through non-relative specifiers.
+   * Relative paths are resolved against the config file directory.
+   * @default {}
+   * @example { baseUrl: ".", paths: { "@/*": ["src/*"] }, packages: [{ name: "@acme/ui", root: "packages/ui" }] }
+   */
+  resolve?: ModuleResolutionConfig;
+};
+
+export type ModuleResolutionConfig = {
+  /** Directory non-relative imports are resolved against, like tsconfig `baseUrl`. */
+  baseUrl?: string;
+
+  /** Import aliases, like tsconfig `paths`. Targets are resolved against `baseUrl`. */
+  paths?: Record<string, string[]>;
+
+  /** Local workspace packages that fragments can be imported from by package name. */
+  packages?: WorkspacePackage[];
+};
+
+export type WorkspacePackage = {
+  /** Package name, as used in import specifiers. */
+  name: string;
+
+  /** Package directory. */
+  root: string;
+
+  /** `package.json` style exports mapping subpaths such as `"."` or `"./*"` to files relative to `root`. */
+  exports?: Record<string, string>;
 };
 
 export type ResolvedMearieConfig = Required<MearieConfig>;
@@ -7,6 +7,7 @@ import type { MearieOptions } from './types.ts';
 
 const VIRTUAL_MODULE_ID = '$mearie';
 const RESOLVED_VIRTUAL_MODULE_ID = '\0' + VIRTUAL_MODULE_ID;
+const GRAPHQL_DOCUMENT_PATTERN = /\.(graphql|gql)$/i;
 
 /**
  * Vite plugin for Mearie GraphQL code generation.
@@ -30,10 +31,10 @@ export const mearie = (options: MearieOptions = {}): Plugin => {
     projectRoot = cwd;
     mearieConfig = mergeConfig(config, options);
 
-    const { schema, document, exclude, scalars } = mearieConfig;
+    const { schema, document, exclude, scalars, keyFields, graphqlModules, globalGraphql, resolve } = mearieConfig;
 
     context = new CodegenContext(projectRoot);
-    context.setConfig({ scalars });
+    context.setConfig({ scalars, keyFields, graphqlModules, globalGraphql, resolve });
 
     const schemaFiles = await findFiles(projectRoot, {
       include: schema,
@@ -168,5 +169,32 @@ export const mearie = (options: MearieOptions = {}): Plugin => {
         }
       }
     },
+
+    async transform(code, id) {
+      const [file, query] = id.split('?');
+      const params = new URLSearchParams(query);
+      const isVueBlock = params.has('vue') && params.get('type') === 'graphql';
+      if (!file || !(isVueBlock || GRAPHQL_DOCUMENT_PATTERN.test(file))) {
+        return;
+      }
+
+      await ensureInitialized();
+
+      const { document, exclude } = mearieConfig;
+      const documentMatcher = createMatcher({ include: document, exclude });
+      if (!documentMatcher(path.relative(projectRoot, file))) {
+        return;
+      }
+
+      // The document source is the key of its artifact, so the document evaluates to the same artifact as
+      // passing its contents to `graphql()`. Vue custom blocks attach it to the component instead.
+      const artifact = `graphql(${JSON.stringify(code)})`;
+      const exported = isVueBlock ? `(component) => {\n  component.graphql = ${artifact};\n}` : artifact;
+
+      return {
+        code: `import { graphql } from '${VIRTUAL_MODULE_ID}';\n\nexport default ${exported};\n`,
+        map: null,
+      };
+    },
   };
 };